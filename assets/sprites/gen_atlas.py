@@ -28,6 +28,9 @@
   [1344..1407] Bat Lord（巨大コウモリ・赤い目）
   [1408..1471] Stone Golem（巨大石ゴーレム・紫のオーラ）
   [1472..1535] 岩弾（Stone Golem の範囲攻撃）
+
+  地面タイル（64x64）:
+  [1536..1599] 地面タイル（白地。タイルマップの色を乗算）
 """
 
 import struct
@@ -349,6 +352,19 @@ def draw_rock_bullet(ox):
 
 draw_rock_bullet(1472)
 
+# ─── 地面タイル（白地。タイルマップの色を乗算して使う）[1536..1599] ──
+def draw_ground_tile(ox):
+    """地面タイル: ほぼ白の地 + 薄い縁と小石の模様（乗算で色が付く）"""
+    fill_rect(ox, 0, ox+64, 64, 255, 255, 255)
+    # 縁（タイル境界がうっすら見える程度）
+    fill_rect(ox,    0,  ox+64, 1,  232, 232, 232)
+    fill_rect(ox,    0,  ox+1,  64, 232, 232, 232)
+    # 小石・草の粒
+    for (dx, dy) in [(9, 13), (41, 7), (25, 30), (52, 38), (14, 50), (37, 55)]:
+        fill_rect(ox+dx, dy, ox+dx+3, dy+2, 222, 222, 222)
+
+draw_ground_tile(1536)
+
 # ─── PNG エンコード ───────────────────────────────────────────────────
 def make_png(width, height, rgba_data):
     def chunk(name, data):
@@ -392,3 +408,4 @@ print("  [1280..1343] Slime King boss (giant slime + crown)")
 print("  [1344..1407] Bat Lord boss (giant bat + red eyes)")
 print("  [1408..1471] Stone Golem boss (giant golem + purple aura)")
 print("  [1472..1535] Rock bullet (Stone Golem attack)")
+print("  [1536..1599] Ground tile (white, tinted per tile)")
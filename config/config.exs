@@ -21,6 +21,15 @@ config :game, current: Game.VampireSurvivor
 # 1.5.2: マップ障害物。:plain | :forest | :minimal
 config :game, map: :minimal
 
+# ワールドの乱数シード。未指定時は起動ごとにランダム（同じシードなら同じ展開になる）
+# config :game, world_seed: 12345
+
+# 入力・コマンドのリプレイ記録先。Engine.play_replay/1 で再生して状態のずれを確かめられる
+# config :game, replay_path: "saves/last_run.replay"
+
+# ワールド状態のチェックサムを frame_events に付ける間隔（フレーム数）。同期ずれの検出用
+# config :game, checksum_interval: 60
+
 # 1.4.8: ゲーム別アセットパス
 # ゲームの assets_path/0 で上書き可能。未指定時は current ゲームの assets_path を使用。
 # GAME_ASSETS_ID 環境変数として game_native 等に渡され、assets/{id}/ を参照する。
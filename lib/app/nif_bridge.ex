@@ -18,10 +18,25 @@ defmodule App.NifBridge do
 
   # ── control ───────────────────────────────────────────────────────
   def add(_a, _b), do: :erlang.nif_error(:nif_not_loaded)
-  def create_world(), do: :erlang.nif_error(:nif_not_loaded)
+  def create_world(_seed), do: :erlang.nif_error(:nif_not_loaded)
+
+  # ワールドの広さを設定（プレイヤー・敵・ボス・弾・カメラ共通の境界）
+  def set_world_size(_world, _width, _height), do: :erlang.nif_error(:nif_not_loaded)
 
   # 1.5.2: マップ障害物を設定。obstacles: [{x, y, radius, kind}, ...]
   def set_map_obstacles(_world, _obstacles), do: :erlang.nif_error(:nif_not_loaded)
+
+  # タイルマップファイル（JSON）を読み込み、広さ・障害物・地面タイル・スポーン領域を設定
+  def load_map(_world, _path), do: :erlang.nif_error(:nif_not_loaded)
+
+  # 敵・武器・ボスのパラメータテーブルを差し替える（format: :json、contents: ファイル内容）
+  def load_entity_params(_format, _contents), do: :erlang.nif_error(:nif_not_loaded)
+
+  # 武器カタログ取得（[{weapon_name, label}]、並び順 = 武器 ID）
+  def get_weapon_catalog, do: :erlang.nif_error(:nif_not_loaded)
+
+  # 敵のスポーンスケジュールを設定（format: :json、contents: ファイル内容）
+  def set_spawn_schedule(_world, _format, _contents), do: :erlang.nif_error(:nif_not_loaded)
   def physics_step(_world, _delta_ms), do: :erlang.nif_error(:nif_not_loaded)
 
   # 1.3.1: フレームイベントを取り出す（[{event_atom, arg1, arg2}] のリスト）
@@ -37,6 +52,9 @@ defmodule App.NifBridge do
   # weapon_name: "magic_wand" | "axe" | "cross" | "whip" | "fireball" | "lightning"
   def add_weapon(_world, _weapon_name), do: :erlang.nif_error(:nif_not_loaded)
 
+  # パッシブアイテムを追加しレベルアップを確定する（passive_id: entity_registry の passives）
+  def add_passive(_world, _passive_id), do: :erlang.nif_error(:nif_not_loaded)
+
   # 武器選択をスキップしてレベルアップ待機を解除する（全武器MaxLv時など）
   def skip_level_up(_world), do: :erlang.nif_error(:nif_not_loaded)
 
@@ -54,6 +72,8 @@ defmodule App.NifBridge do
   def start_render_thread(_world), do: :erlang.nif_error(:nif_not_loaded)
   def pause_physics(_control), do: :erlang.nif_error(:nif_not_loaded)
   def resume_physics(_control), do: :erlang.nif_error(:nif_not_loaded)
+  # frames フレームごとに {:frame_events, events, {frame_id, checksum}} を送る（0 で止める）
+  def set_checksum_interval(_control, _frames), do: :erlang.nif_error(:nif_not_loaded)
 
   # ── query_light（毎フレーム利用可）───────────────────────────────
   # 1.1.8: プレイヤー座標取得
@@ -71,17 +91,26 @@ defmodule App.NifBridge do
   # Q2: 軽量フレームメタデータを1回のNIFで取得（オーバーヘッド対策）
   # 戻り値: {{hp, max_hp, score, elapsed}, {enemy_count, bullet_count, physics_ms},
   #          {exp, level, level_up_pending, exp_to_next}, {boss_alive, boss_hp, boss_max_hp}}
+  #          （複数ボスの boss_hp / boss_max_hp は合計値）
   def get_frame_metadata(_world), do: :erlang.nif_error(:nif_not_loaded)
   # 1.1.14: レベルアップ関連データ取得（{exp, level, level_up_pending, exp_to_next}）
   def get_level_up_data(_world), do: :erlang.nif_error(:nif_not_loaded)
+  # レベルアップの選択肢（武器名・パッシブ名の文字列リスト、Rust 側で計算済み）
+  def get_level_up_choices(_world), do: :erlang.nif_error(:nif_not_loaded)
+  # 現在のウェーブ名（スポーンスケジュールの label、なければ nil）
+  def get_wave_label(_world), do: :erlang.nif_error(:nif_not_loaded)
   # 1.2.2: 装備中の武器スロット情報取得（[{weapon_name, level}]）
   def get_weapon_levels(_world), do: :erlang.nif_error(:nif_not_loaded)
+  # 所持中のパッシブアイテム情報取得（[{passive_name, level}]）
+  def get_passive_levels(_world), do: :erlang.nif_error(:nif_not_loaded)
   # 1.2.4: 磁石エフェクト残り時間（秒）を取得
   def get_magnet_timer(_world), do: :erlang.nif_error(:nif_not_loaded)
-  # ボスの状態を返す: {:alive, hp, max_hp} | {:none, 0.0, 0.0}
+  # 生存中のボスを出現順に返す: [{boss_id, kind_id, hp, max_hp}]（いなければ []）
   def get_boss_info(_world), do: :erlang.nif_error(:nif_not_loaded)
   # プレイヤーが死亡しているかを返す（HP == 0 で true）
   def is_player_dead(_world), do: :erlang.nif_error(:nif_not_loaded)
+  # ゲームプレイ状態のチェックサム（{frame_id, checksum}）。同期ずれの検出用
+  def get_world_checksum(_world), do: :erlang.nif_error(:nif_not_loaded)
 
   # ── snapshot_heavy（明示操作時のみ）──────────────────────────────
   # セーブ/デバッグ用途。毎フレーム呼び出しは禁止。
@@ -89,4 +118,10 @@ defmodule App.NifBridge do
   def load_save_snapshot(_world, _snapshot), do: :erlang.nif_error(:nif_not_loaded)
   # 1.5.5: デバッグ用 — dev ビルド時のみ有効。release では {:error, :debug_build_only}
   def debug_dump_world(_world), do: :erlang.nif_error(:nif_not_loaded)
+
+  # ── replay ────────────────────────────────────────────────────────
+  # 記録は create_world の直後に開始する。再生はワールドを使わず、ファイルから新しいワールドを作る
+  def start_replay_recording(_world, _path), do: :erlang.nif_error(:nif_not_loaded)
+  def stop_replay_recording(_world), do: :erlang.nif_error(:nif_not_loaded)
+  def play_replay_file(_path), do: :erlang.nif_error(:nif_not_loaded)
 end
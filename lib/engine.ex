@@ -69,6 +69,7 @@ defmodule Engine do
 
   @doc """
   ボスをスポーンする（1.4.7: entity_registry で kind → ID に解決）。
+  同時に最大 3 体まで出現し、上限に達している場合は何もしない。
 
   ## 例
       Engine.spawn_boss(world_ref, :slime_king)
@@ -121,6 +122,42 @@ defmodule Engine do
     Queries.get_level_up_data(world_ref)
   end
 
+  @doc """
+  読み込み済みの entity_params の武器テーブルを `[{weapon, label}]` で返す（並び順 = 武器 ID）。
+  武器の名前・表示名は entity_params だけで定義し、Elixir 側はここから引く。
+  """
+  def weapon_catalog do
+    Queries.get_weapon_catalog()
+    |> Enum.map(fn {name, label} -> {String.to_atom(name), label} end)
+  end
+
+  @doc "武器名 atom → 武器 ID のマップ（`entity_registry/0` の weapons 用）"
+  def weapon_ids do
+    weapon_catalog()
+    |> Enum.with_index()
+    |> Map.new(fn {{weapon, _label}, id} -> {weapon, id} end)
+  end
+
+  @doc "武器名 atom → 表示名のマップ"
+  def weapon_labels, do: Map.new(weapon_catalog())
+
+  @doc """
+  レベルアップ時の選択肢（武器名・パッシブ名の atom のリスト）を返す。
+  Rust 側がレベルアップ時に計算したもので、描画スレッドのカードと同じ内容になる。
+  空リストなら選べる強化が残っていない。
+  """
+  def get_level_up_choices(world_ref) do
+    world_ref
+    |> Queries.get_level_up_choices()
+    |> Enum.map(&String.to_atom/1)
+  end
+
+  @doc """
+  現在のウェーブ名を返す（`load_spawn_schedule/2` で設定したスケジュールの `label`）。
+  スケジュール未設定、または有効なウェーブにラベルがなければ `nil`。
+  """
+  def get_wave_label(world_ref), do: Queries.get_wave_label(world_ref)
+
   @doc """
   武器選択をスキップしてレベルアップ待機を解除する。
   全武器 MaxLv のときなどに使用。
@@ -141,9 +178,64 @@ defmodule Engine do
   @doc """
   ワールドを生成する。GameEvents の init で呼ばれる。
   ゲームから直接呼ぶことはない。
+
+  seed はワールドの乱数（スポーン・ドロップ・会心・弾幕・パーティクル）の種。
+  同じ seed と同じ入力なら同じ展開になる。省略時はランダムなシードを使う。
   """
-  def create_world do
-    Commands.create_world()
+  def create_world(seed \\ random_seed()) do
+    Commands.create_world(seed)
+  end
+
+  @doc """
+  create_world 用のランダムなシード（0 以上 2^63 - 1 未満の整数）を返す。
+  """
+  def random_seed, do: :rand.uniform(0x7FFF_FFFF_FFFF_FFFF) - 1
+
+  @doc """
+  ワールドを変更するコマンド（入力・物理ステップ・スポーン・武器追加など）を path に記録し始める。
+  create_world の直後、マップ読み込みより前に呼ぶ。GameEvents は config の `:replay_path` があれば呼ぶ。
+
+  - `:ok` - 記録開始
+  - `{:error, reason}` - ファイルが作れない・既にステップが進んでいる
+  """
+  def start_replay_recording(world_ref, path) do
+    File.mkdir_p(Path.dirname(path))
+    Commands.start_replay_recording(world_ref, path)
+    :ok
+  rescue
+    e in ErlangError -> {:error, e.original}
+  end
+
+  @doc """
+  リプレイの記録を止めてファイルを閉じる。ワールドが破棄されたときも閉じられる。
+  """
+  def stop_replay_recording(world_ref) do
+    Commands.stop_replay_recording(world_ref)
+    :ok
+  rescue
+    e in ErlangError -> {:error, e.original}
+  end
+
+  @doc """
+  リプレイファイルを新しいワールドで再生し、記録時の状態と一致するか確かめる。
+  記録時と同じ entity_params を読み込んだ状態で呼ぶこと。
+
+  - `{:ok, %{frames: n, checkpoints: m}}` - 最後まで一致した
+  - `{:error, reason}` - ファイルが読めない、または状態がずれた（ずれたフレームと値を含む）
+  """
+  def play_replay(path) do
+    {frames, checkpoints} = Commands.play_replay_file(path)
+    {:ok, %{frames: frames, checkpoints: checkpoints}}
+  rescue
+    e in ErlangError -> {:error, e.original}
+  end
+
+  @doc """
+  ワールドの広さを設定する。
+  GameEvents の init で呼ばれる。width / height は MapLoader.world_size_for_map/1 の戻り値。
+  """
+  def set_world_size(world_ref, width, height) do
+    Commands.set_world_size(world_ref, width, height)
   end
 
   @doc """
@@ -154,6 +246,63 @@ defmodule Engine do
     Commands.set_map_obstacles(world_ref, obstacles)
   end
 
+  @doc """
+  タイルマップファイルを読み込み、ワールドの広さ・障害物・地面タイル・スポーン領域・
+  プレイヤー開始位置を設定する。GameEvents の init で、`MapLoader.map_path/1` がパスを返すマップに使う。
+
+  - `:ok` - 読み込み成功
+  - `{:error, reason}` - ファイルがない・検証エラー
+  """
+  def load_map(world_ref, path) do
+    Commands.load_map(world_ref, path)
+    :ok
+  rescue
+    e in ErlangError -> {:error, e.original}
+  end
+
+  @doc """
+  敵・武器・ボスのパラメータテーブルをファイルから読み込み、Rust 側に登録する。
+  GameEvents の init で、ゲームが `entity_params_path/0` を実装している場合に呼ばれる。
+  形式は拡張子から判定する（現在は `.json` のみ）。
+
+  - `:ok` - 登録成功
+  - `{:error, reason}` - 読み込み失敗・未対応形式・検証エラー
+  """
+  def load_entity_params(path) do
+    with {:ok, format} <- entity_params_format(path),
+         {:ok, contents} <- File.read(path) do
+      Commands.load_entity_params(format, contents)
+      :ok
+    end
+  rescue
+    e in ErlangError -> {:error, e.original}
+  end
+
+  @doc """
+  敵のスポーンスケジュールをファイルから読み込み、ワールドに設定する。
+  GameEvents の init で、ゲームが `spawn_schedule_path/0` を実装している場合に呼ばれる。
+  設定後は Rust 側のスポーンディレクターが毎フレーム敵を出現させる。
+
+  - `:ok` - 設定成功
+  - `{:error, reason}` - 読み込み失敗・未対応形式・検証エラー
+  """
+  def load_spawn_schedule(world_ref, path) do
+    with {:ok, format} <- entity_params_format(path),
+         {:ok, contents} <- File.read(path) do
+      Commands.set_spawn_schedule(world_ref, format, contents)
+      :ok
+    end
+  rescue
+    e in ErlangError -> {:error, e.original}
+  end
+
+  defp entity_params_format(path) do
+    case Path.extname(path) do
+      ".json" -> {:ok, :json}
+      ext -> {:error, {:unsupported_format, ext}}
+    end
+  end
+
   @doc """
   1.5.1: ゲームループ制御用リソースを作成する。
   pause_physics / resume_physics で使用。
@@ -191,6 +340,25 @@ defmodule Engine do
     Commands.resume_physics(control_ref)
   end
 
+  @doc """
+  Rust ループが frames フレームごとに `{:frame_events, events, {frame_id, checksum}}` を送るようにする。
+  0 で元の `{:frame_events, events}` だけに戻る。GameEvents は config の `:checksum_interval` があれば呼ぶ。
+  """
+  def set_checksum_interval(control_ref, frames) when is_integer(frames) and frames >= 0 do
+    Commands.set_checksum_interval(control_ref, frames)
+  end
+
+  @doc """
+  ゲームプレイに影響する状態（プレイヤー・敵・弾・アイテム・ボス・武器スロット・乱数）のチェックサムを返す。
+  同じシード・同じ入力で進めたワールド同士は同じフレームで同じ値になるので、同期ずれの検出に使う。
+
+  ## 戻り値
+  `{frame_id, checksum}`（checksum は 64 ビット符号なし整数）
+  """
+  def get_world_checksum(world_ref) do
+    Queries.get_world_checksum(world_ref)
+  end
+
   @doc """
   物理演算を1ステップ実行する。GameEvents の tick から呼ばれる。
   ゲームから直接呼ぶことはない。
@@ -248,6 +416,21 @@ defmodule Engine do
     Queries.get_weapon_levels(world_ref)
   end
 
+  @doc """
+  パッシブアイテムを追加する（entity_registry の passives で名前 → ID に解決）。
+  GameEvents のレベルアップ選択処理で呼ばれる。所持中なら Lv+1。
+  """
+  def add_passive(world_ref, passive) when is_atom(passive) do
+    Commands.add_passive(world_ref, resolve_passive_id(passive))
+  end
+
+  @doc """
+  所持中のパッシブアイテム情報（[{passive_name, level}]）を取得する。
+  """
+  def get_passive_levels(world_ref) do
+    Queries.get_passive_levels(world_ref)
+  end
+
   # ── 1.5.3: セーブ・ロード ─────────────────────────────────────────────
 
   @doc """
@@ -372,4 +555,9 @@ defmodule Engine do
     game = Application.get_env(:game, :current, Game.VampireSurvivor)
     Map.fetch!(game.entity_registry().weapons, weapon)
   end
+
+  defp resolve_passive_id(passive) do
+    game = Application.get_env(:game, :current, Game.VampireSurvivor)
+    Map.fetch!(game.entity_registry().passives, passive)
+  end
 end
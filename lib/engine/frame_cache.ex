@@ -22,15 +22,17 @@ defmodule Engine.FrameCache do
   @doc """
   GameEvents が毎秒（60 フレームごと）書き込む。
 
+  - wave: ログ用のウェーブ名（スポーンスケジュールの label、なければゲームの wave_label/1）
   - render_type: シーンの render_type/0 の戻り値（任意の atom）
   - high_scores: 1.5.3: ゲームオーバー時にハイスコア一覧を渡す（任意）
   """
-  def put(enemy_count, bullet_count, physics_ms, hud_data, render_type \\ :playing, high_scores \\ nil) do
+  def put(enemy_count, bullet_count, physics_ms, hud_data, wave, render_type \\ :playing, high_scores \\ nil) do
     base = %{
       enemy_count:  enemy_count,
       bullet_count: bullet_count,
       physics_ms:   physics_ms,
       hud_data:     hud_data,
+      wave:         wave,
       render_type:  render_type,
       updated_at:   System.monotonic_time(:millisecond),
     }
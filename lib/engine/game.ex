@@ -51,4 +51,20 @@ defmodule Engine.Game do
   空文字列の場合は `assets/sprites/atlas.png` を参照（従来どおり）。
   """
   @callback assets_path() :: String.t()
+
+  @doc """
+  敵・武器・ボスのパラメータテーブルファイルのパス（任意）。
+  実装した場合、ワールド生成前に読み込まれ Rust 側の組み込みテーブルを置き換える。
+  未実装のゲームは組み込みテーブル（ヴァンサバ）を使用する。
+  """
+  @callback entity_params_path() :: String.t()
+
+  @doc """
+  敵のスポーンスケジュール（ウェーブ定義）ファイルのパス（任意）。
+  実装した場合、ワールド生成後に読み込まれ、Rust 側のスポーンディレクターが敵を出現させる。
+  未実装のゲームはシーン側で spawn_enemies を呼んで敵を出す。
+  """
+  @callback spawn_schedule_path() :: String.t()
+
+  @optional_callbacks entity_params_path: 0, spawn_schedule_path: 0
 end
@@ -6,6 +6,8 @@ defmodule Engine.GameEvents do
 
   1.5.1: tick 駆動は Rust 側で高精度 60 Hz。Elixir は `{:frame_events, events}` を
   受信してイベント駆動でシーン制御・入力設定・EventBus 配信を行う。
+  config の `:checksum_interval` を設定すると、その間隔で `{:frame_events, events, {frame_id, checksum}}`
+  が届き、最新の値を state の `last_checksum` に持つ。
 
   1.5.4: ルーム単位で複数インスタンスが起動可能。
   :main ルームのみが SceneManager・FrameCache を駆動する（表示・入力対象）。
@@ -59,14 +61,19 @@ defmodule Engine.GameEvents do
       Engine.RoomRegistry.register(:main)
     end
 
-    world_ref = Engine.create_world()
+    maybe_load_entity_params(current_game())
+    # シードは opts の :seed、config の :world_seed の順に探し、どちらもなければランダム
+    world_seed = Keyword.get_lazy(opts, :seed, fn -> Application.get_env(:game, :world_seed) || Engine.random_seed() end)
+    world_ref = Engine.create_world(world_seed)
+    maybe_start_replay_recording(world_ref)
 
-    # 1.5.2: マップ障害物をロード
+    # 1.5.2: マップの広さと障害物をロード
     map_id = Application.get_env(:game, :map, :plain)
-    obstacles = Engine.MapLoader.obstacles_for_map(map_id)
-    Engine.set_map_obstacles(world_ref, obstacles)
+    load_map(world_ref, map_id)
+    spawn_schedule_loaded = maybe_load_spawn_schedule(current_game(), world_ref)
 
     control_ref = Engine.create_game_loop_control()
+    maybe_set_checksum_interval(control_ref)
     if room_id == :main, do: Engine.FrameCache.init()
     start_ms = now_ms()
 
@@ -81,12 +88,15 @@ defmodule Engine.GameEvents do
     {:ok, %{
       room_id: room_id,
       world_ref: world_ref,
+      world_seed: world_seed,
       control_ref: control_ref,
       last_tick: start_ms,
       frame_count: 0,
       start_ms: start_ms,
       last_spawn_ms: start_ms,
+      spawn_schedule_loaded: spawn_schedule_loaded,
       weapon_levels: initial_weapon_levels,
+      last_checksum: nil,
     }}
   end
 
@@ -146,7 +156,7 @@ defmodule Engine.GameEvents do
 
     case Engine.SceneManager.current() do
       {:ok, %{module: ^level_up_scene}} ->
-        Engine.add_weapon(state.world_ref, weapon)
+        add_upgrade(state.world_ref, weapon)
         new_weapon_levels = fetch_weapon_levels(state.world_ref)
         lv = Map.get(new_weapon_levels, weapon, 1)
         Logger.info("[LEVEL UP] Weapon selected: #{game.weapon_label(weapon, lv)} -> resuming")
@@ -225,6 +235,17 @@ defmodule Engine.GameEvents do
     end
   end
 
+  # 選択肢は武器名かパッシブ名（Rust 側が両方を混ぜて返す）
+  defp add_upgrade(world_ref, choice) do
+    passives = current_game().entity_registry() |> Map.get(:passives, %{})
+
+    if Map.has_key?(passives, choice) do
+      Engine.add_passive(world_ref, choice)
+    else
+      Engine.add_weapon(world_ref, choice)
+    end
+  end
+
   defp apply_weapon_selection(state, weapon_name) when is_binary(weapon_name) do
     requested_weapon =
       try do
@@ -235,9 +256,15 @@ defmodule Engine.GameEvents do
 
     game = current_game()
     allowed_weapons = game.entity_registry().weapons |> Map.keys() |> MapSet.new()
+    # 選択肢にはパッシブアイテム名も含まれる（passives を持たないゲームもある）
+    allowed_passives = game.entity_registry() |> Map.get(:passives, %{}) |> Map.keys() |> MapSet.new()
     fallback_weapon = Map.keys(state.weapon_levels) |> List.first() || :magic_wand
 
     cond do
+      is_atom(requested_weapon) and MapSet.member?(allowed_passives, requested_weapon) ->
+        Engine.add_passive(state.world_ref, requested_weapon)
+        {requested_weapon, fetch_weapon_levels(state.world_ref)}
+
       is_atom(requested_weapon) and MapSet.member?(allowed_weapons, requested_weapon) ->
         Engine.add_weapon(state.world_ref, requested_weapon)
         {requested_weapon, fetch_weapon_levels(state.world_ref)}
@@ -308,6 +335,10 @@ defmodule Engine.GameEvents do
   # ── 1.5.1: Rust からの frame_events を受信してシーン更新 ─────────
 
   @impl true
+  def handle_info({:frame_events, events, {_frame_id, _checksum} = checksum}, state) do
+    handle_info({:frame_events, events}, %{state | last_checksum: checksum})
+  end
+
   def handle_info({:frame_events, events}, state) do
     # 1.5.4: 非 main ルームは headless（physics のみ、SceneManager 非使用）
     if state.room_id != :main do
@@ -372,6 +403,7 @@ defmodule Engine.GameEvents do
       now:           now,
       elapsed:       elapsed,
       last_spawn_ms: state.last_spawn_ms,
+      spawn_schedule_loaded: state.spawn_schedule_loaded,
       weapon_levels: state.weapon_levels,
       frame_count:   state.frame_count,
       start_ms:      state.start_ms,
@@ -399,7 +431,7 @@ defmodule Engine.GameEvents do
       state =
         case scene_state do
           %{choices: [first | _]} ->
-            Engine.add_weapon(state.world_ref, first)
+            add_upgrade(state.world_ref, first)
             new_levels = fetch_weapon_levels(state.world_ref)
             Logger.info("[LEVEL UP] Auto-selected: #{game.weapon_label(first, Map.get(new_levels, first, 1))} -> resuming")
             %{state | weapon_levels: new_levels}
@@ -465,9 +497,9 @@ defmodule Engine.GameEvents do
       hud_data = {hp, max_hp, score, elapsed_s}
       render_type = Engine.SceneManager.render_type()
       high_scores = if render_type == :game_over, do: Engine.load_high_scores(), else: nil
-      Engine.FrameCache.put(enemy_count, bullet_count, physics_ms, hud_data, render_type, high_scores)
+      wave = Engine.get_wave_label(state.world_ref) || game.wave_label(elapsed_s)
+      Engine.FrameCache.put(enemy_count, bullet_count, physics_ms, hud_data, wave, render_type, high_scores)
 
-      wave = game.wave_label(elapsed_s)
       budget_warn = if physics_ms > @tick_ms, do: " [OVER BUDGET]", else: ""
 
       weapon_info =
@@ -498,6 +530,71 @@ defmodule Engine.GameEvents do
 
   defp now_ms, do: System.monotonic_time(:millisecond)
 
+  defp maybe_load_entity_params(game) do
+    if function_exported?(game, :entity_params_path, 0) do
+      path = game.entity_params_path()
+
+      case Engine.load_entity_params(path) do
+        :ok -> :ok
+        {:error, reason} -> Logger.error("[ENTITY_PARAMS] Failed to load #{path}: #{inspect(reason)}")
+      end
+    end
+  end
+
+  # タイルマップファイルのあるマップはファイルから、ないマップは広さと障害物リストを個別に設定する
+  defp load_map(world_ref, map_id) do
+    case Engine.MapLoader.map_path(map_id) do
+      nil ->
+        {map_width, map_height} = Engine.MapLoader.world_size_for_map(map_id)
+        Engine.set_world_size(world_ref, map_width, map_height)
+        Engine.set_map_obstacles(world_ref, Engine.MapLoader.obstacles_for_map(map_id))
+
+      path ->
+        case Engine.load_map(world_ref, path) do
+          :ok -> :ok
+          {:error, reason} -> Logger.error("[MAP] Failed to load #{path}: #{inspect(reason)}")
+        end
+    end
+  end
+
+  defp maybe_set_checksum_interval(control_ref) do
+    case Application.get_env(:game, :checksum_interval) do
+      nil -> :ok
+      frames -> Engine.set_checksum_interval(control_ref, frames)
+    end
+  end
+
+  defp maybe_start_replay_recording(world_ref) do
+    case Application.get_env(:game, :replay_path) do
+      nil ->
+        :ok
+
+      path ->
+        case Engine.start_replay_recording(world_ref, path) do
+          :ok -> :ok
+          {:error, reason} -> Logger.error("[REPLAY] Failed to record to #{path}: #{inspect(reason)}")
+        end
+    end
+  end
+
+  # スケジュールを設定できたら true。false ならシーンが Elixir 側でスポーンする（context の spawn_schedule_loaded）
+  defp maybe_load_spawn_schedule(game, world_ref) do
+    if function_exported?(game, :spawn_schedule_path, 0) do
+      path = game.spawn_schedule_path()
+
+      case Engine.load_spawn_schedule(world_ref, path) do
+        :ok ->
+          true
+
+        {:error, reason} ->
+          Logger.error("[SPAWN] Failed to load #{path}: #{inspect(reason)} -> falling back to Elixir-side spawning")
+          false
+      end
+    else
+      false
+    end
+  end
+
   defp current_game do
     Application.get_env(:game, :current, Game.VampireSurvivor)
   end
@@ -1,5 +1,5 @@
 # Path: lib/engine/map_loader.ex
-# Summary: マップ ID に応じた障害物リストの提供
+# Summary: マップ ID に応じたタイルマップファイル・ワールドの広さ・障害物リストの提供
 defmodule Engine.MapLoader do
   @moduledoc """
   1.5.2: マップ障害物の定義。
@@ -7,7 +7,22 @@ defmodule Engine.MapLoader do
   マップ ID に応じて障害物リストを返す。
   各要素は `{x, y, radius, kind}` のタプル。
   - kind: 0 = 木, 1 = 岩（将来: Ghost のすり抜け判定用）
+
+  タイルマップファイル（`map_path/1`）があるマップは、広さ・障害物・地面タイル・
+  スポーン領域・プレイヤー開始位置をファイルから Rust 側で読み込む。
+  """
+
+  @doc """
+  マップ ID に対応するタイルマップファイル（JSON）のパスを返す。ファイルのないマップは `nil`。
   """
+  def map_path(:forest), do: "assets/maps/forest.json"
+  def map_path(_map_id), do: nil
+
+  @doc """
+  マップ ID に応じてワールドの広さ `{width, height}` を返す。
+  プレイヤーはワールドの中央から始まり、敵・ボス・弾・カメラもこの範囲に収まる。
+  """
+  def world_size_for_map(_map_id), do: {4096.0, 4096.0}
 
   @doc """
   マップ ID に応じて障害物リストを返す。
@@ -20,31 +35,16 @@ defmodule Engine.MapLoader do
       Engine.MapLoader.obstacles_for_map(:plain)
       # => []
 
-      Engine.MapLoader.obstacles_for_map(:forest)
-      # => [{512, 512, 40, 0}, {1024, 768, 30, 1}, ...]
+      Engine.MapLoader.obstacles_for_map(:minimal)
+      # => [{1920.0, 2048.0, 40.0, 0}, {2176.0, 2088.0, 30.0, 1}]
   """
   def obstacles_for_map(:plain), do: []
 
-  def obstacles_for_map(:forest) do
-    # 木・岩の配置データ（x, y, radius, kind）
-    # マップサイズ 4096x4096 を想定した例
-    [
-      {512.0, 512.0, 40.0, 0},
-      {1024.0, 768.0, 30.0, 1},
-      {1536.0, 256.0, 40.0, 0},
-      {768.0, 1024.0, 35.0, 1},
-      {2048.0, 2048.0, 50.0, 0},
-      {2560.0, 1280.0, 30.0, 1},
-      {1280.0, 2560.0, 40.0, 0},
-      {640.0, 640.0, 25.0, 1}
-    ]
-  end
-
   def obstacles_for_map(:minimal) do
-    # 動作確認用: 画面中央付近に数個
+    # 動作確認用: 開始位置（ワールド中央）付近に数個
     [
-      {512.0, 360.0, 40.0, 0},
-      {768.0, 400.0, 30.0, 1}
+      {1920.0, 2048.0, 40.0, 0},
+      {2176.0, 2088.0, 30.0, 1}
     ]
   end
 
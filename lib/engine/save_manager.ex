@@ -66,6 +66,17 @@ defmodule Engine.SaveManager do
           snapshot = :erlang.binary_to_term(binary)
           # 1.7.5: 旧セーブとの互換性（kill_count が無い場合は 0 で補う）
           snapshot = Map.put_new(snapshot, :kill_count, 0)
+          # 旧セーブとの互換性（enemies が無い場合は敵なしで再開）
+          snapshot = Map.put_new(snapshot, :enemies, [])
+          # 旧セーブとの互換性（passive_slots が無い場合はパッシブなし）
+          snapshot = Map.put_new(snapshot, :passive_slots, [])
+          # 旧セーブとの互換性（rng が無い場合はワールドの乱数をそのまま使う）
+          snapshot = Map.put_new(snapshot, :rng, nil)
+          # 旧セーブとの互換性（is_elite が無い敵は通常敵として復元）
+          snapshot =
+            Map.update!(snapshot, :enemies, fn enemies ->
+              Enum.map(enemies, &Map.put_new(&1, :is_elite, false))
+            end)
           Snapshots.load_save_snapshot(world_ref, snapshot)
           :ok
         rescue
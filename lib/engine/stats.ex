@@ -10,6 +10,7 @@ defmodule Engine.Stats do
 
   ## 収集する統計
   - 敵タイプ別撃破数
+  - エリート撃破数
   - 武器別撃破数
   - セッション開始時刻
   - アイテム収集数
@@ -60,10 +61,12 @@ defmodule Engine.Stats do
     new_state =
       Enum.reduce(events, state, fn
         {:enemy_killed, enemy_kind, weapon_kind}, acc ->
+          count_kill(acc, enemy_kind, weapon_kind)
+
+        {:elite_enemy_killed, enemy_kind, weapon_kind}, acc ->
           acc
-          |> Map.update(:kills_by_enemy, %{enemy_kind => 1}, &Map.update(&1, enemy_kind, 1, fn n -> n + 1 end))
-          |> Map.update(:kills_by_weapon, %{weapon_kind => 1}, &Map.update(&1, weapon_kind, 1, fn n -> n + 1 end))
-          |> Map.update(:total_kills, 1, &(&1 + 1))
+          |> count_kill(enemy_kind, weapon_kind)
+          |> Map.update(:elite_kills, 1, &(&1 + 1))
 
         {:level_up_event, new_level, _}, acc ->
           Map.put(acc, :max_level_reached, new_level)
@@ -113,6 +116,7 @@ defmodule Engine.Stats do
     summary = %{
       elapsed_seconds:  elapsed_s,
       total_kills:      state.total_kills,
+      elite_kills:      state.elite_kills,
       kills_by_enemy:   state.kills_by_enemy,
       kills_by_weapon:  state.kills_by_weapon,
       items_collected:  state.items_collected,
@@ -124,10 +128,18 @@ defmodule Engine.Stats do
 
   # ── Private ───────────────────────────────────────────────────
 
+  defp count_kill(acc, enemy_kind, weapon_kind) do
+    acc
+    |> Map.update(:kills_by_enemy, %{enemy_kind => 1}, &Map.update(&1, enemy_kind, 1, fn n -> n + 1 end))
+    |> Map.update(:kills_by_weapon, %{weapon_kind => 1}, &Map.update(&1, weapon_kind, 1, fn n -> n + 1 end))
+    |> Map.update(:total_kills, 1, &(&1 + 1))
+  end
+
   defp initial_state do
     %{
       session_start_ms: System.monotonic_time(:millisecond),
       total_kills:      0,
+      elite_kills:      0,
       kills_by_enemy:   %{},
       kills_by_weapon:  %{},
       items_collected:  %{},
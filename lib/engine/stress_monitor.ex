@@ -67,11 +67,9 @@ defmodule Engine.StressMonitor do
         enemy_count:  enemy_count,
         bullet_count: bullet_count,
         physics_ms:   physics_ms,
-        hud_data:     {hp, max_hp, score, elapsed_s},
+        hud_data:     {hp, max_hp, score, _elapsed_s},
+        wave:         wave,
       }} ->
-        game_module = Application.get_env(:game, :current, Game.VampireSurvivor)
-        wave = game_module.wave_label(elapsed_s)
-
         overrun = physics_ms > @frame_budget_ms
 
         new_state = %{state |
@@ -24,7 +24,7 @@ defmodule Game.MiniShooter do
   def entity_registry do
     %{
       enemies: %{slime: 0},
-      weapons: %{magic_wand: 0},
+      weapons: %{blaster: 0},
       bosses: %{slime_king: 0}
     }
   end
@@ -46,6 +46,10 @@ defmodule Game.MiniShooter do
   @impl Engine.Game
   def assets_path, do: "mini_shooter"
 
+  # 武器 blaster は Rust 側の magic_wand 挙動を流用する
+  @impl Engine.Game
+  def entity_params_path, do: "assets/mini_shooter/entity_params.json"
+
   # ── GameEvents / StressMonitor が参照するオプションAPI ──────────────
   # ヴァンサバと同様のインターフェース。レベルアップなしのためスタブを返す。
 
@@ -21,15 +21,18 @@ defmodule Game.VampireSurvivor do
     ]
   end
 
-  # 1.4.7: 敵・武器・ボスの ID マッピング（Rust の u8 ID に相当）
+  # 1.4.7: 敵・武器・ボス・パッシブの ID マッピング（Rust の u8 ID に相当）
+  # 武器は entity_params の武器テーブルから引く（定義を entity_params_path/0 に一本化）
   @impl Engine.Game
   def entity_registry do
     %{
       enemies: %{slime: 0, bat: 1, golem: 2, skeleton: 3, ghost: 4},
-      weapons: %{
-        magic_wand: 0, axe: 1, cross: 2, whip: 3, fireball: 4, lightning: 5, garlic: 6
-      },
-      bosses: %{slime_king: 0, bat_lord: 1, stone_golem: 2}
+      weapons: Engine.weapon_ids(),
+      bosses: %{slime_king: 0, bat_lord: 1, stone_golem: 2},
+      passives: %{
+        spinach: 0, armor: 1, hollow_heart: 2, empty_tome: 3, wings: 4, attractorb: 5,
+        clover: 6, duplicator: 7
+      }
     }
   end
 
@@ -51,6 +54,14 @@ defmodule Game.VampireSurvivor do
   @impl Engine.Game
   def assets_path, do: "vampire_survivor"
 
+  # 敵・武器・ボスのパラメータテーブル（敵・ボスの ID は entity_registry と一致させる）
+  @impl Engine.Game
+  def entity_params_path, do: "assets/vampire_survivor/entity_params.json"
+
+  # 敵のウェーブ定義（Rust 側のスポーンディレクターが使用）
+  @impl Engine.Game
+  def spawn_schedule_path, do: "assets/vampire_survivor/spawn_schedule.json"
+
   # ── Vampire Survivor 固有（シーン遷移等で GameEvents が参照）──
 
   @doc "レベルアップ武器選択シーンのモジュール"
@@ -62,7 +73,7 @@ defmodule Game.VampireSurvivor do
   @doc "ゲームオーバーシーンのモジュール"
   def game_over_scene, do: Game.VampireSurvivor.Scenes.GameOver
 
-  @doc "StressMonitor / GameEvents のログ用ウェーブラベル"
+  @doc "スポーンスケジュールにウェーブ名がないときのログ用ウェーブラベル"
   def wave_label(elapsed_sec), do: Game.VampireSurvivor.SpawnSystem.wave_label(elapsed_sec)
 
   @doc "武器の表示用ラベル（ログ用）"
@@ -49,10 +49,11 @@ defmodule Game.VampireSurvivor.Scenes.Playing do
 
           if level_up_pending do
             :telemetry.execute([:game, :level_up], %{level: level, count: 1}, %{})
-            choices = Game.VampireSurvivor.LevelSystem.generate_weapon_choices(weapon_levels)
+            # 武器とパッシブの選択肢は Rust 側（描画スレッドのカードと同じもの）を使う
+            choices = Engine.get_level_up_choices(world_ref)
 
             if choices == [] do
-              Logger.info("[LEVEL UP] All weapons at max level — skipping weapon selection")
+              Logger.info("[LEVEL UP] No weapon or passive upgrades left — skipping selection")
               Engine.skip_level_up(world_ref)
               {:continue, state}
             else
@@ -75,8 +76,13 @@ defmodule Game.VampireSurvivor.Scenes.Playing do
               }, state)
             end
           else
-            new_last_spawn = Game.VampireSurvivor.SpawnSystem.maybe_spawn(world_ref, elapsed, last_spawn_ms)
-            {:continue, state, %{context_updates: %{last_spawn_ms: new_last_spawn}}}
+            # スケジュールを設定できていれば Rust 側のスポーンディレクター（spawn_schedule.json）が出す
+            if Map.get(context, :spawn_schedule_loaded, false) do
+              {:continue, state}
+            else
+              new_last_spawn = Game.VampireSurvivor.SpawnSystem.maybe_spawn(world_ref, elapsed, last_spawn_ms)
+              {:continue, state, %{context_updates: %{last_spawn_ms: new_last_spawn}}}
+            end
           end
       end
     end
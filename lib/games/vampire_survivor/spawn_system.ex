@@ -7,6 +7,11 @@ defmodule Game.VampireSurvivor.SpawnSystem do
   Elixir handles the entire spawn schedule as pure data transformations:
   - Wave definitions are plain maps — no mutable state
   - `maybe_spawn/3` is a pure function: same inputs always produce same outputs
+
+  プレイ中の敵スポーンは通常 Rust 側のスポーンディレクターが行う
+  （`assets/vampire_survivor/spawn_schedule.json`、ウェーブ名もそこで定義する）。
+  このモジュールはスケジュールを読み込めなかった場合のフォールバックで、
+  `wave_label/1` もそのときのログ表示にだけ使う。
   """
 
   # 1.7.8 debug tuning:
@@ -1,5 +1,12 @@
 //! Path: native/game_core/src/boss.rs
-//! Summary: ボス種類・HP・行動の共通定義
+//! Summary: ボス種類・HP・行動の共通定義（フェーズ制の行動スクリプト）
+//!
+//! ボスの行動は BossParams::phases にフェーズの列として記述する。
+//! phases[0] が出現時のフェーズで、以降は HP 割合か経過時間の条件を満たすと順に移行する。
+
+use crate::bullet_pattern::BulletPattern;
+use serde::Deserialize;
+use std::borrow::Cow;
 
 /// ボスの種類（セーブデータ互換のため #[repr(u8)] と明示値で固定）
 #[derive(Clone, Copy, PartialEq, Debug)]
@@ -79,13 +86,132 @@ impl BossKind {
             Self::StoneGolem => 13,
         }
     }
+}
 
-    /// 特殊行動のインターバル（秒）
-    pub fn special_interval(&self) -> f32 {
-        match self {
-            Self::SlimeKing => 5.0,
-            Self::BatLord => 4.0,
-            Self::StoneGolem => 6.0,
+// ─── フェーズ制の行動スクリプト ─────────────────────────────────
+
+/// フェーズ中の移動方式
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum BossMovement {
+    /// プレイヤーへ直進する
+    Chase,
+    /// その場に留まる
+    Stationary,
+    /// プレイヤーから radius の距離を保って周回する
+    Orbit { radius: f32 },
+}
+
+/// フェーズ中に cooldown ごとに繰り返す攻撃
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum BossAttack {
+    /// ボスの周囲 radius の円周上に enemy（敵 ID）を count 体召喚する
+    Summon { enemy: u8, count: u32, radius: f32 },
+    /// プレイヤーへ speed で duration 秒突進する（突進中は無敵）
+    Dash { speed: f32, duration: f32 },
+    /// 弾幕パターンで敵弾（岩弾）を撃つ
+    Pattern(BulletPattern),
+}
+
+/// 攻撃と、その発動間隔
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+pub struct BossAttackSlot {
+    #[serde(flatten)]
+    pub attack:   BossAttack,
+    /// 発動間隔（秒）。フェーズ開始から cooldown 後に初回を撃つ
+    pub cooldown: f32,
+}
+
+/// ボスの 1 フェーズ
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct BossPhase {
+    /// HP 割合（hp / max_hp）がこの値以下になったら移行する（phases[0] では無視）
+    #[serde(default)]
+    pub hp_below:         Option<f32>,
+    /// 前のフェーズに入ってからこの秒数が経ったら移行する（phases[0] では無視）
+    #[serde(default)]
+    pub after_secs:       Option<f32>,
+    pub movement:         BossMovement,
+    /// BossParams::speed に掛ける倍率
+    #[serde(default = "default_speed_multiplier")]
+    pub speed_multiplier: f32,
+    #[serde(default)]
+    pub attacks:          Cow<'static, [BossAttackSlot]>,
+    /// このフェーズ中の描画色（乗算）。None なら通常色
+    #[serde(default)]
+    pub tint:             Option<[f32; 4]>,
+}
+
+fn default_speed_multiplier() -> f32 { 1.0 }
+
+impl BossPhase {
+    /// 次のフェーズへの移行条件を満たしているか
+    pub fn should_enter(&self, hp_ratio: f32, prev_phase_elapsed: f32) -> bool {
+        self.hp_below.is_some_and(|t| hp_ratio <= t)
+            || self.after_secs.is_some_and(|t| prev_phase_elapsed >= t)
+    }
+
+    /// 値の妥当性（entity_params の検証から呼ぶ）。
+    /// 召喚先の敵 ID は enemies テーブルを省略した場合もあるため検証せず、未登録なら召喚時に無視する
+    pub fn validate(&self, is_first: bool) -> Result<(), &'static str> {
+        if !is_first && self.hp_below.is_none() && self.after_secs.is_none() {
+            return Err("phases after the first need hp_below or after_secs");
+        }
+        if self.hp_below.is_some_and(|t| !(t > 0.0 && t <= 1.0)) {
+            return Err("phase hp_below must be within (0.0, 1.0]");
+        }
+        if self.after_secs.is_some_and(|t| !(t.is_finite() && t > 0.0)) {
+            return Err("phase after_secs must be > 0");
+        }
+        if !(self.speed_multiplier.is_finite() && self.speed_multiplier >= 0.0) {
+            return Err("phase speed_multiplier must be >= 0");
+        }
+        if let BossMovement::Orbit { radius } = self.movement {
+            if !(radius.is_finite() && radius > 0.0) { return Err("orbit radius must be > 0"); }
         }
+        for slot in self.attacks.iter() {
+            if !(slot.cooldown.is_finite() && slot.cooldown > 0.0) {
+                return Err("attack cooldown must be > 0");
+            }
+            match slot.attack {
+                BossAttack::Summon { count, radius, .. } => {
+                    if count == 0 { return Err("summon count must be > 0"); }
+                    if !(radius.is_finite() && radius >= 0.0) { return Err("summon radius must be >= 0"); }
+                }
+                BossAttack::Dash { speed, duration } => {
+                    if !(speed.is_finite() && speed > 0.0 && duration.is_finite() && duration > 0.0) {
+                        return Err("dash speed and duration must be > 0");
+                    }
+                }
+                BossAttack::Pattern(pattern) => pattern.validate()?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_enter_on_hp_or_elapsed_time() {
+        let phase = BossPhase {
+            hp_below:         Some(0.5),
+            after_secs:       Some(30.0),
+            movement:         BossMovement::Chase,
+            speed_multiplier: 1.0,
+            attacks:          Cow::Borrowed(&[]),
+            tint:             None,
+        };
+        assert!(!phase.should_enter(0.8, 10.0));
+        assert!(phase.should_enter(0.5, 10.0));
+        assert!(phase.should_enter(0.8, 30.0));
+        assert!(phase.validate(false).is_ok());
+
+        let no_trigger = BossPhase { hp_below: None, after_secs: None, ..phase };
+        assert!(no_trigger.validate(true).is_ok());
+        assert!(no_trigger.validate(false).is_err());
     }
 }
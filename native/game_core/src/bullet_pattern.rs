@@ -0,0 +1,179 @@
+//! Path: native/game_core/src/bullet_pattern.rs
+//! Summary: ボス・エリートが撃つ弾幕パターン（放射・螺旋・狙い撃ち拡散・隙間付きリング・波）
+//!
+//! パターンは発射する弾の (角度, 速度, 遅延) の列を生成するだけで、弾の生成は呼び出し側
+//! （game_native の BulletWorld）で行う。乱数は引数の SimpleRng からのみ引くため、
+//! 同じシードなら同じ弾列になる。
+
+use crate::physics::rng::SimpleRng;
+use serde::Deserialize;
+use std::f32::consts::TAU;
+
+/// 弾の並べ方
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+#[serde(tag = "shape", rename_all = "snake_case")]
+pub enum PatternShape {
+    /// 全方位に等間隔（rotation は絶対角度。0 なら右向きから）
+    Radial,
+    /// 1 発ごとに step ラジアンずつ回転させる（delay と組み合わせて渦を描く）
+    Spiral { step: f32 },
+    /// 狙い方向を中心に spread ラジアンの扇形へ等間隔
+    AimedSpread { spread: f32 },
+    /// 全方位に等間隔だが、連続する gap 発分を抜いて逃げ道を作る（抜く位置は乱数）
+    RingWithGap { gap: u32 },
+    /// 狙い方向を中心に amplitude ラジアンの幅で揺れる列（period 発で 1 周期）
+    Wave { amplitude: f32, period: u32 },
+}
+
+/// 弾幕パターン 1 回分の定義
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+pub struct BulletPattern {
+    #[serde(flatten)]
+    pub shape:    PatternShape,
+    /// 弾数
+    pub count:    u32,
+    /// 弾速（px/s）
+    pub speed:    f32,
+    /// 角度のオフセット（ラジアン）。Radial / Spiral / RingWithGap は絶対角度、
+    /// AimedSpread / Wave は狙い方向からの相対角度に加える
+    #[serde(default)]
+    pub rotation: f32,
+    /// n 発目を n × delay 秒遅らせて撃つ（0 なら全弾同時）
+    #[serde(default)]
+    pub delay:    f32,
+    /// 1 発ごとの角度のぶれ（±jitter ラジアン。0 なら乱数を消費しない）
+    #[serde(default)]
+    pub jitter:   f32,
+    /// 1 ヒットあたりのダメージ
+    pub damage:   i32,
+    /// 弾の寿命（秒）
+    pub lifetime: f32,
+}
+
+/// パターンが生成する 1 発分
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PatternShot {
+    /// 発射角度（ラジアン）
+    pub angle: f32,
+    pub speed: f32,
+    /// 発射までの遅延（秒）
+    pub delay: f32,
+}
+
+impl BulletPattern {
+    /// 弾列を生成して out に積む。aim はプレイヤーへの角度（ラジアン）
+    pub fn generate(&self, aim: f32, rng: &mut SimpleRng, out: &mut Vec<PatternShot>) {
+        let count = self.count;
+        let even = |i: u32| self.rotation + i as f32 * TAU / count as f32;
+        let gap_start = match self.shape {
+            PatternShape::RingWithGap { .. } if count > 0 => rng.next_u32() % count,
+            _ => 0,
+        };
+
+        for i in 0..count {
+            let angle = match self.shape {
+                PatternShape::Radial => even(i),
+                PatternShape::Spiral { step } => self.rotation + i as f32 * step,
+                PatternShape::AimedSpread { spread } => {
+                    let t = if count > 1 { i as f32 / (count - 1) as f32 - 0.5 } else { 0.0 };
+                    aim + self.rotation + t * spread
+                }
+                PatternShape::RingWithGap { gap } => {
+                    // gap_start から gap 発分（円周を跨いでもよい）を抜く
+                    if (i + count - gap_start) % count < gap {
+                        continue;
+                    }
+                    even(i)
+                }
+                PatternShape::Wave { amplitude, period } => {
+                    let phase = TAU * i as f32 / period.max(1) as f32;
+                    aim + self.rotation + amplitude * phase.sin()
+                }
+            };
+            let jitter = if self.jitter > 0.0 { (rng.next_f32() * 2.0 - 1.0) * self.jitter } else { 0.0 };
+            out.push(PatternShot {
+                angle: angle + jitter,
+                speed: self.speed,
+                delay: i as f32 * self.delay,
+            });
+        }
+    }
+
+    /// 値の妥当性（entity_params の検証から呼ぶ）
+    pub fn validate(&self) -> Result<(), &'static str> {
+        if self.count == 0 {
+            return Err("pattern count must be > 0");
+        }
+        if !(self.speed.is_finite() && self.speed > 0.0 && self.lifetime.is_finite() && self.lifetime > 0.0) {
+            return Err("pattern speed and lifetime must be > 0");
+        }
+        if self.damage < 0 {
+            return Err("pattern damage must be >= 0");
+        }
+        if !(self.rotation.is_finite() && self.delay.is_finite() && self.delay >= 0.0) {
+            return Err("pattern rotation must be finite and delay >= 0");
+        }
+        if !(self.jitter.is_finite() && self.jitter >= 0.0) {
+            return Err("pattern jitter must be >= 0");
+        }
+        match self.shape {
+            PatternShape::Spiral { step } if !step.is_finite() => Err("spiral step must be finite"),
+            PatternShape::AimedSpread { spread } if !(spread.is_finite() && spread >= 0.0) => {
+                Err("aimed_spread spread must be >= 0")
+            }
+            PatternShape::RingWithGap { gap } if gap >= self.count => Err("ring_with_gap gap must be < count"),
+            PatternShape::Wave { amplitude, period } if !amplitude.is_finite() || period == 0 => {
+                Err("wave amplitude must be finite and period > 0")
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pattern(shape: PatternShape, count: u32) -> BulletPattern {
+        BulletPattern { shape, count, speed: 100.0, rotation: 0.0, delay: 0.0, jitter: 0.0, damage: 5, lifetime: 2.0 }
+    }
+
+    fn shots(p: &BulletPattern, aim: f32, seed: u64) -> Vec<PatternShot> {
+        let mut out = Vec::new();
+        p.generate(aim, &mut SimpleRng::new(seed), &mut out);
+        out
+    }
+
+    #[test]
+    fn radial_and_spread_angles() {
+        let radial = shots(&pattern(PatternShape::Radial, 4), 1.0, 1);
+        let angles: Vec<f32> = radial.iter().map(|s| s.angle).collect();
+        for (a, want) in angles.iter().zip([0.0, TAU / 4.0, TAU / 2.0, TAU * 3.0 / 4.0]) {
+            assert!((a - want).abs() < 1e-5);
+        }
+
+        let spread = shots(&pattern(PatternShape::AimedSpread { spread: 1.0 }, 3), 2.0, 1);
+        assert!((spread[0].angle - 1.5).abs() < 1e-5);
+        assert!((spread[1].angle - 2.0).abs() < 1e-5);
+        assert!((spread[2].angle - 2.5).abs() < 1e-5);
+    }
+
+    #[test]
+    fn spiral_delays_each_shot() {
+        let p = BulletPattern { delay: 0.1, ..pattern(PatternShape::Spiral { step: 0.5 }, 3) };
+        let out = shots(&p, 0.0, 1);
+        assert!((out[2].angle - 1.0).abs() < 1e-5);
+        assert!((out[2].delay - 0.2).abs() < 1e-5);
+    }
+
+    #[test]
+    fn ring_gap_and_jitter_are_deterministic_per_seed() {
+        let p = BulletPattern { jitter: 0.2, ..pattern(PatternShape::RingWithGap { gap: 3 }, 12) };
+        let a = shots(&p, 0.0, 42);
+        assert_eq!(a.len(), 9);
+        assert_eq!(a, shots(&p, 0.0, 42));
+        assert_ne!(a, shots(&p, 0.0, 43));
+        assert!(p.validate().is_ok());
+        assert!(BulletPattern { count: 3, ..p }.validate().is_err());
+    }
+}
@@ -14,10 +14,8 @@ pub const SCREEN_WIDTH:  f32 = 1280.0;
 pub const SCREEN_HEIGHT: f32 = 720.0;
 
 // Map size (1.2.5: camera scroll)
-// Used by game_window binary; NIF lib uses fixed screen coordinates.
-#[allow(dead_code)]
+// Default WorldBounds; the loaded map can override it via set_world_size.
 pub const MAP_WIDTH:  f32 = 4096.0;
-#[allow(dead_code)]
 pub const MAP_HEIGHT: f32 = 4096.0;
 
 // Camera lerp speed (1.2.5)
@@ -31,6 +29,10 @@ pub const PLAYER_SIZE:  f32 = SPRITE_SIZE;
 // Movement
 pub const PLAYER_SPEED: f32 = 200.0;
 
+// Player base stats（パッシブアイテム補正前の値。PlayerStats の基準）
+pub const PLAYER_BASE_MAX_HP: f32 = 100.0;
+pub const PICKUP_RADIUS:      f32 = 60.0;
+
 // Frame budget (used by lib.rs NIF; not all binaries reference it)
 #[allow(dead_code)]
 pub const FRAME_BUDGET_MS: f64 = 1000.0 / 60.0;
@@ -40,6 +42,10 @@ pub const PLAYER_RADIUS: f32 = PLAYER_SIZE / 2.0;
 pub const ENEMY_RADIUS:  f32 = 20.0;
 pub const BULLET_RADIUS: f32 = 6.0;
 
+// Spawn ring: 敵はプレイヤー中心からこの距離の円環（画面外）に出現する
+pub const SPAWN_MIN_DIST: f32 = 800.0;
+pub const SPAWN_MAX_DIST: f32 = 1200.0;
+
 // Enemy separation: 敵同士が重ならないための押し出し半径・強さ
 pub const ENEMY_SEPARATION_RADIUS: f32 = ENEMY_RADIUS * 2.0;
 pub const ENEMY_SEPARATION_FORCE:  f32 = 120.0;
@@ -53,16 +59,22 @@ pub const BULLET_SPEED:         f32 = 400.0;
 pub const BULLET_DAMAGE:        i32 = 10;
 pub const BULLET_LIFETIME:      f32 = 3.0;
 
+// Knockback: ノックバック速度の減衰率（1/秒）。のけぞり中は v *= exp(-DAMPING * dt)
+pub const KNOCKBACK_DAMPING: f32 = 10.0;
+
+// 会心ヒット時のダメージ倍率（会心率は武器ごとの crit_chance）
+pub const CRIT_MULTIPLIER: f32 = 2.0;
+
 // Spatial hash cell size
 pub const CELL_SIZE: f32 = 80.0;
 
-/// パーティクル用 RNG シード（create_world / load_save_snapshot 等で使用）
-pub const PARTICLE_RNG_SEED: u64 = 67890;
-
 /// 武器の最近接敵探索半径（MagicWand / Fireball / Lightning 用）
 #[allow(dead_code)] // lib で使用、bin (game_window) では未使用（main.rs 空間ハッシュ化で使用予定）
 pub const WEAPON_SEARCH_RADIUS: f32 = SCREEN_WIDTH / 2.0;
 
+// 同時に存在できるボスの上限（spawn_boss はこれを超えると何もしない）
+pub const MAX_BOSSES: usize = 3;
+
 // Enemy cap (used by game_window binary; not referenced by the NIF lib)
 #[allow(dead_code)]
 pub const MAX_ENEMIES: usize = 300;
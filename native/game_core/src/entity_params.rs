@@ -1,11 +1,26 @@
 //! Path: native/game_core/src/entity_params.rs
-//! Summary: 敵・武器・ボスの ID ベースパラメータテーブル
+//! Summary: 敵・武器・ボス・パッシブ・武器進化の ID ベースパラメータテーブル（実行時差し替え可能なレジストリ）
 //!
 //! 1.4.7: EnemyKind / WeaponKind / BossKind の enum を増やさずに、
 //! u8 ID でパラメータを参照するテーブルを提供する。
+//!
+//! コンテンツファイル（JSON）から読み込んだテーブルで実行時に差し替えられる。
+//! 差し替えるまでは組み込みのデフォルトテーブル（ヴァンサバ）を参照する。
+
+use crate::boss::{BossAttack, BossAttackSlot, BossMovement, BossPhase};
+use crate::bullet_pattern::{BulletPattern, PatternShape};
+use crate::item::ItemKind;
+use crate::loot::{LootEntry, LootTable, BOSS_DEFAULT_LOOT, ENEMY_DEFAULT_LOOT};
+use crate::player_stats::PlayerStat;
+use crate::status_effect::{StatusApply, StatusKind};
+use serde::Deserialize;
+use std::borrow::Cow;
+use std::fmt;
+use std::sync::atomic::{AtomicPtr, Ordering};
 
 /// 敵のパラメータ（ID で参照）
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct EnemyParams {
     pub max_hp:       f32,
     pub speed:        f32,
@@ -15,8 +30,25 @@ pub struct EnemyParams {
     pub render_kind:  u8,
     /// パーティクル色 [r, g, b, a]
     pub particle_color: [f32; 4],
+    /// true の敵は障害物をすり抜ける（1.5.2: Ghost）
+    #[serde(default)]
+    pub passes_obstacles: bool,
+    /// ノックバックの受けにくさ（速度 = 武器の knockback / mass）
+    #[serde(default = "default_mass")]
+    pub mass:         f32,
+    /// 被ダメージ軽減率（0.0..1.0）。apply_damage で乗算する
+    #[serde(default)]
+    pub resistance:   f32,
+    /// 撃破時のドロップ（省略時は ENEMY_DEFAULT_LOOT）
+    #[serde(default = "default_enemy_loot")]
+    pub loot:         LootTable,
 }
 
+fn default_mass() -> f32 { 1.0 }
+fn default_enemy_loot() -> LootTable { ENEMY_DEFAULT_LOOT }
+fn default_boss_loot() -> LootTable { BOSS_DEFAULT_LOOT }
+fn default_boss_phases() -> Cow<'static, [BossPhase]> { Cow::Borrowed(&CHASE_ONLY_PHASES) }
+
 /// ヴァンサバの敵 ID: 0=Slime, 1=Bat, 2=Golem, 3=Skeleton, 4=Ghost（壁すり抜け）
 pub const ENEMY_ID_SLIME: u8 = 0;
 pub const ENEMY_ID_BAT:   u8 = 1;
@@ -24,36 +56,131 @@ pub const ENEMY_ID_GOLEM: u8 = 2;
 pub const ENEMY_ID_SKELETON: u8 = 3;
 pub const ENEMY_ID_GHOST: u8 = 4;
 
+/// Golem は倒しにくい分、宝箱と回復を落としやすい
+const GOLEM_LOOT: LootTable = LootTable {
+    entries: Cow::Borrowed(&[
+        LootEntry { item: ItemKind::Chest,  weight: 20,  value: Some([100, 100]), luck_scaled: false },
+        LootEntry { item: ItemKind::Magnet, weight: 20,  value: Some([0, 0]),     luck_scaled: true },
+        LootEntry { item: ItemKind::Potion, weight: 100, value: Some([20, 40]),   luck_scaled: true },
+        LootEntry { item: ItemKind::Gem,    weight: 860, value: None,             luck_scaled: false },
+    ]),
+    none_weight: 0,
+    guaranteed:  Cow::Borrowed(&[]),
+    scatter:     0.0,
+};
+
 static ENEMY_TABLE: [EnemyParams; 5] = [
-    EnemyParams { max_hp: 30.0,   speed: 80.0,  radius: 20.0, exp_reward: 5,  damage_per_sec: 20.0, render_kind: 1, particle_color: [1.0, 0.5, 0.1, 1.0] },   // Slime
-    EnemyParams { max_hp: 15.0,   speed: 160.0, radius: 12.0, exp_reward: 3,  damage_per_sec: 10.0, render_kind: 2, particle_color: [0.7, 0.2, 0.9, 1.0] },   // Bat
-    EnemyParams { max_hp: 150.0,  speed: 40.0,  radius: 32.0, exp_reward: 20, damage_per_sec: 40.0, render_kind: 3, particle_color: [0.6, 0.6, 0.6, 1.0] },   // Golem
-    EnemyParams { max_hp: 60.0,   speed: 60.0,  radius: 22.0, exp_reward: 10, damage_per_sec: 15.0, render_kind: 5, particle_color: [0.9, 0.85, 0.7, 1.0] },  // Skeleton（高HP）
-    EnemyParams { max_hp: 40.0,   speed: 100.0, radius: 16.0, exp_reward: 8,  damage_per_sec: 12.0, render_kind: 4, particle_color: [0.5, 0.5, 1.0, 0.8] },   // Ghost（壁すり抜け）
+    EnemyParams { max_hp: 30.0,   speed: 80.0,  radius: 20.0, exp_reward: 5,  damage_per_sec: 20.0, render_kind: 1, particle_color: [1.0, 0.5, 0.1, 1.0],  passes_obstacles: false, mass: 1.0, resistance: 0.0, loot: ENEMY_DEFAULT_LOOT },  // Slime
+    EnemyParams { max_hp: 15.0,   speed: 160.0, radius: 12.0, exp_reward: 3,  damage_per_sec: 10.0, render_kind: 2, particle_color: [0.7, 0.2, 0.9, 1.0],  passes_obstacles: false, mass: 0.6, resistance: 0.0, loot: ENEMY_DEFAULT_LOOT },  // Bat
+    EnemyParams { max_hp: 150.0,  speed: 40.0,  radius: 32.0, exp_reward: 20, damage_per_sec: 40.0, render_kind: 3, particle_color: [0.6, 0.6, 0.6, 1.0],  passes_obstacles: false, mass: 4.0, resistance: 0.0, loot: GOLEM_LOOT },  // Golem
+    EnemyParams { max_hp: 60.0,   speed: 60.0,  radius: 22.0, exp_reward: 10, damage_per_sec: 15.0, render_kind: 5, particle_color: [0.9, 0.85, 0.7, 1.0], passes_obstacles: false, mass: 1.5, resistance: 0.0, loot: ENEMY_DEFAULT_LOOT },  // Skeleton（高HP）
+    EnemyParams { max_hp: 40.0,   speed: 100.0, radius: 16.0, exp_reward: 8,  damage_per_sec: 12.0, render_kind: 4, particle_color: [0.5, 0.5, 1.0, 0.8],  passes_obstacles: true,  mass: 0.8, resistance: 0.0, loot: ENEMY_DEFAULT_LOOT },  // Ghost（壁すり抜け）
 ];
 
 impl EnemyParams {
     pub fn get(id: u8) -> &'static EnemyParams {
-        ENEMY_TABLE.get(id as usize).expect("Invalid enemy ID")
+        enemy_table().get(id as usize).expect("Invalid enemy ID")
     }
 
     /// Ghost は障害物をすり抜ける（1.5.2）
     pub fn passes_through_obstacles(id: u8) -> bool {
-        id == ENEMY_ID_GHOST
+        Self::get(id).passes_obstacles
+    }
+
+    /// テーブル中の最大半径（衝突クエリ半径の算出用。エリートの拡大を含む）
+    pub fn max_radius() -> f32 {
+        let base = enemy_table().iter().map(|p| p.radius).fold(0.0, f32::max);
+        base * EliteParams::get().size_multiplier.max(1.0)
+    }
+}
+
+// ─── EliteParams ───────────────────────────────────────────────
+
+/// エリート敵の補正（全敵種共通。通常敵のパラメータに掛ける）
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EliteParams {
+    /// 最大 HP 倍率（spawn_elite_enemy で倍率を指定しない場合に使う）
+    pub hp_multiplier:     f32,
+    /// 接触ダメージ倍率
+    pub damage_multiplier: f32,
+    /// 当たり判定・スプライトの拡大率
+    pub size_multiplier:   f32,
+    /// 撃破時の経験値・スコア倍率
+    pub exp_multiplier:    f32,
+    /// ルートテーブルの抽選回数
+    pub loot_rolls:        u32,
+    /// cooldown ごとにプレイヤーへ撃つ弾幕（None なら撃たない）
+    #[serde(default)]
+    pub attack:            Option<EliteAttack>,
+}
+
+/// エリートの弾幕攻撃
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+pub struct EliteAttack {
+    #[serde(flatten)]
+    pub pattern:  BulletPattern,
+    /// 発動間隔（秒）。出現から cooldown 後に初回を撃つ
+    pub cooldown: f32,
+}
+
+static ELITE_PARAMS: EliteParams = EliteParams {
+    hp_multiplier:     3.0,
+    damage_multiplier: 1.5,
+    size_multiplier:   1.2,
+    exp_multiplier:    3.0,
+    loot_rolls:        3,
+    attack:            Some(EliteAttack {
+        pattern: BulletPattern {
+            shape: PatternShape::AimedSpread { spread: 0.5 }, count: 3, speed: 160.0, rotation: 0.0, delay: 0.0, jitter: 0.0, damage: 8, lifetime: 3.0,
+        },
+        cooldown: 5.0,
+    }),
+};
+
+impl Default for EliteParams {
+    fn default() -> Self {
+        ELITE_PARAMS
+    }
+}
+
+impl EliteParams {
+    pub fn get() -> &'static EliteParams {
+        installed().map_or(&ELITE_PARAMS, |t| &t.elite)
     }
 }
 
 // ─── WeaponParams ───────────────────────────────────────────────
 
 /// 武器のパラメータ（ID で参照）
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct WeaponParams {
     pub cooldown:     f32,
     pub damage:       i32,
     pub as_u8:        u8,
-    pub name:         &'static str,
+    pub name:         Cow<'static, str>,
+    /// 選択肢・ログに出す表示名（None なら name）
+    #[serde(default)]
+    pub label:        Option<Cow<'static, str>>,
     /// bullet_count_table: [0]=未使用, [1..8]=Lv1..Lv8 の弾数。None の場合は固定 1 発
-    pub bullet_table: Option<&'static [usize]>,
+    #[serde(default)]
+    pub bullet_table: Option<Cow<'static, [usize]>>,
+    /// 発射挙動（WeaponBehavior）の名前。None の場合は name と同名の挙動を使う
+    #[serde(default)]
+    pub behavior:     Option<Cow<'static, str>>,
+    /// ヒットした敵に付与する状態異常（None なら付与しない）
+    #[serde(default)]
+    pub on_hit:       Option<StatusApply>,
+    /// ヒット時のノックバック速度（px/s、mass 1.0 の敵に対する値）
+    #[serde(default)]
+    pub knockback:    f32,
+    /// ヒット時ののけぞり時間（秒）。この間は Chase AI の追尾を止める
+    #[serde(default)]
+    pub hit_stun:     f32,
+    /// 会心率（0.0..1.0）。会心時は CRIT_MULTIPLIER 倍
+    #[serde(default)]
+    pub crit_chance:  f32,
 }
 
 /// Whip の範囲: 120 + (level - 1) * 20
@@ -73,33 +200,83 @@ pub const WEAPON_ID_WHIP:      u8 = 3;
 pub const WEAPON_ID_FIREBALL:  u8 = 4;
 pub const WEAPON_ID_LIGHTNING: u8 = 5;
 pub const WEAPON_ID_GARLIC:    u8 = 6;
+pub const WEAPON_ID_FROST_SHARD: u8 = 7;
+/// 進化後の武器（レベルアップの選択肢には出ない。EvolutionParams 参照）
+pub const WEAPON_ID_HOLY_WAND:    u8 = 8;
+pub const WEAPON_ID_DEATH_SPIRAL: u8 = 9;
+pub const WEAPON_ID_BLOODY_TEAR:  u8 = 10;
+pub const WEAPON_ID_HELLFIRE:     u8 = 11;
+pub const WEAPON_ID_SOUL_EATER:   u8 = 12;
 
 static MW_TABLE: [usize; 9] = [0, 1, 1, 2, 2, 3, 3, 4, 4];
 static CROSS_TABLE: [usize; 9] = [0, 4, 4, 4, 8, 8, 8, 8, 8];
+static HOLY_WAND_TABLE: [usize; 9] = [0, 3, 3, 4, 4, 5, 5, 6, 6];
+
+/// bullet_table の要素数（[0] + Lv1..Lv8）
+const BULLET_TABLE_LEN: usize = 9;
 
 /// Garlic のオーラ半径（px）: 80 + (level - 1) * 15
 pub fn garlic_radius(_weapon_id: u8, level: u32) -> f32 {
     80.0 + (level as f32 - 1.0) * 15.0
 }
 
-static WEAPON_TABLE: [WeaponParams; 7] = [
-    WeaponParams { cooldown: 1.0,  damage: 10, as_u8: 0, name: "magic_wand", bullet_table: Some(&MW_TABLE) },
-    WeaponParams { cooldown: 1.5,  damage: 25, as_u8: 1, name: "axe",        bullet_table: None },
-    WeaponParams { cooldown: 2.0,  damage: 15, as_u8: 2, name: "cross",      bullet_table: Some(&CROSS_TABLE) },
-    WeaponParams { cooldown: 1.0,  damage: 30, as_u8: 3, name: "whip",       bullet_table: None },
-    WeaponParams { cooldown: 1.0,  damage: 20, as_u8: 4, name: "fireball",   bullet_table: None },
-    WeaponParams { cooldown: 1.0,  damage: 15, as_u8: 5, name: "lightning",  bullet_table: None },
-    WeaponParams { cooldown: 0.2,  damage: 1,  as_u8: 6, name: "garlic",     bullet_table: None },
+static WEAPON_TABLE: [WeaponParams; 13] = [
+    WeaponParams { cooldown: 1.0,  damage: 10, as_u8: 0, name: Cow::Borrowed("magic_wand"), label: Some(Cow::Borrowed("Magic Wand (auto-aim)")), bullet_table: Some(Cow::Borrowed(&MW_TABLE)), behavior: None, on_hit: None, knockback: 120.0, hit_stun: 0.1, crit_chance: 0.0 },
+    WeaponParams { cooldown: 1.5,  damage: 25, as_u8: 1, name: Cow::Borrowed("axe"),        label: Some(Cow::Borrowed("Axe (upward throw)")), bullet_table: None, behavior: None, on_hit: None, knockback: 220.0, hit_stun: 0.2, crit_chance: 0.0 },
+    WeaponParams { cooldown: 2.0,  damage: 15, as_u8: 2, name: Cow::Borrowed("cross"),      label: Some(Cow::Borrowed("Cross (4-way fire)")), bullet_table: Some(Cow::Borrowed(&CROSS_TABLE)), behavior: None, on_hit: None, knockback: 150.0, hit_stun: 0.12, crit_chance: 0.0 },
+    WeaponParams { cooldown: 1.0,  damage: 30, as_u8: 3, name: Cow::Borrowed("whip"),       label: Some(Cow::Borrowed("Whip (fan sweep)")), bullet_table: None, behavior: None, on_hit: None, knockback: 320.0, hit_stun: 0.25, crit_chance: 0.0 },
+    WeaponParams { cooldown: 1.0,  damage: 20, as_u8: 4, name: Cow::Borrowed("fireball"),   label: Some(Cow::Borrowed("Fireball (piercing)")), bullet_table: None, behavior: None,
+                   on_hit: Some(StatusApply { kind: StatusKind::Burn, duration: 3.0, potency: 6.0 }), knockback: 80.0, hit_stun: 0.05, crit_chance: 0.0 },
+    WeaponParams { cooldown: 1.0,  damage: 15, as_u8: 5, name: Cow::Borrowed("lightning"),  label: Some(Cow::Borrowed("Lightning (chain)")), bullet_table: None, behavior: None, on_hit: None, knockback: 0.0, hit_stun: 0.0, crit_chance: 0.0 },
+    WeaponParams { cooldown: 0.2,  damage: 1,  as_u8: 6, name: Cow::Borrowed("garlic"),     label: Some(Cow::Borrowed("Garlic (aura damage)")), bullet_table: None, behavior: None,
+                   on_hit: Some(StatusApply { kind: StatusKind::Weaken, duration: 0.5, potency: 0.3 }), knockback: 60.0, hit_stun: 0.05, crit_chance: 0.0 },
+    // 魔法の杖と同じ発射挙動で、当たった敵を鈍足にする
+    WeaponParams { cooldown: 1.2,  damage: 8,  as_u8: 7, name: Cow::Borrowed("frost_shard"), label: Some(Cow::Borrowed("Frost Shard (slow)")), bullet_table: None, behavior: Some(Cow::Borrowed("magic_wand")),
+                   on_hit: Some(StatusApply { kind: StatusKind::Slow, duration: 2.0, potency: 0.5 }), knockback: 60.0, hit_stun: 0.1, crit_chance: 0.0 },
+    // ── 進化後の武器（挙動は進化元と同じで、性能だけ強化）──
+    WeaponParams { cooldown: 0.5,  damage: 15, as_u8: 8, name: Cow::Borrowed("holy_wand"),  label: Some(Cow::Borrowed("Holy Wand (evolved)")), bullet_table: Some(Cow::Borrowed(&HOLY_WAND_TABLE)), behavior: Some(Cow::Borrowed("magic_wand")), on_hit: None, knockback: 120.0, hit_stun: 0.1, crit_chance: 0.0 },
+    WeaponParams { cooldown: 1.2,  damage: 45, as_u8: 9, name: Cow::Borrowed("death_spiral"), label: Some(Cow::Borrowed("Death Spiral (evolved)")), bullet_table: None, behavior: Some(Cow::Borrowed("axe")), on_hit: None, knockback: 260.0, hit_stun: 0.2, crit_chance: 0.0 },
+    WeaponParams { cooldown: 0.8,  damage: 50, as_u8: 10, name: Cow::Borrowed("bloody_tear"), label: Some(Cow::Borrowed("Bloody Tear (evolved)")), bullet_table: None, behavior: Some(Cow::Borrowed("whip")), on_hit: None, knockback: 340.0, hit_stun: 0.25, crit_chance: 0.0 },
+    WeaponParams { cooldown: 0.8,  damage: 40, as_u8: 11, name: Cow::Borrowed("hellfire"),  label: Some(Cow::Borrowed("Hellfire (evolved)")), bullet_table: None, behavior: Some(Cow::Borrowed("fireball")),
+                   on_hit: Some(StatusApply { kind: StatusKind::Burn, duration: 4.0, potency: 12.0 }), knockback: 100.0, hit_stun: 0.05, crit_chance: 0.0 },
+    WeaponParams { cooldown: 0.15, damage: 3,  as_u8: 12, name: Cow::Borrowed("soul_eater"), label: Some(Cow::Borrowed("Soul Eater (evolved)")), bullet_table: None, behavior: Some(Cow::Borrowed("garlic")),
+                   on_hit: Some(StatusApply { kind: StatusKind::Weaken, duration: 0.5, potency: 0.5 }), knockback: 80.0, hit_stun: 0.05, crit_chance: 0.0 },
 ];
 
 impl WeaponParams {
     pub fn get(id: u8) -> &'static WeaponParams {
-        WEAPON_TABLE.get(id as usize).expect("Invalid weapon ID")
+        weapon_table().get(id as usize).expect("Invalid weapon ID")
+    }
+
+    /// 登録外の ID なら None（セーブデータ復元など外部入力の ID 用）
+    pub fn try_get(id: u8) -> Option<&'static WeaponParams> {
+        weapon_table().get(id as usize)
+    }
+
+    /// 武器名から ID を引く（add_weapon / 選択肢の名前解決用）
+    pub fn find_id(name: &str) -> Option<u8> {
+        weapon_table().iter().position(|p| p.name == name).map(|i| i as u8)
+    }
+
+    /// 表示名（label 未指定なら武器名）
+    pub fn label(&self) -> &str {
+        self.label.as_deref().unwrap_or(&self.name)
+    }
+
+    /// 発射挙動の名前（behavior 未指定なら武器名）
+    pub fn behavior_name(&self) -> &str {
+        self.behavior.as_deref().unwrap_or(&self.name)
+    }
+
+    /// 登録済み武器の数（ID は 0..count）
+    pub fn count() -> usize {
+        weapon_table().len()
     }
 
     pub fn bullet_count(&self, level: u32) -> usize {
         let lv = level.clamp(1, 8) as usize;
         self.bullet_table
+            .as_ref()
             .and_then(|t| t.get(lv).copied())
             .unwrap_or(1)
     }
@@ -107,7 +284,8 @@ impl WeaponParams {
 
 // ─── BossParams ────────────────────────────────────────────────
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct BossParams {
     pub max_hp:           f32,
     pub speed:            f32,
@@ -115,23 +293,676 @@ pub struct BossParams {
     pub exp_reward:       u32,
     pub damage_per_sec:   f32,
     pub render_kind:      u8,
-    pub special_interval: f32,
     /// HUD 表示用のボス名
-    pub name:             &'static str,
+    pub name:             Cow<'static, str>,
+    /// 被ダメージ軽減率（0.0..1.0）
+    #[serde(default)]
+    pub resistance:       f32,
+    /// 撃破時のドロップ（省略時は BOSS_DEFAULT_LOOT）
+    #[serde(default = "default_boss_loot")]
+    pub loot:             LootTable,
+    /// 行動フェーズ（phases[0] が出現時。省略時はプレイヤーを追うだけ）
+    #[serde(default = "default_boss_phases")]
+    pub phases:           Cow<'static, [BossPhase]>,
 }
 
 pub const BOSS_ID_SLIME_KING:  u8 = 0;
 pub const BOSS_ID_BAT_LORD:    u8 = 1;
 pub const BOSS_ID_STONE_GOLEM: u8 = 2;
 
+/// 攻撃しないボス（phases 省略時）
+static CHASE_ONLY_PHASES: [BossPhase; 1] = [
+    BossPhase { hp_below: None, after_secs: None, movement: BossMovement::Chase, speed_multiplier: 1.0, attacks: Cow::Borrowed(&[]), tint: None },
+];
+
+/// Slime King: Slime を召喚し、HP 50% で激昂（速度・召喚数・頻度が上がり、螺旋状に岩弾も撃つ）
+static SLIME_KING_PHASES: [BossPhase; 2] = [
+    BossPhase {
+        hp_below: None, after_secs: None, movement: BossMovement::Chase, speed_multiplier: 1.0,
+        attacks: Cow::Borrowed(&[
+            BossAttackSlot { attack: BossAttack::Summon { enemy: ENEMY_ID_SLIME, count: 8, radius: 120.0 }, cooldown: 5.0 },
+        ]),
+        tint: None,
+    },
+    BossPhase {
+        hp_below: Some(0.5), after_secs: None, movement: BossMovement::Chase, speed_multiplier: 1.5,
+        attacks: Cow::Borrowed(&[
+            BossAttackSlot { attack: BossAttack::Summon { enemy: ENEMY_ID_SLIME, count: 12, radius: 140.0 }, cooldown: 3.5 },
+            BossAttackSlot {
+                attack: BossAttack::Pattern(BulletPattern {
+                    shape: PatternShape::Spiral { step: 0.4 }, count: 16, speed: 180.0, rotation: 0.0, delay: 0.05, jitter: 0.0, damage: 15, lifetime: 3.0,
+                }),
+                cooldown: 4.0,
+            },
+        ]),
+        tint: Some([1.0, 0.5, 0.5, 1.0]),
+    },
+];
+
+/// Bat Lord: 一定間隔で突進し、HP 30% で突進の間隔が縮み、扇状の狙い撃ちも加わる
+static BAT_LORD_PHASES: [BossPhase; 2] = [
+    BossPhase {
+        hp_below: None, after_secs: None, movement: BossMovement::Chase, speed_multiplier: 1.0,
+        attacks: Cow::Borrowed(&[
+            BossAttackSlot { attack: BossAttack::Dash { speed: 500.0, duration: 0.6 }, cooldown: 4.0 },
+        ]),
+        tint: None,
+    },
+    BossPhase {
+        hp_below: Some(0.3), after_secs: None, movement: BossMovement::Chase, speed_multiplier: 1.2,
+        attacks: Cow::Borrowed(&[
+            BossAttackSlot { attack: BossAttack::Dash { speed: 600.0, duration: 0.6 }, cooldown: 2.5 },
+            BossAttackSlot {
+                attack: BossAttack::Pattern(BulletPattern {
+                    shape: PatternShape::AimedSpread { spread: 0.8 }, count: 5, speed: 260.0, rotation: 0.0, delay: 0.0, jitter: 0.0, damage: 12, lifetime: 2.0,
+                }),
+                cooldown: 3.0,
+            },
+        ]),
+        tint: Some([1.0, 0.6, 1.0, 1.0]),
+    },
+];
+
+/// Stone Golem: 上下左右に岩弾を撃ち、HP 50% で足を止めて隙間付きのリングを連射する
+static STONE_GOLEM_PHASES: [BossPhase; 2] = [
+    BossPhase {
+        hp_below: None, after_secs: None, movement: BossMovement::Chase, speed_multiplier: 1.0,
+        attacks: Cow::Borrowed(&[
+            BossAttackSlot {
+                attack: BossAttack::Pattern(BulletPattern {
+                    shape: PatternShape::Radial, count: 4, speed: 200.0, rotation: 0.0, delay: 0.0, jitter: 0.0, damage: 25, lifetime: 3.0,
+                }),
+                cooldown: 6.0,
+            },
+        ]),
+        tint: None,
+    },
+    BossPhase {
+        hp_below: Some(0.5), after_secs: None, movement: BossMovement::Stationary, speed_multiplier: 1.0,
+        attacks: Cow::Borrowed(&[
+            BossAttackSlot {
+                attack: BossAttack::Pattern(BulletPattern {
+                    shape: PatternShape::RingWithGap { gap: 4 }, count: 16, speed: 220.0, rotation: 0.0, delay: 0.0, jitter: 0.0, damage: 25, lifetime: 3.0,
+                }),
+                cooldown: 3.0,
+            },
+            BossAttackSlot { attack: BossAttack::Summon { enemy: ENEMY_ID_GOLEM, count: 2, radius: 100.0 }, cooldown: 10.0 },
+        ]),
+        tint: Some([1.0, 0.7, 0.5, 1.0]),
+    },
+];
+
 static BOSS_TABLE: [BossParams; 3] = [
-    BossParams { max_hp: 1000.0, speed: 60.0,  radius: 48.0, exp_reward: 200, damage_per_sec: 30.0, render_kind: 11, special_interval: 5.0, name: "Slime King" },
-    BossParams { max_hp: 2000.0, speed: 200.0, radius: 48.0, exp_reward: 400, damage_per_sec: 50.0, render_kind: 12, special_interval: 4.0, name: "Bat Lord" },
-    BossParams { max_hp: 5000.0, speed: 30.0,  radius: 64.0, exp_reward: 800, damage_per_sec: 80.0, render_kind: 13, special_interval: 6.0, name: "Stone Golem" },
+    BossParams { max_hp: 1000.0, speed: 60.0,  radius: 48.0, exp_reward: 200, damage_per_sec: 30.0, render_kind: 11, name: Cow::Borrowed("Slime King"), resistance: 0.0, loot: BOSS_DEFAULT_LOOT, phases: Cow::Borrowed(&SLIME_KING_PHASES) },
+    BossParams { max_hp: 2000.0, speed: 200.0, radius: 48.0, exp_reward: 400, damage_per_sec: 50.0, render_kind: 12, name: Cow::Borrowed("Bat Lord"), resistance: 0.0, loot: BOSS_DEFAULT_LOOT, phases: Cow::Borrowed(&BAT_LORD_PHASES) },
+    BossParams { max_hp: 5000.0, speed: 30.0,  radius: 64.0, exp_reward: 800, damage_per_sec: 80.0, render_kind: 13, name: Cow::Borrowed("Stone Golem"), resistance: 0.0, loot: BOSS_DEFAULT_LOOT, phases: Cow::Borrowed(&STONE_GOLEM_PHASES) },
 ];
 
 impl BossParams {
     pub fn get(id: u8) -> &'static BossParams {
-        BOSS_TABLE.get(id as usize).expect("Invalid boss ID")
+        boss_table().get(id as usize).expect("Invalid boss ID")
+    }
+}
+
+// ─── PassiveParams ─────────────────────────────────────────────
+
+/// パッシブアイテムのパラメータ（ID で参照）
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PassiveParams {
+    /// 選択肢・セーブで使う名前（武器名と重複不可）
+    pub name:      Cow<'static, str>,
+    /// 補正する能力値
+    pub stat:      PlayerStat,
+    /// 1 レベルあたりの補正量（意味は PlayerStat ごとに異なる）
+    pub per_level: f32,
+    pub max_level: u32,
+}
+
+pub const PASSIVE_ID_SPINACH:      u8 = 0;
+pub const PASSIVE_ID_ARMOR:        u8 = 1;
+pub const PASSIVE_ID_HOLLOW_HEART: u8 = 2;
+pub const PASSIVE_ID_EMPTY_TOME:   u8 = 3;
+pub const PASSIVE_ID_WINGS:        u8 = 4;
+pub const PASSIVE_ID_ATTRACTORB:   u8 = 5;
+pub const PASSIVE_ID_CLOVER:       u8 = 6;
+pub const PASSIVE_ID_DUPLICATOR:   u8 = 7;
+
+static PASSIVE_TABLE: [PassiveParams; 8] = [
+    PassiveParams { name: Cow::Borrowed("spinach"),      stat: PlayerStat::Might,           per_level: 0.1,  max_level: 5 },
+    PassiveParams { name: Cow::Borrowed("armor"),        stat: PlayerStat::Armor,           per_level: 0.05, max_level: 5 },
+    PassiveParams { name: Cow::Borrowed("hollow_heart"), stat: PlayerStat::MaxHp,           per_level: 20.0, max_level: 5 },
+    PassiveParams { name: Cow::Borrowed("empty_tome"),   stat: PlayerStat::Cooldown,        per_level: 0.08, max_level: 5 },
+    PassiveParams { name: Cow::Borrowed("wings"),        stat: PlayerStat::MoveSpeed,       per_level: 0.1,  max_level: 5 },
+    PassiveParams { name: Cow::Borrowed("attractorb"),   stat: PlayerStat::PickupRadius,    per_level: 0.25, max_level: 5 },
+    PassiveParams { name: Cow::Borrowed("clover"),       stat: PlayerStat::Luck,            per_level: 0.1,  max_level: 5 },
+    PassiveParams { name: Cow::Borrowed("duplicator"),   stat: PlayerStat::ProjectileCount, per_level: 1.0,  max_level: 2 },
+];
+
+impl PassiveParams {
+    pub fn get(id: u8) -> &'static PassiveParams {
+        Self::try_get(id).expect("Invalid passive ID")
+    }
+
+    /// 登録外の ID なら None（セーブデータ復元など外部入力の ID 用）
+    pub fn try_get(id: u8) -> Option<&'static PassiveParams> {
+        passive_table().get(id as usize)
+    }
+
+    /// パッシブ名から ID を引く（add_passive / 選択肢の名前解決用）
+    pub fn find_id(name: &str) -> Option<u8> {
+        passive_table().iter().position(|p| p.name == name).map(|i| i as u8)
+    }
+
+    /// 登録済みパッシブの数（ID は 0..count）
+    pub fn count() -> usize {
+        passive_table().len()
+    }
+
+    /// レベル level での補正量の合計
+    pub fn bonus(&self, level: u32) -> f32 {
+        self.per_level * level.min(self.max_level) as f32
+    }
+
+    /// レベルアップ画面の説明行（例: "Damage: +10% -> +20%"）
+    pub fn upgrade_desc(&self, current_lv: u32) -> Vec<String> {
+        let next = self.stat.format_bonus(self.bonus(current_lv + 1));
+        if current_lv == 0 {
+            vec![format!("{}: {}", self.stat.label(), next)]
+        } else {
+            let now = self.stat.format_bonus(self.bonus(current_lv));
+            vec![format!("{}: {} -> {}", self.stat.label(), now, next)]
+        }
+    }
+}
+
+// ─── EvolutionParams ───────────────────────────────────────────
+
+/// 武器進化: weapon が MAX_WEAPON_LEVEL で passive を所持していれば evolved に置き換える。
+/// 名前で参照し、テーブル読み込み時に weapons / passives に存在することを検証する。
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EvolutionParams {
+    pub weapon:  Cow<'static, str>,
+    pub passive: Cow<'static, str>,
+    pub evolved: Cow<'static, str>,
+}
+
+static EVOLUTION_TABLE: [EvolutionParams; 5] = [
+    EvolutionParams { weapon: Cow::Borrowed("magic_wand"), passive: Cow::Borrowed("empty_tome"),   evolved: Cow::Borrowed("holy_wand") },
+    EvolutionParams { weapon: Cow::Borrowed("axe"),        passive: Cow::Borrowed("spinach"),      evolved: Cow::Borrowed("death_spiral") },
+    EvolutionParams { weapon: Cow::Borrowed("whip"),       passive: Cow::Borrowed("hollow_heart"), evolved: Cow::Borrowed("bloody_tear") },
+    EvolutionParams { weapon: Cow::Borrowed("fireball"),   passive: Cow::Borrowed("clover"),       evolved: Cow::Borrowed("hellfire") },
+    EvolutionParams { weapon: Cow::Borrowed("garlic"),     passive: Cow::Borrowed("armor"),        evolved: Cow::Borrowed("soul_eater") },
+];
+
+impl EvolutionParams {
+    /// weapon_id の進化先を (必要なパッシブ ID, 進化後の武器 ID) で返す
+    pub fn for_weapon(weapon_id: u8) -> Option<(u8, u8)> {
+        let name = &WeaponParams::get(weapon_id).name;
+        let e = evolution_table().iter().find(|e| e.weapon == *name)?;
+        Some((PassiveParams::find_id(&e.passive)?, WeaponParams::find_id(&e.evolved)?))
+    }
+
+    /// 進化後の武器か（レベルアップの選択肢から除外する）
+    pub fn is_evolved(weapon_id: u8) -> bool {
+        let name = &WeaponParams::get(weapon_id).name;
+        evolution_table().iter().any(|e| e.evolved == *name)
+    }
+
+    /// 進化後の武器 ID から進化元の武器 ID を引く
+    pub fn base_of(evolved_id: u8) -> Option<u8> {
+        let name = &WeaponParams::get(evolved_id).name;
+        let e = evolution_table().iter().find(|e| e.evolved == *name)?;
+        WeaponParams::find_id(&e.weapon)
+    }
+}
+
+// ─── EntityParamsTable（実行時レジストリ）──────────────────────────
+
+/// コンテンツファイルから読み込むパラメータテーブル一式。
+///
+/// 配列のインデックスがそのまま ID になる。省略したテーブルは組み込みのデフォルトを使う。
+/// 綴りを間違えたキーが黙ってデフォルトに戻らないよう、未知のキーはパースエラーにする。
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EntityParamsTable {
+    #[serde(default = "default_enemies")]
+    pub enemies: Vec<EnemyParams>,
+    #[serde(default = "default_weapons")]
+    pub weapons: Vec<WeaponParams>,
+    #[serde(default = "default_bosses")]
+    pub bosses:  Vec<BossParams>,
+    #[serde(default = "default_passives")]
+    pub passives: Vec<PassiveParams>,
+    /// 武器進化テーブル。進化元・進化先は weapons の名前を参照するため、
+    /// 省略時は組み込みのデフォルトではなく空（進化なし）とする
+    #[serde(default)]
+    pub evolutions: Vec<EvolutionParams>,
+    #[serde(default)]
+    pub elite:   EliteParams,
+}
+
+fn default_enemies() -> Vec<EnemyParams> { ENEMY_TABLE.to_vec() }
+fn default_weapons() -> Vec<WeaponParams> { WEAPON_TABLE.to_vec() }
+fn default_bosses() -> Vec<BossParams> { BOSS_TABLE.to_vec() }
+fn default_passives() -> Vec<PassiveParams> { PASSIVE_TABLE.to_vec() }
+fn default_evolutions() -> Vec<EvolutionParams> { EVOLUTION_TABLE.to_vec() }
+
+impl Default for EntityParamsTable {
+    fn default() -> Self {
+        Self {
+            enemies: default_enemies(),
+            weapons: default_weapons(),
+            bosses:  default_bosses(),
+            passives: default_passives(),
+            evolutions: default_evolutions(),
+            elite:   EliteParams::default(),
+        }
+    }
+}
+
+/// パラメータテーブルの読み込み・検証エラー
+#[derive(Debug)]
+pub enum EntityParamsError {
+    /// ファイル読み込み失敗
+    Io(String),
+    /// JSON として解釈できない
+    Parse(String),
+    /// 値が不正（table: "enemies" / "weapons" / "bosses" / "passives" / "evolutions" / "elite"、index: ID）
+    Invalid { table: &'static str, index: usize, reason: String },
+}
+
+impl fmt::Display for EntityParamsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "entity params io error: {e}"),
+            Self::Parse(e) => write!(f, "entity params parse error: {e}"),
+            Self::Invalid { table, index, reason } => {
+                write!(f, "invalid entity params: {table}[{index}]: {reason}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for EntityParamsError {}
+
+impl EntityParamsTable {
+    /// JSON 文字列からテーブルを読み込み、検証する
+    pub fn from_json(src: &str) -> Result<Self, EntityParamsError> {
+        let table: Self = serde_json::from_str(src)
+            .map_err(|e| EntityParamsError::Parse(e.to_string()))?;
+        table.validate()?;
+        Ok(table)
+    }
+
+    /// JSON ファイルからテーブルを読み込み、検証する
+    pub fn from_json_file(path: impl AsRef<std::path::Path>) -> Result<Self, EntityParamsError> {
+        let src = std::fs::read_to_string(path.as_ref())
+            .map_err(|e| EntityParamsError::Io(format!("{}: {e}", path.as_ref().display())))?;
+        Self::from_json(&src)
+    }
+
+    /// 値の妥当性を検証する（ID は u8 のため各テーブル最大 256 件）
+    pub fn validate(&self) -> Result<(), EntityParamsError> {
+        check_len("enemies", self.enemies.len())?;
+        check_len("weapons", self.weapons.len())?;
+        check_len("bosses", self.bosses.len())?;
+        check_len("passives", self.passives.len())?;
+
+        for (i, e) in self.enemies.iter().enumerate() {
+            let invalid = |reason: &str| invalid("enemies", i, reason);
+            if !(e.max_hp.is_finite() && e.max_hp > 0.0) { return Err(invalid("max_hp must be > 0")); }
+            if !(e.speed.is_finite() && e.speed >= 0.0) { return Err(invalid("speed must be >= 0")); }
+            if !(e.radius.is_finite() && e.radius > 0.0) { return Err(invalid("radius must be > 0")); }
+            if !(e.damage_per_sec.is_finite() && e.damage_per_sec >= 0.0) {
+                return Err(invalid("damage_per_sec must be >= 0"));
+            }
+            if e.particle_color.iter().any(|c| !(0.0..=1.0).contains(c)) {
+                return Err(invalid("particle_color components must be within 0.0..=1.0"));
+            }
+            if !(e.mass.is_finite() && e.mass > 0.0) { return Err(invalid("mass must be > 0")); }
+            if !(0.0..=1.0).contains(&e.resistance) { return Err(invalid("resistance must be within 0.0..=1.0")); }
+            e.loot.validate().map_err(invalid)?;
+        }
+
+        for (i, w) in self.weapons.iter().enumerate() {
+            let invalid = |reason: &str| invalid("weapons", i, reason);
+            if !(w.cooldown.is_finite() && w.cooldown > 0.0) { return Err(invalid("cooldown must be > 0")); }
+            if w.damage < 0 { return Err(invalid("damage must be >= 0")); }
+            if w.as_u8 as usize != i { return Err(invalid("as_u8 must equal the weapon ID (array index)")); }
+            if w.name.is_empty() { return Err(invalid("name must not be empty")); }
+            if self.weapons[..i].iter().any(|o| o.name == w.name) {
+                return Err(invalid("name must be unique"));
+            }
+            if !(w.knockback.is_finite() && w.knockback >= 0.0) { return Err(invalid("knockback must be >= 0")); }
+            if !(w.hit_stun.is_finite() && w.hit_stun >= 0.0) { return Err(invalid("hit_stun must be >= 0")); }
+            if !(0.0..=1.0).contains(&w.crit_chance) { return Err(invalid("crit_chance must be within 0.0..=1.0")); }
+            if w.label.as_deref().is_some_and(str::is_empty) {
+                return Err(invalid("label must not be empty"));
+            }
+            if w.behavior.as_deref().is_some_and(str::is_empty) {
+                return Err(invalid("behavior must not be empty"));
+            }
+            if let Some(t) = &w.bullet_table {
+                if t.len() != BULLET_TABLE_LEN {
+                    return Err(invalid("bullet_table must have 9 entries ([0] + Lv1..Lv8)"));
+                }
+            }
+            if let Some(a) = &w.on_hit {
+                if !(a.duration.is_finite() && a.duration > 0.0) {
+                    return Err(invalid("on_hit.duration must be > 0"));
+                }
+                if !(a.potency.is_finite() && a.potency >= 0.0) {
+                    return Err(invalid("on_hit.potency must be >= 0"));
+                }
+                if matches!(a.kind, StatusKind::Slow | StatusKind::Weaken) && a.potency > 1.0 {
+                    return Err(invalid("on_hit.potency of slow/weaken must be within 0.0..=1.0"));
+                }
+            }
+        }
+
+        for (i, b) in self.bosses.iter().enumerate() {
+            let invalid = |reason: &str| invalid("bosses", i, reason);
+            if !(b.max_hp.is_finite() && b.max_hp > 0.0) { return Err(invalid("max_hp must be > 0")); }
+            if !(b.speed.is_finite() && b.speed >= 0.0) { return Err(invalid("speed must be >= 0")); }
+            if !(b.radius.is_finite() && b.radius > 0.0) { return Err(invalid("radius must be > 0")); }
+            if b.phases.is_empty() { return Err(invalid("phases must not be empty")); }
+            for (pi, phase) in b.phases.iter().enumerate() {
+                phase.validate(pi == 0).map_err(invalid)?;
+            }
+            if b.name.is_empty() { return Err(invalid("name must not be empty")); }
+            if !(0.0..=1.0).contains(&b.resistance) { return Err(invalid("resistance must be within 0.0..=1.0")); }
+            b.loot.validate().map_err(invalid)?;
+        }
+
+        for (i, p) in self.passives.iter().enumerate() {
+            let invalid = |reason: &str| invalid("passives", i, reason);
+            if p.name.is_empty() { return Err(invalid("name must not be empty")); }
+            if self.passives[..i].iter().any(|o| o.name == p.name) {
+                return Err(invalid("name must be unique"));
+            }
+            // 選択肢は名前で解決するため武器名と衝突させない
+            if self.weapons.iter().any(|w| w.name == p.name) {
+                return Err(invalid("name must not collide with a weapon name"));
+            }
+            if !(p.per_level.is_finite() && p.per_level > 0.0) { return Err(invalid("per_level must be > 0")); }
+            if p.max_level == 0 { return Err(invalid("max_level must be >= 1")); }
+        }
+
+        for (i, e) in self.evolutions.iter().enumerate() {
+            let invalid = |reason: &str| invalid("evolutions", i, reason);
+            let weapon_exists = |name: &str| self.weapons.iter().any(|w| w.name == name);
+            if !weapon_exists(&e.weapon) { return Err(invalid("weapon must name an entry in weapons")); }
+            if !weapon_exists(&e.evolved) { return Err(invalid("evolved must name an entry in weapons")); }
+            if !self.passives.iter().any(|p| p.name == e.passive) {
+                return Err(invalid("passive must name an entry in passives"));
+            }
+            if e.weapon == e.evolved { return Err(invalid("evolved must differ from weapon")); }
+            if self.evolutions[..i].iter().any(|o| o.weapon == e.weapon) {
+                return Err(invalid("each weapon may have only one evolution"));
+            }
+            // 進化後の武器は選択肢に出ないため、更に進化元になると到達できない
+            if self.evolutions.iter().any(|o| o.weapon == e.evolved) {
+                return Err(invalid("evolved weapon must not evolve again"));
+            }
+        }
+
+        let e = &self.elite;
+        let positive = |v: f32| v.is_finite() && v > 0.0;
+        if ![e.hp_multiplier, e.damage_multiplier, e.size_multiplier, e.exp_multiplier].into_iter().all(positive) {
+            return Err(invalid("elite", 0, "multipliers must be > 0"));
+        }
+        if e.loot_rolls == 0 { return Err(invalid("elite", 0, "loot_rolls must be >= 1")); }
+        if let Some(attack) = &e.attack {
+            if !positive(attack.cooldown) { return Err(invalid("elite", 0, "attack cooldown must be > 0")); }
+            attack.pattern.validate().map_err(|r| invalid("elite", 0, r))?;
+        }
+        Ok(())
+    }
+}
+
+fn invalid(table: &'static str, index: usize, reason: &str) -> EntityParamsError {
+    EntityParamsError::Invalid { table, index, reason: reason.to_string() }
+}
+
+fn check_len(table: &'static str, len: usize) -> Result<(), EntityParamsError> {
+    if len == 0 || len > u8::MAX as usize + 1 {
+        return Err(invalid(table, 0, "table must have 1..=256 entries"));
+    }
+    Ok(())
+}
+
+/// 差し替え済みのテーブル（null の間は組み込みデフォルトを参照）。
+///
+/// `get()` が `&'static` を返すため、差し替え前のテーブルは解放せずに残す。
+/// 差し替えはゲーム起動時・バランス調整時のみで件数も小さいため、リークは許容する。
+static INSTALLED: AtomicPtr<EntityParamsTable> = AtomicPtr::new(std::ptr::null_mut());
+
+fn installed() -> Option<&'static EntityParamsTable> {
+    let ptr = INSTALLED.load(Ordering::Acquire);
+    // SAFETY: INSTALLED には install_entity_params で Box::leak したポインタのみを格納し、解放しない
+    unsafe { ptr.as_ref() }
+}
+
+fn enemy_table() -> &'static [EnemyParams] {
+    installed().map_or(&ENEMY_TABLE[..], |t| &t.enemies)
+}
+
+fn weapon_table() -> &'static [WeaponParams] {
+    installed().map_or(&WEAPON_TABLE[..], |t| &t.weapons)
+}
+
+fn boss_table() -> &'static [BossParams] {
+    installed().map_or(&BOSS_TABLE[..], |t| &t.bosses)
+}
+
+fn passive_table() -> &'static [PassiveParams] {
+    installed().map_or(&PASSIVE_TABLE[..], |t| &t.passives)
+}
+
+fn evolution_table() -> &'static [EvolutionParams] {
+    installed().map_or(&EVOLUTION_TABLE[..], |t| &t.evolutions)
+}
+
+/// 検証済みテーブルをプロセス全体のレジストリとして登録する。
+///
+/// 既存ワールドの敵・武器・ボス・パッシブは ID で参照し続けるため、ID を減らすテーブルは
+/// ワールド生成前（スポーン前）に登録すること（load_entity_params NIF はワールドが残っている間の縮小を拒否する）。
+pub fn install_entity_params(table: EntityParamsTable) -> Result<(), EntityParamsError> {
+    table.validate()?;
+    let ptr = Box::into_raw(Box::new(table));
+    INSTALLED.store(ptr, Ordering::Release);
+    Ok(())
+}
+
+/// 組み込みのデフォルトテーブルに戻す
+pub fn reset_entity_params() {
+    INSTALLED.store(std::ptr::null_mut(), Ordering::Release);
+}
+
+/// 現在有効なテーブルの件数（enemies, weapons, bosses）
+pub fn entity_params_counts() -> (usize, usize, usize) {
+    (enemy_table().len(), weapon_table().len(), boss_table().len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_table_is_valid() {
+        assert!(EntityParamsTable::default().validate().is_ok());
+    }
+
+    #[test]
+    fn shipped_vampire_survivor_table_matches_defaults() {
+        let src = include_str!("../../../assets/vampire_survivor/entity_params.json");
+        let table = EntityParamsTable::from_json(src).unwrap();
+        assert_eq!(table.enemies.len(), ENEMY_TABLE.len());
+        assert!(table.enemies[ENEMY_ID_GHOST as usize].passes_obstacles);
+        assert_eq!(table.weapons[WEAPON_ID_CROSS as usize].bullet_count(4), 8);
+        assert_eq!(table.weapons.len(), WEAPON_TABLE.len());
+        for (shipped, builtin) in table.weapons.iter().zip(WEAPON_TABLE.iter()) {
+            assert_eq!(shipped.on_hit, builtin.on_hit, "weapon {}", builtin.name);
+            assert_eq!(shipped.knockback, builtin.knockback, "weapon {}", builtin.name);
+            assert_eq!(shipped.hit_stun, builtin.hit_stun, "weapon {}", builtin.name);
+        }
+        for (shipped, builtin) in table.enemies.iter().zip(ENEMY_TABLE.iter()) {
+            assert_eq!(shipped.mass, builtin.mass);
+            assert_eq!(loot_summary(&shipped.loot), loot_summary(&builtin.loot));
+        }
+        for (shipped, builtin) in table.bosses.iter().zip(BOSS_TABLE.iter()) {
+            assert_eq!(loot_summary(&shipped.loot), loot_summary(&builtin.loot));
+            assert_eq!(shipped.phases, builtin.phases, "boss {}", builtin.name);
+        }
+        assert_eq!(table.bosses[BOSS_ID_BAT_LORD as usize].name, "Bat Lord");
+        assert_eq!(table.passives.len(), PASSIVE_TABLE.len());
+        for (shipped, builtin) in table.passives.iter().zip(PASSIVE_TABLE.iter()) {
+            assert_eq!(shipped.name, builtin.name);
+            assert_eq!(shipped.stat, builtin.stat, "passive {}", builtin.name);
+            assert_eq!(shipped.per_level, builtin.per_level, "passive {}", builtin.name);
+            assert_eq!(shipped.max_level, builtin.max_level, "passive {}", builtin.name);
+        }
+        assert_eq!(table.elite, ELITE_PARAMS);
+        assert_eq!(table.evolutions.len(), EVOLUTION_TABLE.len());
+        for (shipped, builtin) in table.evolutions.iter().zip(EVOLUTION_TABLE.iter()) {
+            assert_eq!(
+                (&shipped.weapon, &shipped.passive, &shipped.evolved),
+                (&builtin.weapon, &builtin.passive, &builtin.evolved)
+            );
+        }
+    }
+
+    type LootSummary = (Vec<(ItemKind, u32, Option<[u32; 2]>, bool)>, Vec<(ItemKind, u32)>, u32);
+
+    /// ルートテーブルの比較用（抽選候補と確定ドロップの内容）
+    fn loot_summary(t: &LootTable) -> LootSummary {
+        (
+            t.entries.iter().map(|e| (e.item, e.weight, e.value, e.luck_scaled)).collect(),
+            t.guaranteed.iter().map(|g| (g.item, g.count)).collect(),
+            t.none_weight,
+        )
+    }
+
+    #[test]
+    fn default_evolutions_resolve_to_ids() {
+        assert_eq!(
+            EvolutionParams::for_weapon(WEAPON_ID_MAGIC_WAND),
+            Some((PASSIVE_ID_EMPTY_TOME, WEAPON_ID_HOLY_WAND))
+        );
+        assert_eq!(EvolutionParams::for_weapon(WEAPON_ID_LIGHTNING), None);
+        assert!(EvolutionParams::is_evolved(WEAPON_ID_SOUL_EATER));
+        assert!(!EvolutionParams::is_evolved(WEAPON_ID_GARLIC));
+        assert_eq!(EvolutionParams::base_of(WEAPON_ID_BLOODY_TEAR), Some(WEAPON_ID_WHIP));
+    }
+
+    #[test]
+    fn from_json_fills_omitted_tables_with_defaults() {
+        let src = r#"{
+            "enemies": [
+                { "max_hp": 50.0, "speed": 90.0, "radius": 18.0, "exp_reward": 7,
+                  "damage_per_sec": 25.0, "render_kind": 1, "particle_color": [1.0, 0.5, 0.1, 1.0] }
+            ]
+        }"#;
+        let table = EntityParamsTable::from_json(src).unwrap();
+        assert_eq!(table.enemies.len(), 1);
+        assert!((table.enemies[0].max_hp - 50.0).abs() < 0.001);
+        assert!(!table.enemies[0].passes_obstacles);
+        assert_eq!(table.weapons.len(), WEAPON_TABLE.len());
+        assert_eq!(table.bosses[2].name, "Stone Golem");
+        // 進化テーブルは武器名を参照するため、省略時は空
+        assert!(table.evolutions.is_empty());
+    }
+
+    #[test]
+    fn from_json_reads_weapon_behavior_and_bullet_table() {
+        let src = r#"{
+            "weapons": [
+                { "cooldown": 0.8, "damage": 12, "as_u8": 0, "name": "blaster",
+                  "behavior": "magic_wand", "bullet_table": [0, 1, 2, 2, 3, 3, 4, 4, 5] }
+            ]
+        }"#;
+        let table = EntityParamsTable::from_json(src).unwrap();
+        assert_eq!(table.weapons[0].name, "blaster");
+        // label 省略時は武器名がそのまま表示名になる
+        assert_eq!(table.weapons[0].label(), "blaster");
+        assert_eq!(table.weapons[0].behavior_name(), "magic_wand");
+        assert_eq!(table.weapons[0].bullet_count(8), 5);
+    }
+
+    #[test]
+    fn from_json_rejects_invalid_values() {
+        let negative_hp = r#"{ "bosses": [
+            { "max_hp": -1.0, "speed": 60.0, "radius": 48.0, "exp_reward": 200,
+              "damage_per_sec": 30.0, "render_kind": 11, "name": "X" }
+        ] }"#;
+        assert!(matches!(
+            EntityParamsTable::from_json(negative_hp),
+            Err(EntityParamsError::Invalid { table: "bosses", index: 0, .. })
+        ));
+
+        let short_bullet_table = r#"{ "weapons": [
+            { "cooldown": 1.0, "damage": 10, "as_u8": 0, "name": "w", "bullet_table": [0, 1] }
+        ] }"#;
+        assert!(matches!(
+            EntityParamsTable::from_json(short_bullet_table),
+            Err(EntityParamsError::Invalid { table: "weapons", .. })
+        ));
+
+        let misspelled_key = r#"{ "weapons": [
+            { "cooldown": 1.0, "damage": 10, "as_u8": 0, "name": "w", "bullet_tabel": [0, 1] }
+        ] }"#;
+        assert!(matches!(
+            EntityParamsTable::from_json(misspelled_key),
+            Err(EntityParamsError::Parse(_))
+        ));
+        assert!(matches!(
+            EntityParamsTable::from_json(r#"{ "weapon": [] }"#),
+            Err(EntityParamsError::Parse(_))
+        ));
+        let misspelled_elite_key = r#"{ "elite": {
+            "hp_multiplier": 3.0, "damage_multiplier": 1.5, "size_multiplier": 1.2,
+            "exp_multiplier": 3.0, "loot_rolls": 3, "attak": null
+        } }"#;
+        assert!(matches!(
+            EntityParamsTable::from_json(misspelled_elite_key),
+            Err(EntityParamsError::Parse(_))
+        ));
+
+        let unknown_evolved = r#"{ "evolutions": [
+            { "weapon": "whip", "passive": "armor", "evolved": "no_such_weapon" }
+        ] }"#;
+        assert!(matches!(
+            EntityParamsTable::from_json(unknown_evolved),
+            Err(EntityParamsError::Invalid { table: "evolutions", .. })
+        ));
+
+        let inverted_loot_range = r#"{ "bosses": [
+            { "max_hp": 100.0, "speed": 60.0, "radius": 48.0, "exp_reward": 200,
+              "damage_per_sec": 30.0, "render_kind": 11, "name": "X",
+              "loot": { "guaranteed": [{ "item": "potion", "value": [50, 10] }] } }
+        ] }"#;
+        assert!(matches!(
+            EntityParamsTable::from_json(inverted_loot_range),
+            Err(EntityParamsError::Invalid { table: "bosses", .. })
+        ));
+
+        let passive_named_like_weapon = r#"{ "passives": [
+            { "name": "whip", "stat": "might", "per_level": 0.1, "max_level": 5 }
+        ] }"#;
+        assert!(matches!(
+            EntityParamsTable::from_json(passive_named_like_weapon),
+            Err(EntityParamsError::Invalid { table: "passives", .. })
+        ));
+
+        assert!(matches!(
+            EntityParamsTable::from_json(r#"{ "enemies": [] }"#),
+            Err(EntityParamsError::Invalid { table: "enemies", .. })
+        ));
+        assert!(matches!(
+            EntityParamsTable::from_json("{ not json"),
+            Err(EntityParamsError::Parse(_))
+        ));
     }
 }
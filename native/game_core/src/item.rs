@@ -1,20 +1,25 @@
 //! Path: native/game_core/src/item.rs
 //! Summary: アイテム種類・レンダー kind の定義と ItemWorld
 
-/// アイテムの種類
-#[derive(Clone, Copy, PartialEq, Debug, Default)]
+use serde::Deserialize;
+
+/// アイテムの種類（ルートテーブルでは snake_case の名前で指定する）
+#[derive(Clone, Copy, PartialEq, Debug, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
 #[repr(u8)]
 pub enum ItemKind {
     #[default]
     Gem    = 0, // 経験値宝石（緑）
     Potion = 1, // 回復ポーション（赤）
     Magnet = 2, // 磁石（黄）
+    Chest  = 3, // 宝箱（取得時に武器進化を判定）
 }
 
 /// `get_render_data` / `get_item_data` が返す kind 値
 pub const RENDER_KIND_GEM:    u8 = 5;
 pub const RENDER_KIND_POTION: u8 = 6;
 pub const RENDER_KIND_MAGNET: u8 = 7;
+pub const RENDER_KIND_CHEST:  u8 = 8;
 
 impl ItemKind {
     /// レンダラーに渡す kind 値
@@ -23,6 +28,7 @@ impl ItemKind {
             Self::Gem    => RENDER_KIND_GEM,
             Self::Potion => RENDER_KIND_POTION,
             Self::Magnet => RENDER_KIND_MAGNET,
+            Self::Chest  => RENDER_KIND_CHEST,
         }
     }
 }
@@ -34,7 +40,7 @@ pub struct ItemWorld {
     pub positions_x: Vec<f32>,
     pub positions_y: Vec<f32>,
     pub kinds:       Vec<ItemKind>,
-    pub value:       Vec<u32>,  // Gem: EXP 量, Potion: 回復量, Magnet: 未使用
+    pub value:       Vec<u32>,  // Gem: EXP 量, Potion: 回復量, Magnet: 未使用, Chest: 進化しなかった場合のスコア
     pub alive:       Vec<bool>,
     pub count:       usize,
     /// kill 時にインデックスを積み、spawn 時に pop して再利用する
@@ -2,10 +2,18 @@
 //! Summary: ゲームコア共通ロジック（定数・敵・武器・物理プリミティブ）
 
 pub mod boss;
+pub mod bullet_pattern;
+pub mod checksum;
 pub mod constants;
 pub mod enemy;
 pub mod entity_params;
 pub mod item;
+pub mod loot;
 pub mod physics;
+pub mod player_stats;
+pub mod replay;
+pub mod spawn_director;
+pub mod status_effect;
+pub mod tile_map;
 pub mod util;
 pub mod weapon;
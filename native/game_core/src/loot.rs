@@ -0,0 +1,182 @@
+//! Path: native/game_core/src/loot.rs
+//! Summary: 敵・ボスの撃破時ドロップを決めるルートテーブル（重み付き抽選 + 確定ドロップ）
+//!
+//! テーブルは entity_params の敵・ボスごとに持ち、ワールドの SimpleRng で抽選する。
+//! 抽選結果 (ItemKind, value) のスポーンは呼び出し側で行う。
+
+use crate::item::ItemKind;
+use crate::physics::rng::SimpleRng;
+use serde::Deserialize;
+use std::borrow::Cow;
+
+/// 1 回の抽選で選ばれうるドロップ
+#[derive(Clone, Debug, Deserialize)]
+pub struct LootEntry {
+    pub item:        ItemKind,
+    pub weight:      u32,
+    /// 値の範囲 [min, max]（Gem: EXP 量, Potion: 回復量, Chest: スコア）。
+    /// 省略時は撃破した敵の exp_reward
+    #[serde(default)]
+    pub value:       Option<[u32; 2]>,
+    /// true なら重みに Luck を掛ける
+    #[serde(default)]
+    pub luck_scaled: bool,
+}
+
+/// 抽選せずに必ず落とすドロップ
+#[derive(Clone, Debug, Deserialize)]
+pub struct GuaranteedDrop {
+    pub item:  ItemKind,
+    #[serde(default = "default_count")]
+    pub count: u32,
+    /// 1 個あたりの値の範囲 [min, max]。省略時は exp_reward を count で等分する
+    #[serde(default)]
+    pub value: Option<[u32; 2]>,
+}
+
+fn default_count() -> u32 { 1 }
+
+/// 撃破時のドロップ定義
+#[derive(Clone, Debug, Deserialize)]
+pub struct LootTable {
+    /// 重み付き抽選の候補（1 回だけ抽選する）
+    #[serde(default)]
+    pub entries:      Cow<'static, [LootEntry]>,
+    /// 何も落とさない重み（entries と同じ抽選に含める）
+    #[serde(default)]
+    pub none_weight:  u32,
+    #[serde(default)]
+    pub guaranteed:   Cow<'static, [GuaranteedDrop]>,
+    /// ドロップを撃破位置からばら撒く範囲（一辺 px。0 なら撃破位置に置く）
+    #[serde(default)]
+    pub scatter:      f32,
+}
+
+/// 通常敵の組み込みテーブル（千分率: 宝箱 0.5% / 磁石 2% / 回復 5%、残りは Gem）
+pub const ENEMY_DEFAULT_LOOT: LootTable = LootTable {
+    entries: Cow::Borrowed(&[
+        LootEntry { item: ItemKind::Chest,  weight: 5,   value: Some([100, 100]), luck_scaled: false },
+        LootEntry { item: ItemKind::Magnet, weight: 20,  value: Some([0, 0]),     luck_scaled: true },
+        LootEntry { item: ItemKind::Potion, weight: 50,  value: Some([20, 20]),   luck_scaled: true },
+        LootEntry { item: ItemKind::Gem,    weight: 925, value: None,             luck_scaled: false },
+    ]),
+    none_weight: 0,
+    guaranteed:  Cow::Borrowed(&[]),
+    scatter:     0.0,
+};
+
+/// ボスの組み込みテーブル（1.2.9: exp_reward を 10 個の Gem に分けて周囲にばら撒く）
+pub const BOSS_DEFAULT_LOOT: LootTable = LootTable {
+    entries:     Cow::Borrowed(&[]),
+    none_weight: 0,
+    guaranteed:  Cow::Borrowed(&[GuaranteedDrop { item: ItemKind::Gem, count: 10, value: None }]),
+    scatter:     200.0,
+};
+
+impl LootTable {
+    /// ドロップを抽選し、(種類, 値) を順に out へ積む（確定ドロップ → 抽選の順）。
+    /// exp_reward は value 省略時の値、luck は luck_scaled の重み倍率
+    pub fn roll(&self, rng: &mut SimpleRng, luck: f32, exp_reward: u32, out: &mut Vec<(ItemKind, u32)>) {
+        for g in self.guaranteed.iter() {
+            for _ in 0..g.count {
+                let value = match g.value {
+                    Some(range) => roll_value(rng, range),
+                    None        => exp_reward / g.count.max(1),
+                };
+                out.push((g.item, value));
+            }
+        }
+
+        let weight_of = |e: &LootEntry| {
+            if e.luck_scaled { (e.weight as f32 * luck.max(0.0)) as u32 } else { e.weight }
+        };
+        let total = self.none_weight + self.entries.iter().map(weight_of).sum::<u32>();
+        if total == 0 {
+            return;
+        }
+        let mut roll = rng.next_u32() % total;
+        for e in self.entries.iter() {
+            let wt = weight_of(e);
+            if roll < wt {
+                let value = e.value.map_or(exp_reward, |range| roll_value(rng, range));
+                out.push((e.item, value));
+                return;
+            }
+            roll -= wt;
+        }
+    }
+
+    /// 値の妥当性（entity_params の検証から呼ぶ）
+    pub fn validate(&self) -> Result<(), &'static str> {
+        let has_range_error = |v: &Option<[u32; 2]>| v.is_some_and(|[min, max]| min > max);
+        if self.entries.iter().any(|e| has_range_error(&e.value))
+            || self.guaranteed.iter().any(|g| has_range_error(&g.value))
+        {
+            return Err("loot value range must satisfy min <= max");
+        }
+        if self.entries.iter().any(|e| e.weight == 0) {
+            return Err("loot entry weight must be > 0");
+        }
+        if self.guaranteed.iter().any(|g| g.count == 0) {
+            return Err("loot guaranteed count must be > 0");
+        }
+        if !(self.scatter.is_finite() && self.scatter >= 0.0) {
+            return Err("loot scatter must be >= 0");
+        }
+        Ok(())
+    }
+}
+
+/// [min, max] の一様乱数（min == max なら乱数を消費しない）
+fn roll_value(rng: &mut SimpleRng, [min, max]: [u32; 2]) -> u32 {
+    if min >= max {
+        return min;
+    }
+    min + rng.next_u32() % (max - min + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn boss_default_splits_exp_into_gems() {
+        let mut rng = SimpleRng::new(1);
+        let mut drops = Vec::new();
+        BOSS_DEFAULT_LOOT.roll(&mut rng, 1.0, 400, &mut drops);
+        assert_eq!(drops.len(), 10);
+        assert!(drops.iter().all(|&d| d == (ItemKind::Gem, 40)));
+    }
+
+    #[test]
+    fn weighted_roll_respects_weights_and_ranges() {
+        let table = LootTable {
+            entries: Cow::Owned(vec![
+                LootEntry { item: ItemKind::Potion, weight: 1, value: Some([10, 30]), luck_scaled: false },
+            ]),
+            none_weight: 0,
+            guaranteed:  Cow::Borrowed(&[]),
+            scatter:     0.0,
+        };
+        let mut rng = SimpleRng::new(7);
+        for _ in 0..50 {
+            let mut drops = Vec::new();
+            table.roll(&mut rng, 1.0, 5, &mut drops);
+            let [(kind, value)] = drops[..] else { panic!("expected one drop") };
+            assert_eq!(kind, ItemKind::Potion);
+            assert!((10..=30).contains(&value));
+        }
+
+        // Luck 0 で luck_scaled の重みが 0 になれば何も落とさない
+        let lucky_only = LootTable {
+            entries: Cow::Owned(vec![
+                LootEntry { item: ItemKind::Magnet, weight: 10, value: None, luck_scaled: true },
+            ]),
+            none_weight: 0,
+            ..table
+        };
+        let mut drops = Vec::new();
+        lucky_only.roll(&mut rng, 0.0, 5, &mut drops);
+        assert!(drops.is_empty());
+    }
+}
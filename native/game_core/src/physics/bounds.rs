@@ -0,0 +1,93 @@
+//! Path: native/game_core/src/physics/bounds.rs
+//! Summary: ワールドの広さ（WorldBounds）と、座標のクランプ・カメラ位置の計算
+//!
+//! プレイヤー・敵・ボス・弾・カメラはすべてこの境界を参照する。
+//! 既定は MAP_WIDTH × MAP_HEIGHT で、マップ読み込み時に set_world_size で変更できる。
+
+use crate::constants::{MAP_HEIGHT, MAP_WIDTH};
+
+/// ワールドの広さ（左上 (0, 0) から (width, height) まで）
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WorldBounds {
+    pub width:  f32,
+    pub height: f32,
+}
+
+impl Default for WorldBounds {
+    fn default() -> Self {
+        Self { width: MAP_WIDTH, height: MAP_HEIGHT }
+    }
+}
+
+impl WorldBounds {
+    pub fn new(width: f32, height: f32) -> Self {
+        Self { width, height }
+    }
+
+    /// ワールドの中心座標
+    pub fn center(&self) -> (f32, f32) {
+        (self.width / 2.0, self.height / 2.0)
+    }
+
+    /// 左上基準・一辺 size の矩形がワールドからはみ出さないように座標をクランプする
+    pub fn clamp_top_left(&self, x: f32, y: f32, size: f32) -> (f32, f32) {
+        (
+            x.clamp(0.0, (self.width - size).max(0.0)),
+            y.clamp(0.0, (self.height - size).max(0.0)),
+        )
+    }
+
+    /// 中心基準・半径 radius の円がワールドからはみ出さないように座標をクランプする
+    pub fn clamp_center(&self, x: f32, y: f32, radius: f32) -> (f32, f32) {
+        (
+            x.clamp(radius.min(self.width / 2.0), (self.width - radius).max(self.width / 2.0)),
+            y.clamp(radius.min(self.height / 2.0), (self.height - radius).max(self.height / 2.0)),
+        )
+    }
+
+    /// (x, y) がワールドを margin だけ広げた範囲に入っているか（画面外の弾の消去判定用）
+    pub fn contains(&self, x: f32, y: f32, margin: f32) -> bool {
+        (-margin..=self.width + margin).contains(&x) && (-margin..=self.height + margin).contains(&y)
+    }
+
+    /// 中心 (x, y)・半径 radius の円がワールドに完全に収まっているか（スポーン位置の判定用）
+    pub fn contains_circle(&self, x: f32, y: f32, radius: f32) -> bool {
+        x >= radius && x <= self.width - radius && y >= radius && y <= self.height - radius
+    }
+
+    /// (focus_x, focus_y) を画面中心に映すカメラの左上座標。
+    /// ワールドの外が映らないように端で止め、ワールドが画面より狭い軸は中央に寄せる
+    pub fn camera_offset(&self, focus_x: f32, focus_y: f32, view_w: f32, view_h: f32) -> (f32, f32) {
+        let axis = |focus: f32, view: f32, world: f32| {
+            if world <= view {
+                (world - view) / 2.0
+            } else {
+                (focus - view / 2.0).clamp(0.0, world - view)
+            }
+        };
+        (axis(focus_x, view_w, self.width), axis(focus_y, view_h, self.height))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clamps_entities_inside_world() {
+        let b = WorldBounds::new(1000.0, 500.0);
+        assert_eq!(b.clamp_top_left(-10.0, 600.0, 32.0), (0.0, 468.0));
+        assert_eq!(b.clamp_center(2000.0, -5.0, 48.0), (952.0, 48.0));
+        assert!(b.contains(-50.0, 520.0, 100.0));
+        assert!(!b.contains(1200.0, 0.0, 100.0));
+    }
+
+    #[test]
+    fn camera_stops_at_world_edges() {
+        let b = WorldBounds::new(4096.0, 600.0);
+        // 中央付近は追従、端では止まる。画面より狭い縦方向は中央寄せ
+        assert_eq!(b.camera_offset(2000.0, 300.0, 1280.0, 720.0), (1360.0, -60.0));
+        assert_eq!(b.camera_offset(100.0, 300.0, 1280.0, 720.0).0, 0.0);
+        assert_eq!(b.camera_offset(4090.0, 300.0, 1280.0, 720.0).0, 4096.0 - 1280.0);
+    }
+}
@@ -0,0 +1,360 @@
+//! Path: native/game_core/src/physics/chase.rs
+//! Summary: Chase AI の移動カーネル（スカラー・SSE2・AVX2・NEON）と実行時の CPU 機能判定
+//!
+//! 敵 SoA のスライス（ChaseSlices）を受け取り、生存していてのけぞり中でない敵をプレイヤーに向けて
+//! speeds の速さで進める。のけぞり中の敵は現在の速度（ノックバック）のまま流す。
+//! SIMD 版もスカラー版（chase_one）と同じ演算順（sqrt → max → 除算 → 乗算）で計算するため、
+//! 結果はスカラー版と一致する。x86_64 では AVX2 を実行時に判定し、なければ SSE2 を使う。
+//! aarch64 では NEON、それ以外の環境ではスカラー版を使う。
+
+use std::sync::OnceLock;
+
+/// 距離の下限（プレイヤーと重なった敵の 0 除算を防ぐ）
+const MIN_DIST: f32 = 0.001;
+
+/// Chase AI が読み書きする敵 SoA のスライス（すべて同じ長さ）
+pub struct ChaseSlices<'a> {
+    pub pos_x:  &'a mut [f32],
+    pub pos_y:  &'a mut [f32],
+    pub vel_x:  &'a mut [f32],
+    pub vel_y:  &'a mut [f32],
+    pub speeds: &'a [f32],
+    pub alive:  &'a [bool],
+    pub stun:   &'a [f32],
+}
+
+impl ChaseSlices<'_> {
+    fn len(&self) -> usize {
+        self.pos_x.len()
+    }
+
+    fn assert_consistent(&self) {
+        let n = self.len();
+        assert!(
+            [self.pos_y.len(), self.vel_x.len(), self.vel_y.len(), self.speeds.len(), self.alive.len(), self.stun.len()]
+                .iter()
+                .all(|&m| m == n),
+            "ChaseSlices: all slices must have the same length"
+        );
+    }
+}
+
+/// 1 体分の Chase AI（生存している敵に対して呼ぶ）。update_chase_ai（rayon 版）と各 SIMD 版の基準になる
+#[inline]
+#[allow(clippy::too_many_arguments)]
+pub fn chase_one(x: &mut f32, y: &mut f32, vx: &mut f32, vy: &mut f32, speed: f32, stun: f32, player_x: f32, player_y: f32, dt: f32) {
+    // のけぞり中は追尾せず、ノックバック速度のまま流される
+    if stun <= 0.0 || stun.is_nan() {
+        let dx = player_x - *x;
+        let dy = player_y - *y;
+        let dist = (dx * dx + dy * dy).sqrt().max(MIN_DIST);
+        *vx = (dx / dist) * speed;
+        *vy = (dy / dist) * speed;
+    }
+    *x += *vx * dt;
+    *y += *vy * dt;
+}
+
+/// スカラー版（SIMD のない環境と、SIMD 版の端数レーンで使う）。from 番目以降を処理する
+fn chase_scalar(s: &mut ChaseSlices, from: usize, player_x: f32, player_y: f32, dt: f32) {
+    for i in from..s.len() {
+        if s.alive[i] {
+            chase_one(&mut s.pos_x[i], &mut s.pos_y[i], &mut s.vel_x[i], &mut s.vel_y[i], s.speeds[i], s.stun[i], player_x, player_y, dt);
+        }
+    }
+}
+
+/// Chase AI の実装の種類
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChaseBackend {
+    Scalar,
+    Sse2,
+    Avx2,
+    Neon,
+}
+
+impl ChaseBackend {
+    /// この CPU で使える中で最も速い実装（初回に判定した結果を使い回す）
+    pub fn detect() -> Self {
+        static DETECTED: OnceLock<ChaseBackend> = OnceLock::new();
+        *DETECTED.get_or_init(|| {
+            Self::available().into_iter().last().unwrap_or(Self::Scalar)
+        })
+    }
+
+    /// この CPU で使える実装（遅い順）。ベンチマーク・テストで全実装を比べるために使う
+    pub fn available() -> Vec<Self> {
+        [Self::Scalar, Self::Sse2, Self::Avx2, Self::Neon].into_iter().filter(|b| b.is_supported()).collect()
+    }
+
+    pub fn is_supported(self) -> bool {
+        match self {
+            Self::Scalar => true,
+            Self::Sse2 => cfg!(target_arch = "x86_64"),
+            #[cfg(target_arch = "x86_64")]
+            Self::Avx2 => is_x86_feature_detected!("avx2"),
+            #[cfg(not(target_arch = "x86_64"))]
+            Self::Avx2 => false,
+            Self::Neon => cfg!(target_arch = "aarch64"),
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Scalar => "scalar",
+            Self::Sse2 => "sse2",
+            Self::Avx2 => "avx2",
+            Self::Neon => "neon",
+        }
+    }
+
+    /// この実装で全敵を 1 フレーム分動かす。この CPU で使えない実装ならスカラー版で処理する
+    pub fn run(self, s: &mut ChaseSlices, player_x: f32, player_y: f32, dt: f32) {
+        s.assert_consistent();
+        let done = if !self.is_supported() {
+            0
+        } else {
+            match self {
+                Self::Scalar => 0,
+                // SAFETY: スライスの長さは assert_consistent で揃っており、CPU 機能は is_supported で確認済み
+                #[cfg(target_arch = "x86_64")]
+                Self::Sse2 => unsafe { chase_sse2(s, player_x, player_y, dt) },
+                #[cfg(target_arch = "x86_64")]
+                Self::Avx2 => unsafe { chase_avx2(s, player_x, player_y, dt) },
+                #[cfg(target_arch = "aarch64")]
+                Self::Neon => unsafe { chase_neon(s, player_x, player_y, dt) },
+                #[allow(unreachable_patterns)]
+                _ => 0,
+            }
+        };
+        chase_scalar(s, done, player_x, player_y, dt);
+    }
+}
+
+/// SSE2 版（4 レーン）。処理したレーン数を返す（残りはスカラー版で処理する）
+#[cfg(target_arch = "x86_64")]
+unsafe fn chase_sse2(s: &mut ChaseSlices, player_x: f32, player_y: f32, dt: f32) -> usize {
+    use std::arch::x86_64::*;
+
+    // mask のレーンは a、それ以外は b（SSE2 には blendv がない）
+    #[inline(always)]
+    unsafe fn select(mask: __m128, a: __m128, b: __m128) -> __m128 {
+        _mm_or_ps(_mm_and_ps(mask, a), _mm_andnot_ps(mask, b))
+    }
+
+    let simd_len = s.len() / 4 * 4;
+    let px4 = _mm_set1_ps(player_x);
+    let py4 = _mm_set1_ps(player_y);
+    let dt4 = _mm_set1_ps(dt);
+    let min4 = _mm_set1_ps(MIN_DIST);
+    let zero4 = _mm_setzero_ps();
+    let zero_i = _mm_setzero_si128();
+
+    for base in (0..simd_len).step_by(4) {
+        let ex = _mm_loadu_ps(s.pos_x.as_ptr().add(base));
+        let ey = _mm_loadu_ps(s.pos_y.as_ptr().add(base));
+        let old_vx = _mm_loadu_ps(s.vel_x.as_ptr().add(base));
+        let old_vy = _mm_loadu_ps(s.vel_y.as_ptr().add(base));
+        let sp = _mm_loadu_ps(s.speeds.as_ptr().add(base));
+        let stun = _mm_loadu_ps(s.stun.as_ptr().add(base));
+
+        let dx = _mm_sub_ps(px4, ex);
+        let dy = _mm_sub_ps(py4, ey);
+        let dist = _mm_max_ps(_mm_sqrt_ps(_mm_add_ps(_mm_mul_ps(dx, dx), _mm_mul_ps(dy, dy))), min4);
+        let chase_vx = _mm_mul_ps(_mm_div_ps(dx, dist), sp);
+        let chase_vy = _mm_mul_ps(_mm_div_ps(dy, dist), sp);
+
+        // のけぞり中（stun > 0）でないレーンだけ追尾する
+        let steer = _mm_cmpngt_ps(stun, zero4);
+        let vx = select(steer, chase_vx, old_vx);
+        let vy = select(steer, chase_vy, old_vy);
+        let new_ex = _mm_add_ps(ex, _mm_mul_ps(vx, dt4));
+        let new_ey = _mm_add_ps(ey, _mm_mul_ps(vy, dt4));
+
+        // alive（bool = 0/1 の 4 バイト）を 32bit レーンのマスクに広げる
+        let bytes = (s.alive.as_ptr().add(base) as *const i32).read_unaligned();
+        let wide = _mm_unpacklo_epi16(_mm_unpacklo_epi8(_mm_cvtsi32_si128(bytes), zero_i), zero_i);
+        let alive = _mm_castsi128_ps(_mm_cmpgt_epi32(wide, zero_i));
+
+        _mm_storeu_ps(s.pos_x.as_mut_ptr().add(base), select(alive, new_ex, ex));
+        _mm_storeu_ps(s.pos_y.as_mut_ptr().add(base), select(alive, new_ey, ey));
+        _mm_storeu_ps(s.vel_x.as_mut_ptr().add(base), select(alive, vx, old_vx));
+        _mm_storeu_ps(s.vel_y.as_mut_ptr().add(base), select(alive, vy, old_vy));
+    }
+    simd_len
+}
+
+/// AVX2 版（8 レーン）。処理したレーン数を返す（残りはスカラー版で処理する）
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+unsafe fn chase_avx2(s: &mut ChaseSlices, player_x: f32, player_y: f32, dt: f32) -> usize {
+    use std::arch::x86_64::*;
+
+    let simd_len = s.len() / 8 * 8;
+    let px8 = _mm256_set1_ps(player_x);
+    let py8 = _mm256_set1_ps(player_y);
+    let dt8 = _mm256_set1_ps(dt);
+    let min8 = _mm256_set1_ps(MIN_DIST);
+    let zero8 = _mm256_setzero_ps();
+
+    for base in (0..simd_len).step_by(8) {
+        let ex = _mm256_loadu_ps(s.pos_x.as_ptr().add(base));
+        let ey = _mm256_loadu_ps(s.pos_y.as_ptr().add(base));
+        let old_vx = _mm256_loadu_ps(s.vel_x.as_ptr().add(base));
+        let old_vy = _mm256_loadu_ps(s.vel_y.as_ptr().add(base));
+        let sp = _mm256_loadu_ps(s.speeds.as_ptr().add(base));
+        let stun = _mm256_loadu_ps(s.stun.as_ptr().add(base));
+
+        let dx = _mm256_sub_ps(px8, ex);
+        let dy = _mm256_sub_ps(py8, ey);
+        let dist = _mm256_max_ps(_mm256_sqrt_ps(_mm256_add_ps(_mm256_mul_ps(dx, dx), _mm256_mul_ps(dy, dy))), min8);
+        let chase_vx = _mm256_mul_ps(_mm256_div_ps(dx, dist), sp);
+        let chase_vy = _mm256_mul_ps(_mm256_div_ps(dy, dist), sp);
+
+        let steer = _mm256_cmp_ps::<_CMP_NGT_UQ>(stun, zero8);
+        let vx = _mm256_blendv_ps(old_vx, chase_vx, steer);
+        let vy = _mm256_blendv_ps(old_vy, chase_vy, steer);
+        let new_ex = _mm256_add_ps(ex, _mm256_mul_ps(vx, dt8));
+        let new_ey = _mm256_add_ps(ey, _mm256_mul_ps(vy, dt8));
+
+        // alive（8 バイト）をゼロ拡張して 32bit レーンのマスクにする
+        let bytes = _mm_loadl_epi64(s.alive.as_ptr().add(base) as *const __m128i);
+        let alive = _mm256_castsi256_ps(_mm256_cmpgt_epi32(_mm256_cvtepu8_epi32(bytes), _mm256_setzero_si256()));
+
+        _mm256_storeu_ps(s.pos_x.as_mut_ptr().add(base), _mm256_blendv_ps(ex, new_ex, alive));
+        _mm256_storeu_ps(s.pos_y.as_mut_ptr().add(base), _mm256_blendv_ps(ey, new_ey, alive));
+        _mm256_storeu_ps(s.vel_x.as_mut_ptr().add(base), _mm256_blendv_ps(old_vx, vx, alive));
+        _mm256_storeu_ps(s.vel_y.as_mut_ptr().add(base), _mm256_blendv_ps(old_vy, vy, alive));
+    }
+    simd_len
+}
+
+/// NEON 版（4 レーン）。処理したレーン数を返す（残りはスカラー版で処理する）
+#[cfg(target_arch = "aarch64")]
+unsafe fn chase_neon(s: &mut ChaseSlices, player_x: f32, player_y: f32, dt: f32) -> usize {
+    use std::arch::aarch64::*;
+
+    let simd_len = s.len() / 4 * 4;
+    let px4 = vdupq_n_f32(player_x);
+    let py4 = vdupq_n_f32(player_y);
+    let dt4 = vdupq_n_f32(dt);
+    let min4 = vdupq_n_f32(MIN_DIST);
+    let zero4 = vdupq_n_f32(0.0);
+
+    for base in (0..simd_len).step_by(4) {
+        let ex = vld1q_f32(s.pos_x.as_ptr().add(base));
+        let ey = vld1q_f32(s.pos_y.as_ptr().add(base));
+        let old_vx = vld1q_f32(s.vel_x.as_ptr().add(base));
+        let old_vy = vld1q_f32(s.vel_y.as_ptr().add(base));
+        let sp = vld1q_f32(s.speeds.as_ptr().add(base));
+        let stun = vld1q_f32(s.stun.as_ptr().add(base));
+
+        let dx = vsubq_f32(px4, ex);
+        let dy = vsubq_f32(py4, ey);
+        let dist = vmaxq_f32(vsqrtq_f32(vaddq_f32(vmulq_f32(dx, dx), vmulq_f32(dy, dy))), min4);
+        let chase_vx = vmulq_f32(vdivq_f32(dx, dist), sp);
+        let chase_vy = vmulq_f32(vdivq_f32(dy, dist), sp);
+
+        let steer = vmvnq_u32(vcgtq_f32(stun, zero4));
+        let vx = vbslq_f32(steer, chase_vx, old_vx);
+        let vy = vbslq_f32(steer, chase_vy, old_vy);
+        // 積和命令（vfmaq）は丸めが 1 回になりスカラー版とずれるため、乗算と加算を分ける
+        let new_ex = vaddq_f32(ex, vmulq_f32(vx, dt4));
+        let new_ey = vaddq_f32(ey, vmulq_f32(vy, dt4));
+
+        // alive（4 バイト）を 32bit レーンに広げてマスクにする
+        let bytes = (s.alive.as_ptr().add(base) as *const u32).read_unaligned();
+        let wide = vmovl_u16(vget_low_u16(vmovl_u8(vcreate_u8(bytes as u64))));
+        let alive = vcgtq_u32(wide, vdupq_n_u32(0));
+
+        vst1q_f32(s.pos_x.as_mut_ptr().add(base), vbslq_f32(alive, new_ex, ex));
+        vst1q_f32(s.pos_y.as_mut_ptr().add(base), vbslq_f32(alive, new_ey, ey));
+        vst1q_f32(s.vel_x.as_mut_ptr().add(base), vbslq_f32(alive, vx, old_vx));
+        vst1q_f32(s.vel_y.as_mut_ptr().add(base), vbslq_f32(alive, vy, old_vy));
+    }
+    simd_len
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::physics::rng::SimpleRng;
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Soa {
+        x:     Vec<f32>,
+        y:     Vec<f32>,
+        vx:    Vec<f32>,
+        vy:    Vec<f32>,
+        speed: Vec<f32>,
+        alive: Vec<bool>,
+        stun:  Vec<f32>,
+    }
+
+    impl Soa {
+        /// 死亡・のけぞり中・プレイヤーと重なった敵を含む、レーン幅で割り切れない長さの SoA
+        fn random(n: usize) -> Self {
+            let mut rng = SimpleRng::new(21);
+            let mut f = |m: u32| (rng.next_u32() % m) as f32;
+            let mut soa = Self {
+                x: Vec::new(), y: Vec::new(), vx: Vec::new(), vy: Vec::new(),
+                speed: Vec::new(), alive: Vec::new(), stun: Vec::new(),
+            };
+            for i in 0..n {
+                soa.x.push(f(2000) + 0.25);
+                soa.y.push(f(2000) + 0.75);
+                soa.vx.push(f(200) - 100.0);
+                soa.vy.push(f(200) - 100.0);
+                soa.speed.push(40.0 + f(120));
+                soa.alive.push(i % 5 != 2);
+                soa.stun.push(if i % 7 == 3 { 0.2 } else { 0.0 });
+            }
+            soa.x[4] = 1000.0;
+            soa.y[4] = 1000.0;
+            soa
+        }
+
+        fn slices(&mut self) -> ChaseSlices<'_> {
+            ChaseSlices {
+                pos_x: &mut self.x, pos_y: &mut self.y, vel_x: &mut self.vx, vel_y: &mut self.vy,
+                speeds: &self.speed, alive: &self.alive, stun: &self.stun,
+            }
+        }
+    }
+
+    #[test]
+    fn every_backend_matches_chase_one() {
+        let start = Soa::random(1003);
+        let mut expected = start.clone();
+        for i in 0..expected.x.len() {
+            if expected.alive[i] {
+                let (speed, stun) = (expected.speed[i], expected.stun[i]);
+                chase_one(&mut expected.x[i], &mut expected.y[i], &mut expected.vx[i], &mut expected.vy[i], speed, stun, 1000.0, 1000.0, 0.016);
+            }
+        }
+
+        assert!(ChaseBackend::available().contains(&ChaseBackend::detect()));
+        for backend in ChaseBackend::available() {
+            let mut soa = start.clone();
+            backend.run(&mut soa.slices(), 1000.0, 1000.0, 0.016);
+            for i in 0..soa.x.len() {
+                let same = |a: f32, b: f32| (a - b).abs() <= 1e-4;
+                assert!(
+                    same(soa.x[i], expected.x[i]) && same(soa.y[i], expected.y[i])
+                        && same(soa.vx[i], expected.vx[i]) && same(soa.vy[i], expected.vy[i]),
+                    "{} differs at {i}", backend.name()
+                );
+            }
+            // 死亡した敵は動かない
+            assert_eq!(soa.x[2], start.x[2]);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "same length")]
+    fn rejects_mismatched_slices() {
+        let mut soa = Soa::random(16);
+        soa.stun.pop();
+        ChaseBackend::Scalar.run(&mut soa.slices(), 0.0, 0.0, 0.016);
+    }
+}
@@ -0,0 +1,327 @@
+//! Path: native/game_core/src/physics/collider.rs
+//! Summary: 静的障害物の形状（円・軸平行矩形・凸多角形）と円との重なり・押し出し計算
+//!
+//! 形状はすべて障害物の中心 (x, y) からの相対座標で持つ。プレイヤー・敵・弾はいずれも円として扱い、
+//! penetration が返すベクトルだけ動かせば形状の外に出る。sweep は 1 フレーム分の移動（線分）に沿った
+//! 最初の接触時刻を返し、速い弾が薄い障害物や小さい敵をすり抜けないようにする。
+
+use serde::Deserialize;
+
+/// 静的障害物の形状。JSON では radius / width・height / points のどれを持つかで判別する
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+#[serde(untagged)]
+pub enum ObstacleShape {
+    /// 円
+    Circle { radius: f32 },
+    /// 軸平行矩形（中心基準で幅 width・高さ height）
+    Rect { width: f32, height: f32 },
+    /// 凸多角形（中心からの相対座標。時計回り・反時計回りのどちらでもよい）
+    Polygon { points: Vec<[f32; 2]> },
+}
+
+impl ObstacleShape {
+    /// 値の妥当性（マップの検証から呼ぶ）
+    pub fn validate(&self) -> Result<(), &'static str> {
+        match self {
+            Self::Circle { radius } if !(radius.is_finite() && *radius > 0.0) => Err("obstacle radius must be > 0"),
+            Self::Rect { width, height } if !(width.is_finite() && height.is_finite() && *width > 0.0 && *height > 0.0) => {
+                Err("obstacle width and height must be > 0")
+            }
+            Self::Polygon { points } => {
+                if points.len() < 3 || points.iter().flatten().any(|v| !v.is_finite()) {
+                    return Err("obstacle polygon needs at least 3 finite points");
+                }
+                if !is_convex(points) {
+                    return Err("obstacle polygon must be convex");
+                }
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// 中心からの外接矩形の半幅・半高（空間ハッシュへの登録範囲）
+    pub fn half_extents(&self) -> (f32, f32) {
+        match self {
+            Self::Circle { radius } => (*radius, *radius),
+            Self::Rect { width, height } => (width / 2.0, height / 2.0),
+            Self::Polygon { points } => points
+                .iter()
+                .fold((0.0_f32, 0.0_f32), |(hx, hy), p| (hx.max(p[0].abs()), hy.max(p[1].abs()))),
+        }
+    }
+
+    /// 中心からの相対座標 (px, py) にある半径 r の円を形状の外に出す押し出しベクトル。
+    /// 重なっていなければ None（接しているだけの場合も None）
+    pub fn penetration(&self, px: f32, py: f32, r: f32) -> Option<(f32, f32)> {
+        match self {
+            Self::Circle { radius } => {
+                let dist = (px * px + py * py).sqrt();
+                let overlap = radius + r - dist;
+                if overlap <= 0.0 {
+                    None
+                } else if dist < 1e-4 {
+                    Some((overlap, 0.0))
+                } else {
+                    Some((px / dist * overlap, py / dist * overlap))
+                }
+            }
+            Self::Rect { width, height } => {
+                let (hw, hh) = (width / 2.0, height / 2.0);
+                let (qx, qy) = (px.clamp(-hw, hw), py.clamp(-hh, hh));
+                let (dx, dy) = (px - qx, py - qy);
+                let dist_sq = dx * dx + dy * dy;
+                if dist_sq > 0.0 {
+                    // 中心が矩形の外: 最近点から離す
+                    push_from_point(dx, dy, dist_sq, r)
+                } else {
+                    // 中心が矩形の内側: いちばん近い辺の外へ出す
+                    let exits = [(hw - px, (1.0, 0.0)), (px + hw, (-1.0, 0.0)), (hh - py, (0.0, 1.0)), (py + hh, (0.0, -1.0))];
+                    let (depth, (nx, ny)) = exits.into_iter().fold(exits[0], |a, b| if b.0 < a.0 { b } else { a });
+                    Some((nx * (depth + r), ny * (depth + r)))
+                }
+            }
+            Self::Polygon { points } => polygon_penetration(points, px, py, r),
+        }
+    }
+
+    /// 中心からの相対座標 (px, py) にある半径 r の円が (dx, dy) だけ動くとき、形状に最初に接する時刻 t（0.0..=1.0）。
+    /// 始点で既に重なっていれば 0.0、当たらなければ None
+    pub fn sweep(&self, px: f32, py: f32, dx: f32, dy: f32, r: f32) -> Option<f32> {
+        match self {
+            Self::Circle { radius } => sweep_point_circle(px, py, dx, dy, radius + r),
+            Self::Rect { width, height } => {
+                let (hw, hh) = (width / 2.0, height / 2.0);
+                polygon_sweep(&[[-hw, -hh], [hw, -hh], [hw, hh], [-hw, hh]], px, py, dx, dy, r)
+            }
+            Self::Polygon { points } => polygon_sweep(points, px, py, dx, dy, r),
+        }
+    }
+
+    /// 形状の内側を cell px 間隔の格子で塗りつぶしたときのマス（中心からの相対の左上座標）を out に積む。
+    /// 矩形・円以外をスプライトで描くための近似（凸多角形は塗りつぶしマスの集まりで描く）
+    pub fn fill_cells(&self, cell: f32, out: &mut Vec<(f32, f32)>) {
+        let (hx, hy) = self.half_extents();
+        let (nx, ny) = ((hx * 2.0 / cell).ceil() as i32, (hy * 2.0 / cell).ceil() as i32);
+        for iy in 0..ny {
+            for ix in 0..nx {
+                let (x, y) = (-hx + ix as f32 * cell, -hy + iy as f32 * cell);
+                if self.contains_point(x + cell / 2.0, y + cell / 2.0) {
+                    out.push((x, y));
+                }
+            }
+        }
+    }
+
+    /// 中心からの相対座標 (px, py) が形状の内側か
+    pub fn contains_point(&self, px: f32, py: f32) -> bool {
+        match self {
+            Self::Circle { radius } => px * px + py * py <= radius * radius,
+            Self::Rect { width, height } => px.abs() <= width / 2.0 && py.abs() <= height / 2.0,
+            Self::Polygon { points } => {
+                let sign = winding(points);
+                edges(points).all(|(a, b)| cross(b[0] - a[0], b[1] - a[1], px - a[0], py - a[1]) * sign >= 0.0)
+            }
+        }
+    }
+}
+
+/// 円の中心からの相対座標 (px, py) にある点が (dx, dy) だけ動くとき、半径 radius の円に入る時刻 t（0.0..=1.0）。
+/// 始点が円の内側なら 0.0、当たらなければ None
+pub fn sweep_point_circle(px: f32, py: f32, dx: f32, dy: f32, radius: f32) -> Option<f32> {
+    let c = px * px + py * py - radius * radius;
+    if c < 0.0 {
+        return Some(0.0);
+    }
+    let a = dx * dx + dy * dy;
+    let b = px * dx + py * dy;
+    let disc = b * b - a * c;
+    if a <= 0.0 || b >= 0.0 || disc < 0.0 {
+        return None;
+    }
+    let t = (-b - disc.sqrt()) / a;
+    (t <= 1.0).then_some(t)
+}
+
+fn cross(ax: f32, ay: f32, bx: f32, by: f32) -> f32 {
+    ax * by - ay * bx
+}
+
+/// 多角形の辺 (始点, 終点) を順に返す
+fn edges(points: &[[f32; 2]]) -> impl Iterator<Item = ([f32; 2], [f32; 2])> + '_ {
+    points.iter().zip(points.iter().cycle().skip(1)).map(|(&a, &b)| (a, b))
+}
+
+/// 反時計回り（y 下向きの画面座標では時計回り）なら 1.0、逆なら -1.0
+fn winding(points: &[[f32; 2]]) -> f32 {
+    let area: f32 = edges(points).map(|(a, b)| cross(a[0], a[1], b[0], b[1])).sum();
+    if area >= 0.0 { 1.0 } else { -1.0 }
+}
+
+fn is_convex(points: &[[f32; 2]]) -> bool {
+    let sign = winding(points);
+    let n = points.len();
+    (0..n).all(|i| {
+        let (a, b, c) = (points[i], points[(i + 1) % n], points[(i + 2) % n]);
+        cross(b[0] - a[0], b[1] - a[1], c[0] - b[0], c[1] - b[1]) * sign >= 0.0
+    })
+}
+
+/// 最近点からのずれ (dx, dy) が r 未満なら、その方向へ r まで押し出す
+fn push_from_point(dx: f32, dy: f32, dist_sq: f32, r: f32) -> Option<(f32, f32)> {
+    if dist_sq >= r * r {
+        return None;
+    }
+    let dist = dist_sq.sqrt();
+    Some((dx / dist * (r - dist), dy / dist * (r - dist)))
+}
+
+/// 凸多角形と円の押し出し。中心が外側なら境界の最近点から、内側なら最も浅い辺の法線方向へ出す
+fn polygon_penetration(points: &[[f32; 2]], px: f32, py: f32, r: f32) -> Option<(f32, f32)> {
+    let sign = winding(points);
+    let mut inside = true;
+    let mut best_outside: Option<(f32, f32, f32)> = None; // (dist_sq, dx, dy)
+    let mut shallowest = (f32::MAX, 0.0, 0.0); // (depth, nx, ny)
+
+    for (a, b) in edges(points) {
+        let (ex, ey) = (b[0] - a[0], b[1] - a[1]);
+        let len = (ex * ex + ey * ey).sqrt().max(1e-6);
+        // 外向き法線（巻き方向に合わせて向きを揃える）
+        let (nx, ny) = (ey / len * sign, -ex / len * sign);
+        let depth = -((px - a[0]) * nx + (py - a[1]) * ny);
+        if depth < 0.0 {
+            inside = false;
+        }
+        if depth < shallowest.0 {
+            shallowest = (depth, nx, ny);
+        }
+
+        // 辺上の最近点
+        let t = (((px - a[0]) * ex + (py - a[1]) * ey) / (len * len)).clamp(0.0, 1.0);
+        let (dx, dy) = (px - (a[0] + ex * t), py - (a[1] + ey * t));
+        let dist_sq = dx * dx + dy * dy;
+        if best_outside.is_none_or(|(d, _, _)| dist_sq < d) {
+            best_outside = Some((dist_sq, dx, dy));
+        }
+    }
+
+    if inside {
+        let (depth, nx, ny) = shallowest;
+        return Some((nx * (depth + r), ny * (depth + r)));
+    }
+    let (dist_sq, dx, dy) = best_outside?;
+    push_from_point(dx, dy, dist_sq, r)
+}
+
+/// 凸多角形と移動する円の最初の接触時刻。多角形を r だけ膨らませた形（各辺を法線方向に r ずらした辺と、
+/// 各頂点を中心とする半径 r の円の和）と、円の中心が描く線分との交差で求める
+fn polygon_sweep(points: &[[f32; 2]], px: f32, py: f32, dx: f32, dy: f32, r: f32) -> Option<f32> {
+    if polygon_penetration(points, px, py, r).is_some() {
+        return Some(0.0);
+    }
+    let sign = winding(points);
+    let mut first: Option<f32> = None;
+    let mut hit = |t: f32| {
+        if first.is_none_or(|f| t < f) {
+            first = Some(t);
+        }
+    };
+
+    for (a, b) in edges(points) {
+        let (ex, ey) = (b[0] - a[0], b[1] - a[1]);
+        let len_sq = (ex * ex + ey * ey).max(1e-12);
+        let len = len_sq.sqrt();
+        let (nx, ny) = (ey / len * sign, -ex / len * sign);
+        // 外側から辺に向かって進むときだけ当たる
+        let denom = dx * nx + dy * ny;
+        if denom < 0.0 {
+            let (ax, ay) = (a[0] + nx * r, a[1] + ny * r);
+            let t = ((ax - px) * nx + (ay - py) * ny) / denom;
+            if (0.0..=1.0).contains(&t) {
+                let s = ((px + dx * t - ax) * ex + (py + dy * t - ay) * ey) / len_sq;
+                if (0.0..=1.0).contains(&s) {
+                    hit(t);
+                }
+            }
+        }
+    }
+    for v in points {
+        if let Some(t) = sweep_point_circle(px - v[0], py - v[1], dx, dy, r) {
+            hit(t);
+        }
+    }
+    first
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx(a: (f32, f32), b: (f32, f32)) -> bool {
+        (a.0 - b.0).abs() < 1e-3 && (a.1 - b.1).abs() < 1e-3
+    }
+
+    #[test]
+    fn rect_pushes_out_from_edges_corners_and_inside() {
+        let rect = ObstacleShape::Rect { width: 200.0, height: 20.0 };
+        // 上辺に 5px めり込み
+        assert!(approx(rect.penetration(30.0, -25.0, 20.0).unwrap(), (0.0, -5.0)));
+        // 角の外側（斜め）
+        let p = rect.penetration(105.0, 15.0, 10.0).unwrap();
+        assert!(p.0 > 0.0 && p.1 > 0.0);
+        // 中心が内側: 最も近い辺（下辺、残り 2px）から半径分外へ
+        assert!(approx(rect.penetration(0.0, 8.0, 10.0).unwrap(), (0.0, 12.0)));
+        assert!(rect.penetration(0.0, 40.0, 10.0).is_none());
+    }
+
+    #[test]
+    fn polygon_matches_either_winding() {
+        // 画面座標で時計回り・反時計回りの同じ三角形
+        let cw = ObstacleShape::Polygon { points: vec![[-50.0, 50.0], [0.0, -50.0], [50.0, 50.0]] };
+        let ccw = ObstacleShape::Polygon { points: vec![[50.0, 50.0], [0.0, -50.0], [-50.0, 50.0]] };
+        for shape in [&cw, &ccw] {
+            assert!(shape.validate().is_ok());
+            // 底辺の下から 4px めり込み
+            assert!(approx(shape.penetration(0.0, 56.0, 10.0).unwrap(), (0.0, 4.0)));
+            // 内側の点は底辺の外へ
+            assert!(approx(shape.penetration(0.0, 40.0, 10.0).unwrap(), (0.0, 20.0)));
+            assert!(shape.penetration(80.0, 0.0, 10.0).is_none());
+            assert!(shape.contains_point(0.0, 0.0) && !shape.contains_point(-45.0, -40.0));
+        }
+        let concave = ObstacleShape::Polygon { points: vec![[0.0, 0.0], [10.0, 0.0], [5.0, 2.0], [10.0, 10.0], [0.0, 10.0]] };
+        assert!(concave.validate().is_err());
+    }
+
+    #[test]
+    fn sweeps_find_first_contact_instead_of_tunneling() {
+        // 幅 8px の壁を 1 フレームで 200px 進む弾（半径 6）が通り抜ける
+        let wall = ObstacleShape::Rect { width: 8.0, height: 100.0 };
+        assert!(wall.penetration(100.0, 0.0, 6.0).is_none());
+        let t = wall.sweep(-100.0, 0.0, 200.0, 0.0, 6.0).unwrap();
+        assert!((t - 0.45).abs() < 1e-4);
+        // 角をかすめる場合は頂点の丸みで当たる
+        assert!(wall.sweep(-100.0, 53.0, 200.0, 0.0, 6.0).is_some());
+        assert!(wall.sweep(-100.0, 57.0, 200.0, 0.0, 6.0).is_none());
+        // 遠ざかる・届かない
+        assert!(wall.sweep(-100.0, 0.0, -200.0, 0.0, 6.0).is_none());
+        assert!(wall.sweep(-100.0, 0.0, 50.0, 0.0, 6.0).is_none());
+
+        let tri = ObstacleShape::Polygon { points: vec![[-50.0, 50.0], [0.0, -50.0], [50.0, 50.0]] };
+        let t = tri.sweep(0.0, 100.0, 0.0, -100.0, 10.0).unwrap();
+        assert!((t - 0.4).abs() < 1e-4);
+
+        assert!(sweep_point_circle(-30.0, 0.0, 60.0, 0.0, 12.0).is_some_and(|t| (t - 0.3).abs() < 1e-6));
+        assert_eq!(sweep_point_circle(5.0, 0.0, 60.0, 0.0, 12.0), Some(0.0));
+        assert_eq!(sweep_point_circle(-30.0, 20.0, 60.0, 0.0, 12.0), None);
+    }
+
+    #[test]
+    fn fill_cells_cover_the_shape() {
+        let mut cells = Vec::new();
+        ObstacleShape::Rect { width: 64.0, height: 32.0 }.fill_cells(16.0, &mut cells);
+        assert_eq!(cells.len(), 8);
+        cells.clear();
+        ObstacleShape::Polygon { points: vec![[-32.0, -32.0], [32.0, -32.0], [-32.0, 32.0]] }.fill_cells(16.0, &mut cells);
+        assert!(cells.len() > 4 && cells.len() < 16);
+    }
+}
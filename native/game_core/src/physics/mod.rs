@@ -1,7 +1,12 @@
 //! Path: native/game_core/src/physics/mod.rs
-//! Summary: 物理モジュールの再エクスポート（衝突・分離・RNG・空間ハッシュ）
+//! Summary: 物理モジュールの再エクスポート（ワールド境界・Chase AI カーネル・障害物形状・衝突・分離・RNG・空間ハッシュ・グリッド・スポーン位置）
 
+pub mod bounds;
+pub mod chase;
+pub mod collider;
 pub mod obstacle_resolve;
 pub mod rng;
 pub mod separation;
+pub mod spatial_grid;
 pub mod spatial_hash;
+pub mod spawn_placement;
@@ -1,22 +1,141 @@
 //! Path: native/game_core/src/physics/rng.rs
-//! Summary: 決定論的 LCG 乱数ジェネレータ（no-std 互換）
+//! Summary: 決定論的 PCG32 乱数ジェネレータとシステムごとの乱数ストリーム（no-std 互換）
+//!
+//! SimpleRng は PCG32（XSH-RR）。同じシードでもストリーム番号が違えば独立した列になるので、
+//! ワールドは 1 つのシードから用途別のストリーム（RngStreams）を作る。
+//! パーティクルなど見た目だけの乱数は別ストリームから引くため、ゲームプレイの乱数列を乱さない。
+//! 状態は RngState として読み出し・復元できる（セーブ・リプレイ用）。
 
-pub struct SimpleRng(u64);
+const PCG_MULTIPLIER: u64 = 6364136223846793005;
+
+/// SimpleRng の内部状態（state と奇数の増分 inc）
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RngState {
+    pub state: u64,
+    pub inc:   u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SimpleRng {
+    state: u64,
+    inc:   u64,
+}
 
 impl SimpleRng {
+    /// ストリーム 0 で初期化する
     pub fn new(seed: u64) -> Self {
-        Self(seed)
+        Self::with_stream(seed, 0)
+    }
+
+    /// seed とストリーム番号から初期化する（PCG の標準的な初期化手順）
+    pub fn with_stream(seed: u64, stream: u64) -> Self {
+        let mut rng = Self { state: 0, inc: (stream << 1) | 1 };
+        rng.step();
+        rng.state = rng.state.wrapping_add(seed);
+        rng.step();
+        rng
+    }
+
+    /// ワールドのシードから用途別のストリームを作る
+    pub fn for_stream(seed: u64, stream: RngStream) -> Self {
+        Self::with_stream(seed, stream as u64)
+    }
+
+    /// 保存した状態から復元する（inc は奇数に補正する）
+    pub fn from_state(s: RngState) -> Self {
+        Self { state: s.state, inc: s.inc | 1 }
+    }
+
+    pub fn state(&self) -> RngState {
+        RngState { state: self.state, inc: self.inc }
+    }
+
+    fn step(&mut self) {
+        self.state = self.state.wrapping_mul(PCG_MULTIPLIER).wrapping_add(self.inc);
     }
 
     pub fn next_u32(&mut self) -> u32 {
-        self.0 = self.0
-            .wrapping_mul(6364136223846793005)
-            .wrapping_add(1442695040888963407);
-        (self.0 >> 33) as u32
+        let old = self.state;
+        self.step();
+        let xorshifted = (((old >> 18) ^ old) >> 27) as u32;
+        xorshifted.rotate_right((old >> 59) as u32)
     }
 
+    /// [0, 1) の一様乱数（上位 24 ビットを使う）
     pub fn next_f32(&mut self) -> f32 {
-        self.next_u32() as f32 / u32::MAX as f32
+        (self.next_u32() >> 8) as f32 * (1.0 / (1u32 << 24) as f32)
+    }
+}
+
+/// 乱数ストリームの用途（値がストリーム番号）
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RngStream {
+    /// 敵の種類・スポーン位置・隊形
+    Spawn     = 1,
+    /// ドロップ抽選と散らばり
+    Loot      = 2,
+    /// 会心判定
+    Combat    = 3,
+    /// ボス・エリートの弾幕パターン
+    Ai        = 4,
+    /// パーティクル（見た目のみ）
+    Particles = 5,
+}
+
+/// ワールドのゲームプレイ用乱数（パーティクル用は ParticleWorld が持つ）
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RngStreams {
+    seed:       u64,
+    pub spawn:  SimpleRng,
+    pub loot:   SimpleRng,
+    pub combat: SimpleRng,
+    pub ai:     SimpleRng,
+}
+
+/// RngStreams の保存用状態
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RngStreamsState {
+    pub seed:   u64,
+    pub spawn:  RngState,
+    pub loot:   RngState,
+    pub combat: RngState,
+    pub ai:     RngState,
+}
+
+impl RngStreams {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            spawn:  SimpleRng::for_stream(seed, RngStream::Spawn),
+            loot:   SimpleRng::for_stream(seed, RngStream::Loot),
+            combat: SimpleRng::for_stream(seed, RngStream::Combat),
+            ai:     SimpleRng::for_stream(seed, RngStream::Ai),
+        }
+    }
+
+    /// ワールド生成時のシード
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn state(&self) -> RngStreamsState {
+        RngStreamsState {
+            seed:   self.seed,
+            spawn:  self.spawn.state(),
+            loot:   self.loot.state(),
+            combat: self.combat.state(),
+            ai:     self.ai.state(),
+        }
+    }
+
+    pub fn from_state(s: RngStreamsState) -> Self {
+        Self {
+            seed:   s.seed,
+            spawn:  SimpleRng::from_state(s.spawn),
+            loot:   SimpleRng::from_state(s.loot),
+            combat: SimpleRng::from_state(s.combat),
+            ai:     SimpleRng::from_state(s.ai),
+        }
     }
 }
 
@@ -41,4 +160,48 @@ mod tests {
             assert!(f >= 0.0 && f <= 1.0);
         }
     }
+
+    #[test]
+    fn next_f32_covers_the_unit_interval() {
+        let mut rng = SimpleRng::new(7);
+        let samples: Vec<f32> = (0..10_000).map(|_| rng.next_f32()).collect();
+        assert!(samples.iter().all(|&f| (0.0..1.0).contains(&f)));
+        let upper = samples.iter().filter(|&&f| f >= 0.5).count();
+        assert!((4_500..5_500).contains(&upper), "{upper} samples in [0.5, 1)");
+    }
+
+    #[test]
+    fn streams_with_the_same_seed_are_independent() {
+        let mut spawn = SimpleRng::for_stream(1, RngStream::Spawn);
+        let mut loot = SimpleRng::for_stream(1, RngStream::Loot);
+        let a: Vec<u32> = (0..8).map(|_| spawn.next_u32()).collect();
+        let b: Vec<u32> = (0..8).map(|_| loot.next_u32()).collect();
+        assert_ne!(a, b);
+
+        // 他のストリームを進めても spawn の列は変わらない
+        let mut streams = RngStreams::new(1);
+        for _ in 0..100 {
+            streams.loot.next_u32();
+            streams.ai.next_f32();
+        }
+        let c: Vec<u32> = (0..8).map(|_| streams.spawn.next_u32()).collect();
+        assert_eq!(a, c);
+    }
+
+    #[test]
+    fn restored_state_continues_the_same_sequence() {
+        let mut streams = RngStreams::new(42);
+        for _ in 0..17 {
+            streams.spawn.next_u32();
+            streams.combat.next_f32();
+        }
+        let saved = streams.state();
+        let mut restored = RngStreams::from_state(saved);
+        assert_eq!(restored.seed(), 42);
+        for _ in 0..10 {
+            assert_eq!(streams.spawn.next_u32(), restored.spawn.next_u32());
+            assert_eq!(streams.combat.next_u32(), restored.combat.next_u32());
+            assert_eq!(streams.ai.next_u32(), restored.ai.next_u32());
+        }
+    }
 }
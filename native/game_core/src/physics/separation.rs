@@ -1,7 +1,20 @@
 //! Path: native/game_core/src/physics/separation.rs
 //! Summary: 敵同士の重なり解消（Separation）トレイトと適用ロジック
 
-use super::spatial_hash::SpatialHash;
+use super::spatial_grid::SpatialGrid;
+use rayon::prelude::*;
+
+/// 生存・死亡を含めた敵スロット数がこれ未満なら並列化せずシリアル版で処理する（スレッド起動の方が高くつく）
+const PARALLEL_THRESHOLD: usize = 1024;
+
+/// 並列版の分離パスが同時に借用する SoA のスライス
+pub struct SeparationSlices<'a> {
+    pub pos_x: &'a [f32],
+    pub pos_y: &'a [f32],
+    pub alive: &'a [bool],
+    pub sep_x: &'a mut [f32],
+    pub sep_y: &'a mut [f32],
+}
 
 // ─── 分離トレイト ──────────────────────────────────────────────
 /// 敵同士の重なりを解消する分離（Separation）パスを提供するトレイト。
@@ -19,19 +32,77 @@ pub trait EnemySeparation {
     fn sep_buf_y(&mut self) -> &mut Vec<f32>;
     /// 近隣クエリ結果の再利用バッファ（毎フレームのヒープアロケーションを回避）
     fn neighbor_buf(&mut self) -> &mut Vec<usize>;
+    /// 位置・生存フラグ（読み取り）と sep バッファ（書き込み）を同時に借用する（並列版で使う）
+    fn separation_slices(&mut self) -> SeparationSlices<'_>;
 }
 
-/// 分離パスを実行する。
+/// 分離パスを実行する。敵が PARALLEL_THRESHOLD 体以上なら rayon で並列に処理する。
+///
+/// 並列版はギャザー方式: 各敵が近隣の敵を読み、自分の sep バッファにだけ書き込む。
+/// ペアの押し出しを両方に書くシリアル版と、加算順による誤差を除いて同じ結果になる。
+/// grid は CollisionWorld::dynamic を渡してフレームをまたいで再利用する。
+pub fn apply_separation<W: EnemySeparation>(
+    world: &mut W,
+    grid: &mut SpatialGrid,
+    separation_radius: f32,
+    separation_force: f32,
+    dt: f32,
+) {
+    let len = world.enemy_count();
+    if len < PARALLEL_THRESHOLD {
+        apply_separation_serial(world, grid, separation_radius, separation_force, dt);
+        return;
+    }
+
+    grid.rebuild((0..len).filter(|&i| world.is_alive(i)).map(|i| (i, world.pos_x(i), world.pos_y(i))));
+    let grid = &*grid;
+    let SeparationSlices { pos_x, pos_y, alive, sep_x, sep_y } = world.separation_slices();
+    let r_sq = separation_radius * separation_radius;
+    let scale = separation_force * dt;
+
+    sep_x
+        .par_iter_mut()
+        .zip(sep_y.par_iter_mut())
+        .enumerate()
+        // 近隣クエリのバッファは rayon のタスク単位で 1 つ確保して使い回す
+        .for_each_init(Vec::new, |neighbors, (i, (sx, sy))| {
+            *sx = 0.0;
+            *sy = 0.0;
+            if !alive[i] {
+                return;
+            }
+            let (ix, iy) = (pos_x[i], pos_y[i]);
+            grid.query_nearby_into(ix, iy, separation_radius, neighbors);
+            for &j in neighbors.iter() {
+                if j == i || !alive[j] {
+                    continue;
+                }
+                let dx = ix - pos_x[j];
+                let dy = iy - pos_y[j];
+                let dist_sq = dx * dx + dy * dy;
+                if dist_sq < r_sq && dist_sq > 1e-6 {
+                    let dist = dist_sq.sqrt();
+                    let force = (separation_radius - dist) * scale;
+                    *sx += (dx / dist) * force;
+                    *sy += (dy / dist) * force;
+                }
+            }
+        });
+
+    apply_separation_buffers(world);
+}
+
+/// シリアル版の分離パス。
 ///
 /// アルゴリズム:
-///   1. Spatial Hash で近隣の敵を列挙
-///   2. 重なっているペアに対して押し出しベクトルを計算しバッファに蓄積
+///   1. grid を生存敵で作り直し、近隣の敵を列挙
+///   2. 重なっているペアに対して押し出しベクトルを計算し、両方のバッファに蓄積（各ペアは一度だけ）
 ///   3. バッファを位置に適用
 ///
-/// rayon で並列化できないため（書き込みが衝突する）シングルスレッドで処理する。
-/// Spatial Hash により計算量は O(n) に近い。
-pub fn apply_separation<W: EnemySeparation>(
+/// グリッドにより計算量は O(n) に近い。
+pub fn apply_separation_serial<W: EnemySeparation>(
     world: &mut W,
+    grid: &mut SpatialGrid,
     separation_radius: f32,
     separation_force: f32,
     dt: f32,
@@ -45,13 +116,8 @@ pub fn apply_separation<W: EnemySeparation>(
     world.sep_buf_x().iter_mut().for_each(|v| *v = 0.0);
     world.sep_buf_y().iter_mut().for_each(|v| *v = 0.0);
 
-    // Spatial Hash を構築（生存敵のみ）
-    let mut hash = SpatialHash::new(separation_radius);
-    for i in 0..len {
-        if world.is_alive(i) {
-            hash.insert(i, world.pos_x(i), world.pos_y(i));
-        }
-    }
+    // グリッドを構築（生存敵のみ）
+    grid.rebuild((0..len).filter(|&i| world.is_alive(i)).map(|i| (i, world.pos_x(i), world.pos_y(i))));
 
     for i in 0..len {
         if !world.is_alive(i) {
@@ -61,7 +127,7 @@ pub fn apply_separation<W: EnemySeparation>(
         let iy = world.pos_y(i);
 
         // neighbor_buf を再利用してヒープアロケーションを回避
-        hash.query_nearby_into(ix, iy, separation_radius, world.neighbor_buf());
+        grid.query_nearby_into(ix, iy, separation_radius, world.neighbor_buf());
         // buf の借用を解放するため長さだけ取り出してインデックスアクセス
         let nb_len = world.neighbor_buf().len();
         for ni in 0..nb_len {
@@ -91,8 +157,12 @@ pub fn apply_separation<W: EnemySeparation>(
         }
     }
 
-    // バッファを位置に適用
-    for i in 0..len {
+    apply_separation_buffers(world);
+}
+
+/// sep バッファを生存敵の位置に適用する
+fn apply_separation_buffers<W: EnemySeparation>(world: &mut W) {
+    for i in 0..world.enemy_count() {
         if world.is_alive(i) {
             let sx = world.sep_buf_x()[i];
             let sy = world.sep_buf_y()[i];
@@ -101,3 +171,57 @@ pub fn apply_separation<W: EnemySeparation>(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::physics::rng::SimpleRng;
+
+    struct Enemies {
+        x:         Vec<f32>,
+        y:         Vec<f32>,
+        alive:     Vec<bool>,
+        sep_x:     Vec<f32>,
+        sep_y:     Vec<f32>,
+        neighbors: Vec<usize>,
+    }
+
+    impl Enemies {
+        fn random(n: usize, seed: u64) -> Self {
+            let mut rng = SimpleRng::new(seed);
+            let x = (0..n).map(|_| (rng.next_u32() % 1500) as f32 + rng.next_f32()).collect();
+            let y = (0..n).map(|_| (rng.next_u32() % 1000) as f32 + rng.next_f32()).collect();
+            let alive = (0..n).map(|i| i % 7 != 3).collect();
+            Self { x, y, alive, sep_x: vec![0.0; n], sep_y: vec![0.0; n], neighbors: Vec::new() }
+        }
+    }
+
+    impl EnemySeparation for Enemies {
+        fn enemy_count(&self) -> usize { self.x.len() }
+        fn is_alive(&self, i: usize) -> bool { self.alive[i] }
+        fn pos_x(&self, i: usize) -> f32 { self.x[i] }
+        fn pos_y(&self, i: usize) -> f32 { self.y[i] }
+        fn add_pos_x(&mut self, i: usize, v: f32) { self.x[i] += v; }
+        fn add_pos_y(&mut self, i: usize, v: f32) { self.y[i] += v; }
+        fn sep_buf_x(&mut self) -> &mut Vec<f32> { &mut self.sep_x }
+        fn sep_buf_y(&mut self) -> &mut Vec<f32> { &mut self.sep_y }
+        fn neighbor_buf(&mut self) -> &mut Vec<usize> { &mut self.neighbors }
+        fn separation_slices(&mut self) -> SeparationSlices<'_> {
+            SeparationSlices { pos_x: &self.x, pos_y: &self.y, alive: &self.alive, sep_x: &mut self.sep_x, sep_y: &mut self.sep_y }
+        }
+    }
+
+    #[test]
+    fn parallel_matches_serial_within_tolerance() {
+        let mut serial = Enemies::random(5000, 4);
+        let mut parallel = Enemies::random(5000, 4);
+        let mut grid = SpatialGrid::new(80.0);
+        apply_separation_serial(&mut serial, &mut grid, 40.0, 150.0, 0.016);
+        apply_separation(&mut parallel, &mut grid, 40.0, 150.0, 0.016);
+        let moved = serial.x.iter().zip(&Enemies::random(5000, 4).x).filter(|(a, b)| a != b).count();
+        assert!(moved > 1000, "separation should move overlapping enemies");
+        for i in 0..5000 {
+            assert!((serial.x[i] - parallel.x[i]).abs() < 1e-3 && (serial.y[i] - parallel.y[i]).abs() < 1e-3, "enemy {i}");
+        }
+    }
+}
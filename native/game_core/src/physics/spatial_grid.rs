@@ -0,0 +1,212 @@
+//! Path: native/game_core/src/physics/spatial_grid.rs
+//! Summary: 密なグリッド（セル開始オフセット + セル順に並べた ID 配列）による動的エンティティの近傍クエリ
+//!
+//! 毎フレーム全エンティティを入れ直す用途向け。rebuild は計数ソート（セルごとの個数を数えて累積和を取り、
+//! ID をセル順に並べる）で、内部の Vec はフレームをまたいで再利用するため、要素数が増えない限り
+//! アロケーションは発生しない。グリッドの範囲は rebuild ごとに点群の外接矩形から決める。
+//! 任意の位置・大きさを持つ静的障害物は疎な SpatialHash 側で扱う。
+
+/// グリッドのセル数の上限。点群が極端に散らばっている場合はセルを広げてこの数に収める
+const MAX_CELLS: usize = 1 << 20;
+
+pub struct SpatialGrid {
+    /// 基本のセル幅（px）
+    pub cell_size: f32,
+    /// 直近の rebuild での実効セル幅（通常は cell_size）
+    cell:          f32,
+    origin_x:      f32,
+    origin_y:      f32,
+    cols:          usize,
+    rows:          usize,
+    /// セル c（行優先）の ID は sorted[cell_start[c]..cell_start[c + 1]]
+    cell_start:    Vec<usize>,
+    sorted:        Vec<usize>,
+    /// rebuild の作業バッファ（入力の (id, x, y) とセルごとの書き込み位置）
+    points:        Vec<(usize, f32, f32)>,
+    cursor:        Vec<usize>,
+}
+
+impl SpatialGrid {
+    pub fn new(cell_size: f32) -> Self {
+        Self {
+            cell_size,
+            cell:       cell_size,
+            origin_x:   0.0,
+            origin_y:   0.0,
+            cols:       0,
+            rows:       0,
+            cell_start: vec![0],
+            sorted:     Vec::new(),
+            points:     Vec::new(),
+            cursor:     Vec::new(),
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.points.clear();
+        self.sorted.clear();
+        self.cell_start.clear();
+        self.cell_start.push(0);
+        self.cols = 0;
+        self.rows = 0;
+    }
+
+    /// 登録されている ID の数
+    pub fn len(&self) -> usize {
+        self.sorted.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sorted.is_empty()
+    }
+
+    /// (id, x, y) の列でグリッドを作り直す。同じセル内の ID は渡した順に並ぶ。
+    /// 座標が有限でない点は登録しない
+    pub fn rebuild(&mut self, points: impl IntoIterator<Item = (usize, f32, f32)>) {
+        self.clear();
+        self.points.extend(points.into_iter().filter(|&(_, x, y)| x.is_finite() && y.is_finite()));
+        if self.points.is_empty() {
+            return;
+        }
+
+        let (mut min_x, mut min_y, mut max_x, mut max_y) = (f32::MAX, f32::MAX, f32::MIN, f32::MIN);
+        for &(_, x, y) in &self.points {
+            min_x = min_x.min(x);
+            min_y = min_y.min(y);
+            max_x = max_x.max(x);
+            max_y = max_y.max(y);
+        }
+        let mut cell = self.cell_size;
+        let dims = |cell: f32| (((max_x - min_x) / cell) as usize + 1, ((max_y - min_y) / cell) as usize + 1);
+        while dims(cell).0.saturating_mul(dims(cell).1) > MAX_CELLS {
+            cell *= 2.0;
+        }
+        let (cols, rows) = dims(cell);
+        (self.cell, self.origin_x, self.origin_y, self.cols, self.rows) = (cell, min_x, min_y, cols, rows);
+
+        let key = |x: f32, y: f32| {
+            let cx = (((x - min_x) / cell) as usize).min(cols - 1);
+            let cy = (((y - min_y) / cell) as usize).min(rows - 1);
+            cy * cols + cx
+        };
+        let n_cells = cols * rows;
+
+        // 1. セルごとの個数を数える（cell_start[c + 1] に入れておく）
+        self.cell_start.resize(n_cells + 1, 0);
+        for &(_, x, y) in &self.points {
+            self.cell_start[key(x, y) + 1] += 1;
+        }
+        // 2. 累積和でセルの開始位置にする
+        for c in 0..n_cells {
+            self.cell_start[c + 1] += self.cell_start[c];
+        }
+        // 3. ID をセル順に並べる
+        self.cursor.clear();
+        self.cursor.extend_from_slice(&self.cell_start[..n_cells]);
+        self.sorted.resize(self.points.len(), 0);
+        for &(id, x, y) in &self.points {
+            let c = key(x, y);
+            self.sorted[self.cursor[c]] = id;
+            self.cursor[c] += 1;
+        }
+    }
+
+    /// 指定円の範囲に掛かるセルの ID を `buf` に書き込む（アロケーションなし）。
+    /// 円の外の ID も含みうるので、呼び出し側で距離判定する。
+    /// 呼び出し前に `buf` をクリアする必要はない（内部で `clear()` する）。
+    pub fn query_nearby_into(&self, x: f32, y: f32, radius: f32, buf: &mut Vec<usize>) {
+        buf.clear();
+        if self.cols == 0 {
+            return;
+        }
+        let range = |v: f32, origin: f32, n: usize| {
+            let lo = ((v - radius - origin) / self.cell).floor();
+            let hi = ((v + radius - origin) / self.cell).floor();
+            if hi < 0.0 || lo >= n as f32 || lo.is_nan() || hi.is_nan() {
+                return None;
+            }
+            Some((lo.max(0.0) as usize, (hi as usize).min(n - 1)))
+        };
+        let (Some((x0, x1)), Some((y0, y1))) = (range(x, self.origin_x, self.cols), range(y, self.origin_y, self.rows)) else {
+            return;
+        };
+        for cy in y0..=y1 {
+            // 同じ行のセルは sorted 上で連続している
+            let from = self.cell_start[cy * self.cols + x0];
+            let to = self.cell_start[cy * self.cols + x1 + 1];
+            buf.extend_from_slice(&self.sorted[from..to]);
+        }
+    }
+
+    /// 後方互換用（`query_nearby_into` への移行が完了したら削除可）
+    pub fn query_nearby(&self, x: f32, y: f32, radius: f32) -> Vec<usize> {
+        let mut buf = Vec::new();
+        self.query_nearby_into(x, y, radius, &mut buf);
+        buf
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::physics::rng::SimpleRng;
+
+    fn random_points(n: usize, seed: u64) -> Vec<(usize, f32, f32)> {
+        let mut rng = SimpleRng::new(seed);
+        (0..n).map(|i| (i, (rng.next_u32() % 4000) as f32, (rng.next_u32() % 3000) as f32 - 500.0)).collect()
+    }
+
+    #[test]
+    fn queries_return_every_point_in_range_once() {
+        let points = random_points(2000, 7);
+        let mut grid = SpatialGrid::new(80.0);
+        grid.rebuild(points.iter().copied());
+        assert_eq!(grid.len(), 2000);
+
+        let mut buf = Vec::new();
+        for &(qx, qy, r) in &[(0.0, 0.0, 40.0), (1999.5, 1000.0, 200.0), (3990.0, 2490.0, 120.0), (-300.0, -900.0, 50.0)] {
+            grid.query_nearby_into(qx, qy, r, &mut buf);
+            let mut found = buf.clone();
+            found.sort_unstable();
+            found.dedup();
+            assert_eq!(found.len(), buf.len(), "duplicate IDs");
+            for &(id, x, y) in &points {
+                if (x - qx).powi(2) + (y - qy).powi(2) <= r * r {
+                    assert!(found.binary_search(&id).is_ok(), "missing {id} near ({qx}, {qy})");
+                }
+            }
+        }
+        grid.query_nearby_into(10_000.0, 10_000.0, 100.0, &mut buf);
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn rebuild_reuses_buffers() {
+        let points = random_points(5000, 1);
+        let mut grid = SpatialGrid::new(80.0);
+        grid.rebuild(points.clone());
+        let capacities = |g: &SpatialGrid| {
+            [g.sorted.capacity(), g.cell_start.capacity(), g.points.capacity(), g.cursor.capacity()]
+        };
+        let first = capacities(&grid);
+        // 同じ点群・その部分集合での rebuild ではバッファを確保し直さない
+        for n in [5000, 2500, 100] {
+            grid.rebuild(points[..n].to_vec());
+            assert!(capacities(&grid).iter().zip(&first).all(|(now, before)| now <= before), "grew at n = {n}");
+        }
+
+        grid.clear();
+        assert!(grid.is_empty() && grid.query_nearby(0.0, 0.0, 1000.0).is_empty());
+    }
+
+    #[test]
+    fn widely_scattered_points_stay_within_cell_budget() {
+        let mut grid = SpatialGrid::new(1.0);
+        grid.rebuild([(0, 0.0, 0.0), (1, 1.0e7, 1.0e7), (2, 5.0, 5.0), (3, f32::NAN, 0.0)]);
+        assert!(grid.cols * grid.rows <= MAX_CELLS);
+        assert_eq!(grid.len(), 3);
+        let mut near = grid.query_nearby(3.0, 3.0, 5.0);
+        near.sort_unstable();
+        assert_eq!(near, vec![0, 2]);
+    }
+}
@@ -1,6 +1,11 @@
 //! Path: native/game_core/src/physics/spatial_hash.rs
 //! Summary: 空間ハッシュによる衝突検出・近傍クエリ
+//!
+//! SpatialHash は疎なハッシュマップで、任意の位置・大きさの静的障害物を登録する。
+//! 毎フレーム入れ直す敵は密なグリッド（SpatialGrid）で扱う。
 
+use super::collider::ObstacleShape;
+use super::spatial_grid::SpatialGrid;
 use rustc_hash::FxHashMap;
 
 pub struct SpatialHash {
@@ -25,6 +30,17 @@ impl SpatialHash {
         self.cells.entry(key).or_default().push(id);
     }
 
+    /// 矩形 [min_x, max_x] × [min_y, max_y] に掛かるすべてのセルに id を登録する（大きな静的障害物用）
+    pub fn insert_aabb(&mut self, id: usize, min_x: f32, min_y: f32, max_x: f32, max_y: f32) {
+        let (x0, y0) = self.cell_key(min_x, min_y);
+        let (x1, y1) = self.cell_key(max_x, max_y);
+        for ix in x0..=x1 {
+            for iy in y0..=y1 {
+                self.cells.entry((ix, iy)).or_default().push(id);
+            }
+        }
+    }
+
     fn cell_key(&self, x: f32, y: f32) -> (i32, i32) {
         (
             (x / self.cell_size).floor() as i32,
@@ -56,16 +72,35 @@ impl SpatialHash {
     }
 }
 
-#[derive(Clone, Copy, Debug)]
+/// 静的障害物。(x, y) は形状の中心、kind: 0 = 木, 1 = 岩
+#[derive(Clone, Debug, PartialEq)]
 pub struct StaticObstacle {
-    pub x:      f32,
-    pub y:      f32,
-    pub radius: f32,
-    pub kind:   u8,
+    pub x:     f32,
+    pub y:     f32,
+    pub shape: ObstacleShape,
+    pub kind:  u8,
+}
+
+impl StaticObstacle {
+    pub fn circle(x: f32, y: f32, radius: f32, kind: u8) -> Self {
+        Self { x, y, shape: ObstacleShape::Circle { radius }, kind }
+    }
+
+    /// 外接矩形 (min_x, min_y, max_x, max_y)
+    pub fn aabb(&self) -> (f32, f32, f32, f32) {
+        let (hx, hy) = self.shape.half_extents();
+        (self.x - hx, self.y - hy, self.x + hx, self.y + hy)
+    }
+
+    /// 中心 (cx, cy)・半径 r の円をこの障害物の外に出す押し出しベクトル（重なっていなければ None）
+    pub fn penetration(&self, cx: f32, cy: f32, r: f32) -> Option<(f32, f32)> {
+        self.shape.penetration(cx - self.x, cy - self.y, r)
+    }
 }
 
 pub struct CollisionWorld {
-    pub dynamic:     SpatialHash,
+    /// 生存中の敵（毎フレーム rebuild する。分離パスと衝突判定で共有）
+    pub dynamic:     SpatialGrid,
     pub static_hash: SpatialHash,
     pub obstacles:   Vec<StaticObstacle>,
 }
@@ -73,22 +108,27 @@ pub struct CollisionWorld {
 impl CollisionWorld {
     pub fn new(cell_size: f32) -> Self {
         Self {
-            dynamic:     SpatialHash::new(cell_size),
+            dynamic:     SpatialGrid::new(cell_size),
             static_hash: SpatialHash::new(cell_size),
             obstacles:   Vec::new(),
         }
     }
 
+    /// 円形の障害物 (x, y, radius, kind) で静的障害物を置き換える
     pub fn rebuild_static(&mut self, obstacles: &[(f32, f32, f32, u8)]) {
-        self.obstacles.clear();
+        self.set_static(
+            obstacles.iter().map(|&(x, y, radius, kind)| StaticObstacle::circle(x, y, radius, kind)).collect(),
+        );
+    }
+
+    /// 任意形状の静的障害物で置き換える。各障害物は外接矩形が掛かるすべてのセルに登録する
+    pub fn set_static(&mut self, obstacles: Vec<StaticObstacle>) {
         self.static_hash.clear();
-        for (x, y, radius, kind) in obstacles {
-            let idx = self.obstacles.len();
-            self.obstacles.push(StaticObstacle {
-                x: *x, y: *y, radius: *radius, kind: *kind,
-            });
-            self.static_hash.insert(idx, *x, *y);
+        for (idx, o) in obstacles.iter().enumerate() {
+            let (min_x, min_y, max_x, max_y) = o.aabb();
+            self.static_hash.insert_aabb(idx, min_x, min_y, max_x, max_y);
         }
+        self.obstacles = obstacles;
     }
 
     pub fn query_static_nearby_into(
@@ -96,17 +136,85 @@ impl CollisionWorld {
         x: f32, y: f32, radius: f32,
         buf: &mut Vec<usize>,
     ) {
-        self.static_hash.query_nearby_into(x, y, radius + 80.0, buf);
+        self.static_hash.query_nearby_into(x, y, radius, buf);
+        // 複数セルに登録した障害物が重複して返らないようにする
+        buf.sort_unstable();
+        buf.dedup();
         let obstacles = &self.obstacles;
-        buf.retain(|&idx| {
-            if let Some(o) = obstacles.get(idx) {
-                let hit_r = radius + o.radius;
-                let dx = x - o.x;
-                let dy = y - o.y;
-                dx * dx + dy * dy <= hit_r * hit_r
-            } else {
-                false
+        buf.retain(|&idx| obstacles.get(idx).is_some_and(|o| o.penetration(x, y, radius).is_some()));
+    }
+
+    /// 半径 r の円が (x0, y0) から (x1, y1) へ動くとき、静的障害物に最初に接する時刻 t（0.0..=1.0）
+    pub fn sweep_static(&self, x0: f32, y0: f32, x1: f32, y1: f32, r: f32, buf: &mut Vec<usize>) -> Option<f32> {
+        let (dx, dy) = (x1 - x0, y1 - y0);
+        // 移動線分全体を覆う円で候補を絞る
+        let reach = (dx * dx + dy * dy).sqrt() / 2.0 + r;
+        self.query_static_nearby_into(x0 + dx / 2.0, y0 + dy / 2.0, reach, buf);
+        buf.iter()
+            .filter_map(|&idx| {
+                let o = &self.obstacles[idx];
+                o.shape.sweep(x0 - o.x, y0 - o.y, dx, dy, r)
+            })
+            .min_by(f32::total_cmp)
+    }
+
+    /// 中心 (cx, cy)・半径 r の円を、重なっているすべての静的障害物の外へ出すまでの移動量の合計。
+    /// 押し出し先が別の障害物に掛かる場合に備えて最大 iterations 回繰り返す
+    pub fn resolve_circle(&self, cx: f32, cy: f32, r: f32, iterations: u32, buf: &mut Vec<usize>) -> (f32, f32) {
+        let (mut x, mut y) = (cx, cy);
+        for _ in 0..iterations {
+            self.query_static_nearby_into(x, y, r, buf);
+            let mut pushed = false;
+            for &idx in buf.iter() {
+                if let Some((dx, dy)) = self.obstacles[idx].penetration(x, y, r) {
+                    x += dx;
+                    y += dy;
+                    pushed = true;
+                }
             }
-        });
+            if !pushed {
+                break;
+            }
+        }
+        (x - cx, y - cy)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn large_obstacles_are_found_from_every_cell_they_cover() {
+        let mut collision = CollisionWorld::new(80.0);
+        collision.set_static(vec![
+            StaticObstacle { x: 500.0, y: 100.0, shape: ObstacleShape::Rect { width: 1000.0, height: 40.0 }, kind: 1 },
+            StaticObstacle::circle(300.0, 400.0, 20.0, 0),
+        ]);
+        let mut buf = Vec::new();
+        // 中心から遠い壁の端でも当たり、重複せず 1 件だけ返る
+        collision.query_static_nearby_into(980.0, 130.0, 12.0, &mut buf);
+        assert_eq!(buf, vec![0]);
+        collision.query_static_nearby_into(980.0, 140.0, 12.0, &mut buf);
+        assert!(buf.is_empty());
+        collision.query_static_nearby_into(330.0, 400.0, 12.0, &mut buf);
+        assert_eq!(buf, vec![1]);
+    }
+
+    #[test]
+    fn resolve_circle_pushes_out_of_walls_and_corners() {
+        let mut collision = CollisionWorld::new(80.0);
+        // L 字の壁
+        collision.set_static(vec![
+            StaticObstacle { x: 200.0, y: 100.0, shape: ObstacleShape::Rect { width: 400.0, height: 40.0 }, kind: 1 },
+            StaticObstacle { x: 20.0, y: 300.0, shape: ObstacleShape::Rect { width: 40.0, height: 400.0 }, kind: 1 },
+        ]);
+        let mut buf = Vec::new();
+        let (dx, dy) = collision.resolve_circle(300.0, 125.0, 10.0, 5, &mut buf);
+        assert!(dx.abs() < 1e-3 && (dy - 5.0).abs() < 1e-3);
+        // 内角に食い込んだ円は両方の壁の外に出る
+        let (dx, dy) = collision.resolve_circle(45.0, 125.0, 10.0, 5, &mut buf);
+        collision.query_static_nearby_into(45.0 + dx, 125.0 + dy, 10.0 - 1e-3, &mut buf);
+        assert!(buf.is_empty());
     }
 }
@@ -0,0 +1,297 @@
+//! Path: native/game_core/src/physics/spawn_placement.rs
+//! Summary: 障害物・ワールド境界を避けたスポーン位置の決定と隊形（円弧・円周・直線）
+//!
+//! 敵はプレイヤー周囲 SPAWN_MIN_DIST〜SPAWN_MAX_DIST の円環に出す（ボスの召喚はボス中心・召喚半径の円周）。候補が障害物と重なるか
+//! ワールドからはみ出す場合は乱数で RANDOM_ATTEMPTS 回まで引き直し、それでも見つからなければ
+//! 乱数を使わない走査で空いている位置を探す。マップにスポーン領域（SpawnZone）があれば、
+//! その内側だけを候補にする。座標はすべて中心基準。
+
+use super::bounds::WorldBounds;
+use super::rng::SimpleRng;
+use super::spatial_hash::CollisionWorld;
+use crate::constants::{SPAWN_MAX_DIST, SPAWN_MIN_DIST};
+use crate::tile_map::SpawnZone;
+use serde::Deserialize;
+use std::f32::consts::TAU;
+
+/// 乱数で候補を引き直す回数
+const RANDOM_ATTEMPTS: u32 = 8;
+/// フォールバック走査で試す方向の数（円環上を等分する）
+const FALLBACK_ANGLES: u32 = 32;
+
+/// 複数体をまとめて出すときの並べ方
+#[derive(Clone, Copy, Debug, Default, PartialEq, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SpawnFormation {
+    /// 1 体ずつ円環上のランダムな位置（従来の挙動）
+    #[default]
+    Scatter,
+    /// プレイヤーを中心とする spread ラジアンの円弧に等間隔
+    Arc { spread: f32 },
+    /// プレイヤーを囲む円周上に等間隔
+    Ring,
+    /// プレイヤーへの方向と直交する直線上に spacing px 間隔
+    Line { spacing: f32 },
+}
+
+impl SpawnFormation {
+    /// n 体の隊形の i 番目の中心座標。(cx, cy) はプレイヤー中心、
+    /// base_angle・dist はプレイヤーから見た隊形の中心の方向と距離
+    pub fn slot(&self, cx: f32, cy: f32, base_angle: f32, dist: f32, i: usize, n: usize) -> (f32, f32) {
+        let along = |angle: f32| (cx + angle.cos() * dist, cy + angle.sin() * dist);
+        match *self {
+            Self::Scatter => along(base_angle),
+            Self::Arc { spread } => {
+                let t = if n > 1 { i as f32 / (n - 1) as f32 - 0.5 } else { 0.0 };
+                along(base_angle + t * spread)
+            }
+            Self::Ring => along(base_angle + i as f32 * TAU / n.max(1) as f32),
+            Self::Line { spacing } => {
+                let (x, y) = along(base_angle);
+                let offset = (i as f32 - (n.saturating_sub(1)) as f32 / 2.0) * spacing;
+                (x - base_angle.sin() * offset, y + base_angle.cos() * offset)
+            }
+        }
+    }
+
+    /// 値の妥当性（スポーンスケジュールの検証から呼ぶ）
+    pub fn validate(&self) -> Result<(), &'static str> {
+        match *self {
+            Self::Arc { spread } if !(spread.is_finite() && spread >= 0.0) => Err("arc spread must be >= 0"),
+            Self::Line { spacing } if !(spacing.is_finite() && spacing > 0.0) => Err("line spacing must be > 0"),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// スポーンする 1 体分の当たり判定
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SpawnBody {
+    pub radius: f32,
+    /// false なら障害物との重なりを無視する（障害物をすり抜ける敵）
+    pub solid:  bool,
+}
+
+/// プレイヤー周囲のスポーン位置を探す
+pub struct SpawnPlacer<'a> {
+    collision: &'a CollisionWorld,
+    bounds:    WorldBounds,
+    center_x:  f32,
+    center_y:  f32,
+    zones:     &'a [SpawnZone],
+    min_dist:  f32,
+    max_dist:  f32,
+}
+
+impl<'a> SpawnPlacer<'a> {
+    /// (center_x, center_y) はプレイヤーの中心座標
+    pub fn new(collision: &'a CollisionWorld, bounds: WorldBounds, center_x: f32, center_y: f32) -> Self {
+        Self {
+            collision,
+            bounds,
+            center_x,
+            center_y,
+            zones: &[],
+            min_dist: SPAWN_MIN_DIST,
+            max_dist: SPAWN_MAX_DIST,
+        }
+    }
+
+    /// スポーン領域を設定する（空ならワールド全体）
+    pub fn with_zones(self, zones: &'a [SpawnZone]) -> Self {
+        Self { zones, ..self }
+    }
+
+    /// 中心からの距離の範囲を設定する（既定は SPAWN_MIN_DIST〜SPAWN_MAX_DIST）
+    pub fn with_distance(self, min_dist: f32, max_dist: f32) -> Self {
+        Self { min_dist, max_dist, ..self }
+    }
+
+    fn random_dist(&self, rng: &mut SimpleRng) -> f32 {
+        self.min_dist + rng.next_f32() * (self.max_dist - self.min_dist)
+    }
+
+    /// 中心 (x, y) に body を置けるか（ワールドに収まり、スポーン領域の内側で、solid なら障害物と重ならない）
+    pub fn is_free(&self, x: f32, y: f32, body: SpawnBody, buf: &mut Vec<usize>) -> bool {
+        if !self.bounds.contains_circle(x, y, body.radius) {
+            return false;
+        }
+        if !self.zones.is_empty() && !self.zones.iter().any(|z| z.contains(x, y)) {
+            return false;
+        }
+        if !body.solid {
+            return true;
+        }
+        self.collision.query_static_nearby_into(x, y, body.radius, buf);
+        buf.is_empty()
+    }
+
+    fn at(&self, angle: f32, dist: f32) -> (f32, f32) {
+        (self.center_x + angle.cos() * dist, self.center_y + angle.sin() * dist)
+    }
+
+    /// 円環上のランダムな空き位置を 1 つ返す
+    pub fn scatter(&self, rng: &mut SimpleRng, body: SpawnBody, buf: &mut Vec<usize>) -> (f32, f32) {
+        let mut angle = 0.0;
+        for _ in 0..RANDOM_ATTEMPTS {
+            angle = rng.next_f32() * TAU;
+            let dist = self.random_dist(rng);
+            let (x, y) = self.at(angle, dist);
+            if self.is_free(x, y, body, buf) {
+                return (x, y);
+            }
+        }
+        self.fallback(angle, body, buf)
+    }
+
+    /// 乱数を使わずに空き位置を探す。最後に試した角度から円環上を 3 つの距離で一周し、
+    /// それでもなければ（マップが円環より狭い場合など）各方向を境界内に寄せた位置、
+    /// 各スポーン領域の中心を順に試す。どこも塞がっていれば最後の角度を境界内に寄せた位置を返す
+    fn fallback(&self, angle: f32, body: SpawnBody, buf: &mut Vec<usize>) -> (f32, f32) {
+        let angles = (0..FALLBACK_ANGLES).map(|k| angle + k as f32 * TAU / FALLBACK_ANGLES as f32);
+        let mid = (self.min_dist + self.max_dist) / 2.0;
+        for dist in [self.min_dist, mid, self.max_dist] {
+            for a in angles.clone() {
+                let (x, y) = self.at(a, dist);
+                if self.is_free(x, y, body, buf) {
+                    return (x, y);
+                }
+            }
+        }
+        for a in angles {
+            let (x, y) = self.at(a, self.min_dist);
+            let (x, y) = self.bounds.clamp_center(x, y, body.radius);
+            if self.is_free(x, y, body, buf) {
+                return (x, y);
+            }
+        }
+        for zone in self.zones {
+            let (x, y) = (zone.x + zone.width / 2.0, zone.y + zone.height / 2.0);
+            if self.is_free(x, y, body, buf) {
+                return (x, y);
+            }
+        }
+        let (x, y) = self.at(angle, self.min_dist);
+        self.bounds.clamp_center(x, y, body.radius)
+    }
+
+    /// bodies を formation に並べた中心座標を順に out に積む。
+    /// 隊形上の位置が塞がっている個体だけ scatter で個別に置き直す
+    pub fn formation(
+        &self,
+        formation: SpawnFormation,
+        rng: &mut SimpleRng,
+        bodies: &[SpawnBody],
+        buf: &mut Vec<usize>,
+        out: &mut Vec<(f32, f32)>,
+    ) {
+        if formation == SpawnFormation::Scatter {
+            out.extend(bodies.iter().map(|&body| self.scatter(rng, body, buf)));
+            return;
+        }
+        let base_angle = rng.next_f32() * TAU;
+        let dist = self.random_dist(rng);
+        let n = bodies.len();
+        for (i, &body) in bodies.iter().enumerate() {
+            let (x, y) = formation.slot(self.center_x, self.center_y, base_angle, dist, i, n);
+            if self.is_free(x, y, body, buf) {
+                out.push((x, y));
+            } else {
+                out.push(self.scatter(rng, body, buf));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BODY: SpawnBody = SpawnBody { radius: 20.0, solid: true };
+
+    #[test]
+    fn scatter_avoids_obstacles_and_world_edges() {
+        // プレイヤーを左上寄りに置き、円環の一部がワールド外になるようにする
+        let mut collision = CollisionWorld::new(80.0);
+        let obstacles: Vec<_> = (0..24)
+            .map(|k| {
+                let a = k as f32 * TAU / 24.0;
+                (900.0 + a.cos() * 1000.0, 900.0 + a.sin() * 1000.0, 120.0, 0)
+            })
+            .collect();
+        collision.rebuild_static(&obstacles);
+        let placer = SpawnPlacer::new(&collision, WorldBounds::new(3000.0, 3000.0), 900.0, 900.0);
+
+        let mut rng = SimpleRng::new(3);
+        let mut buf = Vec::new();
+        for _ in 0..200 {
+            let (x, y) = placer.scatter(&mut rng, BODY, &mut buf);
+            assert!(placer.is_free(x, y, BODY, &mut buf), "({x}, {y}) is blocked");
+        }
+    }
+
+    #[test]
+    fn falls_back_deterministically_when_ring_is_outside_world() {
+        // 円環がすべてワールド外になる狭いマップでも、境界内の空き位置を返す
+        let mut collision = CollisionWorld::new(80.0);
+        collision.rebuild_static(&[(480.0, 250.0, 40.0, 0)]);
+        let placer = SpawnPlacer::new(&collision, WorldBounds::new(500.0, 500.0), 250.0, 250.0);
+        let mut buf = Vec::new();
+
+        let a = placer.scatter(&mut SimpleRng::new(5), BODY, &mut buf);
+        let b = placer.scatter(&mut SimpleRng::new(5), BODY, &mut buf);
+        assert_eq!(a, b);
+        assert!(placer.is_free(a.0, a.1, BODY, &mut buf));
+    }
+
+    #[test]
+    fn formations_keep_their_shape() {
+        let collision = CollisionWorld::new(80.0);
+        let placer = SpawnPlacer::new(&collision, WorldBounds::new(8000.0, 8000.0), 4000.0, 4000.0);
+        let dist = |&(x, y): &(f32, f32)| ((x - 4000.0).powi(2) + (y - 4000.0).powi(2)).sqrt();
+        let mut buf = Vec::new();
+
+        let mut ring = Vec::new();
+        placer.formation(SpawnFormation::Ring, &mut SimpleRng::new(1), &[BODY; 6], &mut buf, &mut ring);
+        assert_eq!(ring.len(), 6);
+        assert!(ring.iter().all(|p| (dist(p) - dist(&ring[0])).abs() < 0.1));
+
+        let mut line = Vec::new();
+        let formation = SpawnFormation::Line { spacing: 50.0 };
+        placer.formation(formation, &mut SimpleRng::new(1), &[BODY; 3], &mut buf, &mut line);
+        let gap = ((line[1].0 - line[0].0).powi(2) + (line[1].1 - line[0].1).powi(2)).sqrt();
+        assert!((gap - 50.0).abs() < 0.1);
+        assert!((dist(&line[0]) - dist(&line[2])).abs() < 0.1);
+
+        assert!(SpawnFormation::Line { spacing: 0.0 }.validate().is_err());
+    }
+
+    #[test]
+    fn custom_distance_places_ring_around_any_center() {
+        let mut collision = CollisionWorld::new(80.0);
+        collision.rebuild_static(&[(1120.0, 1000.0, 30.0, 0)]);
+        let placer = SpawnPlacer::new(&collision, WorldBounds::new(4000.0, 4000.0), 1000.0, 1000.0)
+            .with_distance(120.0, 120.0);
+        let mut buf = Vec::new();
+        let mut ring = Vec::new();
+        placer.formation(SpawnFormation::Ring, &mut SimpleRng::new(2), &[BODY; 8], &mut buf, &mut ring);
+        for &(x, y) in &ring {
+            let d = ((x - 1000.0).powi(2) + (y - 1000.0).powi(2)).sqrt();
+            assert!((d - 120.0).abs() < 0.1, "({x}, {y}) is {d}px away");
+            assert!(placer.is_free(x, y, BODY, &mut buf), "({x}, {y}) is blocked");
+        }
+    }
+
+    #[test]
+    fn spawns_stay_inside_spawn_zones() {
+        let collision = CollisionWorld::new(80.0);
+        let zones = [SpawnZone { x: 0.0, y: 0.0, width: 4000.0, height: 500.0 }];
+        let placer = SpawnPlacer::new(&collision, WorldBounds::new(4000.0, 4000.0), 2000.0, 1200.0).with_zones(&zones);
+        let mut rng = SimpleRng::new(11);
+        let mut buf = Vec::new();
+        for _ in 0..50 {
+            let (x, y) = placer.scatter(&mut rng, BODY, &mut buf);
+            assert!(zones[0].contains(x, y), "({x}, {y}) is outside the zone");
+        }
+    }
+}
@@ -0,0 +1,186 @@
+//! Path: native/game_core/src/player_stats.rs
+//! Summary: パッシブアイテムのスロットと、そこから算出するプレイヤー能力値（PlayerStats）
+//!
+//! パッシブアイテムの種類と 1 レベルあたりの補正量は entity_params の `passives` テーブルで定義する。
+//! 武器システム・移動・アイテム収集・被ダメージは定数ではなく PlayerStats を参照する。
+
+use crate::constants::{PICKUP_RADIUS, PLAYER_BASE_MAX_HP, PLAYER_SPEED};
+use crate::entity_params::PassiveParams;
+use serde::Deserialize;
+
+pub const MAX_PASSIVE_SLOTS: usize = 6;
+
+/// PlayerStat の種別数
+pub const PLAYER_STAT_COUNT: usize = 8;
+
+/// 被ダメージ軽減率（Armor）の上限
+pub const MAX_ARMOR: f32 = 0.75;
+/// クールダウン倍率の下限
+pub const MIN_COOLDOWN_MULTIPLIER: f32 = 0.4;
+
+/// パッシブアイテムが補正する能力値。per_level の意味は種別ごとに異なる
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlayerStat {
+    /// 与ダメージ倍率 +per_level（0.1 = +10%）
+    Might,
+    /// 被ダメージ軽減率 +per_level（上限 MAX_ARMOR）
+    Armor,
+    /// 最大 HP +per_level
+    MaxHp,
+    /// クールダウン短縮率 +per_level（下限 MIN_COOLDOWN_MULTIPLIER）
+    Cooldown,
+    /// 移動速度倍率 +per_level
+    MoveSpeed,
+    /// アイテム収集半径倍率 +per_level
+    PickupRadius,
+    /// 幸運 +per_level（ドロップ率・会心率の倍率）
+    Luck,
+    /// 弾数 +per_level（複数発射する武器のみ）
+    ProjectileCount,
+}
+
+impl PlayerStat {
+    /// 表示用ラベル
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Might           => "Damage",
+            Self::Armor           => "Damage taken",
+            Self::MaxHp           => "Max HP",
+            Self::Cooldown        => "Cooldown",
+            Self::MoveSpeed       => "Move speed",
+            Self::PickupRadius    => "Pickup radius",
+            Self::Luck            => "Luck",
+            Self::ProjectileCount => "Projectiles",
+        }
+    }
+
+    /// 補正量の表示文字列（例: "+10%", "-5%", "+20"）
+    pub fn format_bonus(self, bonus: f32) -> String {
+        match self {
+            Self::MaxHp | Self::ProjectileCount => format!("+{:.0}", bonus),
+            Self::Armor | Self::Cooldown        => format!("-{:.0}%", bonus * 100.0),
+            _                                   => format!("+{:.0}%", bonus * 100.0),
+        }
+    }
+}
+
+// ─── PassiveSlot ──────────────────────────────────────────────
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PassiveSlot {
+    pub kind_id: u8,
+    pub level:   u32,
+}
+
+impl PassiveSlot {
+    pub fn new(kind_id: u8) -> Self {
+        Self { kind_id, level: 1 }
+    }
+}
+
+// ─── PlayerStats ──────────────────────────────────────────────
+
+/// パッシブアイテム補正後のプレイヤー能力値
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PlayerStats {
+    /// 与ダメージ倍率
+    pub might:               f32,
+    /// 被ダメージ軽減率（0.0..=MAX_ARMOR）
+    pub armor:               f32,
+    pub max_hp:              f32,
+    /// 武器クールダウンに掛ける倍率
+    pub cooldown_multiplier: f32,
+    /// 移動速度（px/s）
+    pub move_speed:          f32,
+    /// アイテム自動収集半径（px）
+    pub pickup_radius:       f32,
+    /// ドロップ率・会心率に掛ける倍率
+    pub luck:                f32,
+    /// 複数発射する武器に加算する弾数
+    pub extra_projectiles:   usize,
+}
+
+impl Default for PlayerStats {
+    fn default() -> Self {
+        Self {
+            might:               1.0,
+            armor:               0.0,
+            max_hp:              PLAYER_BASE_MAX_HP,
+            cooldown_multiplier: 1.0,
+            move_speed:          PLAYER_SPEED,
+            pickup_radius:       PICKUP_RADIUS,
+            luck:                1.0,
+            extra_projectiles:   0,
+        }
+    }
+}
+
+impl PlayerStats {
+    /// 所持パッシブから能力値を算出する（未登録の ID は無視）
+    pub fn from_passives(slots: &[PassiveSlot]) -> Self {
+        let mut bonus = [0.0_f32; PLAYER_STAT_COUNT];
+        for slot in slots {
+            let Some(p) = PassiveParams::try_get(slot.kind_id) else { continue };
+            bonus[p.stat as usize] += p.bonus(slot.level);
+        }
+        let base = Self::default();
+        Self {
+            might:               base.might + bonus[PlayerStat::Might as usize],
+            armor:               bonus[PlayerStat::Armor as usize].min(MAX_ARMOR),
+            max_hp:              base.max_hp + bonus[PlayerStat::MaxHp as usize],
+            cooldown_multiplier: (1.0 - bonus[PlayerStat::Cooldown as usize]).max(MIN_COOLDOWN_MULTIPLIER),
+            move_speed:          base.move_speed * (1.0 + bonus[PlayerStat::MoveSpeed as usize]),
+            pickup_radius:       base.pickup_radius * (1.0 + bonus[PlayerStat::PickupRadius as usize]),
+            luck:                base.luck + bonus[PlayerStat::Luck as usize],
+            extra_projectiles:   bonus[PlayerStat::ProjectileCount as usize].round() as usize,
+        }
+    }
+
+    /// 武器の基礎ダメージに Might を掛ける
+    pub fn scale_damage(&self, damage: i32) -> i32 {
+        (damage as f32 * self.might).round() as i32
+    }
+
+    /// プレイヤーへのダメージに Armor を適用する
+    pub fn mitigate(&self, damage: f32) -> f32 {
+        damage * (1.0 - self.armor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity_params::{PASSIVE_ID_ARMOR, PASSIVE_ID_DUPLICATOR, PASSIVE_ID_EMPTY_TOME, PASSIVE_ID_SPINACH};
+
+    #[test]
+    fn no_passives_gives_base_stats() {
+        let stats = PlayerStats::from_passives(&[]);
+        assert_eq!(stats, PlayerStats::default());
+        assert_eq!(stats.scale_damage(10), 10);
+    }
+
+    #[test]
+    fn passive_levels_stack_into_stats() {
+        let slots = [
+            PassiveSlot { kind_id: PASSIVE_ID_SPINACH, level: 3 },
+            PassiveSlot::new(PASSIVE_ID_DUPLICATOR),
+        ];
+        let stats = PlayerStats::from_passives(&slots);
+        assert!((stats.might - 1.3).abs() < 0.001);
+        assert_eq!(stats.scale_damage(10), 13);
+        assert_eq!(stats.extra_projectiles, 1);
+    }
+
+    #[test]
+    fn armor_and_cooldown_respect_max_level() {
+        let slots = [
+            PassiveSlot { kind_id: PASSIVE_ID_ARMOR, level: 100 },
+            PassiveSlot { kind_id: PASSIVE_ID_EMPTY_TOME, level: 5 },
+        ];
+        let stats = PlayerStats::from_passives(&slots);
+        // Lv は max_level（5）で頭打ち
+        assert!((stats.armor - 0.25).abs() < 0.001);
+        assert!((stats.mitigate(100.0) - 75.0).abs() < 0.001);
+        assert!((stats.cooldown_multiplier - 0.6).abs() < 0.001);
+    }
+}
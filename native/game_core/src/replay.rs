@@ -0,0 +1,445 @@
+//! Path: native/game_core/src/replay.rs
+//! Summary: リプレイファイル（ワールドを変更するコマンドのフレームごとの記録）のバイナリ形式
+//!
+//! ヘッダ（マジック・バージョン・ワールドのシード）のあとに、コマンドと検証用チェックポイントを
+//! 適用した順に並べる。各レコードは「直前のレコードからのフレーム差（可変長整数）・タグ・ペイロード」で、
+//! 数値はすべてリトルエンディアン。記録と再生（コマンドの適用）は game_native 側で行う。
+
+use std::fmt;
+use std::io::{self, Read, Write};
+
+const MAGIC: [u8; 4] = *b"EXRP";
+/// 形式を変えたら上げる（古いファイルは読み込みを拒否する）
+pub const REPLAY_VERSION: u16 = 2;
+/// 記録中に何フレームごとにチェックポイントを書くか
+pub const REPLAY_CHECKPOINT_INTERVAL: u32 = 60;
+/// 文字列・障害物リストの長さの上限（壊れたファイルで巨大な確保をしないため）
+const MAX_PAYLOAD_LEN: usize = 64 << 20;
+
+const TAG_SET_INPUT: u8 = 0;
+const TAG_STEP: u8 = 1;
+const TAG_SPAWN_ENEMIES: u8 = 2;
+const TAG_SPAWN_ELITE: u8 = 3;
+const TAG_SPAWN_BOSS: u8 = 4;
+const TAG_ADD_WEAPON: u8 = 5;
+const TAG_ADD_PASSIVE: u8 = 6;
+const TAG_SKIP_LEVEL_UP: u8 = 7;
+const TAG_SET_WORLD_SIZE: u8 = 8;
+const TAG_SET_MAP_OBSTACLES: u8 = 9;
+const TAG_LOAD_MAP: u8 = 10;
+const TAG_SET_SPAWN_SCHEDULE: u8 = 11;
+const TAG_CHECKPOINT: u8 = 0xFF;
+
+/// ワールドを変更するコマンド（NIF・描画スレッドの入力・物理ステップ）
+#[derive(Clone, Debug, PartialEq)]
+pub enum ReplayCommand {
+    /// プレイヤーの移動入力（set_player_input / push_tick / 描画スレッドの入力）
+    SetInput { dx: f32, dy: f32 },
+    /// 物理ステップ 1 回。physics_step は delta_ms を f32 に変換して使うので f32 で持つ
+    Step { delta_ms: f32 },
+    SpawnEnemies { kind_id: u8, count: u32 },
+    /// hp_multiplier <= 0 ならコンテンツの EliteParams に従う
+    SpawnElite { kind_id: u8, count: u32, hp_multiplier: f64 },
+    SpawnBoss { kind_id: u8 },
+    AddWeapon { weapon_id: u8 },
+    AddPassive { passive_id: u8 },
+    SkipLevelUp,
+    SetWorldSize { width: f64, height: f64 },
+    /// 円形障害物 (x, y, radius, kind)
+    SetMapObstacles { obstacles: Vec<(f32, f32, f32, u8)> },
+    /// マップ JSON の内容（ファイルパスではなく中身を持つので、リプレイ単体で再生できる）
+    LoadMap { json: String },
+    /// スポーンスケジュール JSON の内容
+    SetSpawnSchedule { json: String },
+}
+
+/// 再生時に状態のずれを検出するためのワールドの要約
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ReplayCheckpoint {
+    pub player_x:      f32,
+    pub player_y:      f32,
+    pub player_hp:     f32,
+    pub score:         u32,
+    pub kill_count:    u32,
+    pub level:         u32,
+    pub exp:           u32,
+    pub alive_enemies: u32,
+    pub bullets:       u32,
+    /// ワールド全体のチェックサム（GameWorldInner::checksum）
+    pub checksum:      u64,
+}
+
+/// リプレイの 1 レコード
+#[derive(Clone, Debug, PartialEq)]
+pub enum ReplayEvent {
+    Command(ReplayCommand),
+    Checkpoint(ReplayCheckpoint),
+}
+
+/// リプレイファイルのヘッダ
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ReplayHeader {
+    /// create_world に渡したシード
+    pub seed: u64,
+}
+
+/// リプレイの読み込みエラー
+#[derive(Debug)]
+pub enum ReplayError {
+    Io(io::Error),
+    /// リプレイファイルではない
+    BadMagic,
+    /// 対応していないバージョン
+    UnsupportedVersion(u16),
+    /// 途中で切れている・値が不正
+    Corrupt(&'static str),
+}
+
+impl fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "replay I/O error: {e}"),
+            Self::BadMagic => write!(f, "not a replay file"),
+            Self::UnsupportedVersion(v) => write!(f, "unsupported replay version {v} (expected {REPLAY_VERSION})"),
+            Self::Corrupt(reason) => write!(f, "corrupt replay: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for ReplayError {}
+
+impl From<io::Error> for ReplayError {
+    fn from(e: io::Error) -> Self {
+        if e.kind() == io::ErrorKind::UnexpectedEof {
+            Self::Corrupt("unexpected end of file")
+        } else {
+            Self::Io(e)
+        }
+    }
+}
+
+/// リプレイの書き出し。フレーム ID は単調増加でなければならない
+pub struct ReplayWriter<W: Write> {
+    out:        W,
+    last_frame: u32,
+}
+
+impl<W: Write> ReplayWriter<W> {
+    /// ヘッダを書き出す。最初のレコードのフレーム差は 0 から数える
+    pub fn new(mut out: W, header: ReplayHeader) -> io::Result<Self> {
+        out.write_all(&MAGIC)?;
+        out.write_all(&REPLAY_VERSION.to_le_bytes())?;
+        out.write_all(&header.seed.to_le_bytes())?;
+        Ok(Self { out, last_frame: 0 })
+    }
+
+    pub fn write(&mut self, frame_id: u32, event: &ReplayEvent) -> io::Result<()> {
+        match event {
+            ReplayEvent::Command(cmd) => self.write_command(frame_id, cmd),
+            ReplayEvent::Checkpoint(cp) => self.write_checkpoint(frame_id, cp),
+        }
+    }
+
+    pub fn write_command(&mut self, frame_id: u32, cmd: &ReplayCommand) -> io::Result<()> {
+        let mut buf = self.record_start(frame_id)?;
+        encode_command(&mut buf, cmd);
+        self.out.write_all(&buf)
+    }
+
+    pub fn write_checkpoint(&mut self, frame_id: u32, cp: &ReplayCheckpoint) -> io::Result<()> {
+        let mut buf = self.record_start(frame_id)?;
+        buf.push(TAG_CHECKPOINT);
+        for v in [cp.player_x, cp.player_y, cp.player_hp] {
+            buf.extend_from_slice(&v.to_le_bytes());
+        }
+        for v in [cp.score, cp.kill_count, cp.level, cp.exp, cp.alive_enemies, cp.bullets] {
+            buf.extend_from_slice(&v.to_le_bytes());
+        }
+        buf.extend_from_slice(&cp.checksum.to_le_bytes());
+        self.out.write_all(&buf)
+    }
+
+    /// フレーム差を書いたレコードのバッファを返す
+    fn record_start(&mut self, frame_id: u32) -> io::Result<Vec<u8>> {
+        let Some(delta) = frame_id.checked_sub(self.last_frame) else {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "replay frame_id went backwards"));
+        };
+        self.last_frame = frame_id;
+        let mut buf = Vec::with_capacity(16);
+        push_varint(&mut buf, delta);
+        Ok(buf)
+    }
+
+    /// バッファを書き出して出力先を返す
+    pub fn finish(mut self) -> io::Result<W> {
+        self.out.flush()?;
+        Ok(self.out)
+    }
+}
+
+fn encode_command(buf: &mut Vec<u8>, cmd: &ReplayCommand) {
+    let put_str = |buf: &mut Vec<u8>, s: &str| {
+        buf.extend_from_slice(&(s.len() as u32).to_le_bytes());
+        buf.extend_from_slice(s.as_bytes());
+    };
+    match cmd {
+        ReplayCommand::SetInput { dx, dy } => {
+            buf.push(TAG_SET_INPUT);
+            buf.extend_from_slice(&dx.to_le_bytes());
+            buf.extend_from_slice(&dy.to_le_bytes());
+        }
+        ReplayCommand::Step { delta_ms } => {
+            buf.push(TAG_STEP);
+            buf.extend_from_slice(&delta_ms.to_le_bytes());
+        }
+        ReplayCommand::SpawnEnemies { kind_id, count } => {
+            buf.extend_from_slice(&[TAG_SPAWN_ENEMIES, *kind_id]);
+            buf.extend_from_slice(&count.to_le_bytes());
+        }
+        ReplayCommand::SpawnElite { kind_id, count, hp_multiplier } => {
+            buf.extend_from_slice(&[TAG_SPAWN_ELITE, *kind_id]);
+            buf.extend_from_slice(&count.to_le_bytes());
+            buf.extend_from_slice(&hp_multiplier.to_le_bytes());
+        }
+        ReplayCommand::SpawnBoss { kind_id } => buf.extend_from_slice(&[TAG_SPAWN_BOSS, *kind_id]),
+        ReplayCommand::AddWeapon { weapon_id } => buf.extend_from_slice(&[TAG_ADD_WEAPON, *weapon_id]),
+        ReplayCommand::AddPassive { passive_id } => buf.extend_from_slice(&[TAG_ADD_PASSIVE, *passive_id]),
+        ReplayCommand::SkipLevelUp => buf.push(TAG_SKIP_LEVEL_UP),
+        ReplayCommand::SetWorldSize { width, height } => {
+            buf.push(TAG_SET_WORLD_SIZE);
+            buf.extend_from_slice(&width.to_le_bytes());
+            buf.extend_from_slice(&height.to_le_bytes());
+        }
+        ReplayCommand::SetMapObstacles { obstacles } => {
+            buf.push(TAG_SET_MAP_OBSTACLES);
+            buf.extend_from_slice(&(obstacles.len() as u32).to_le_bytes());
+            for &(x, y, r, kind) in obstacles {
+                for v in [x, y, r] {
+                    buf.extend_from_slice(&v.to_le_bytes());
+                }
+                buf.push(kind);
+            }
+        }
+        ReplayCommand::LoadMap { json } => {
+            buf.push(TAG_LOAD_MAP);
+            put_str(buf, json);
+        }
+        ReplayCommand::SetSpawnSchedule { json } => {
+            buf.push(TAG_SET_SPAWN_SCHEDULE);
+            put_str(buf, json);
+        }
+    }
+}
+
+/// リプレイの読み込み
+pub struct ReplayReader<R: Read> {
+    input:  R,
+    header: ReplayHeader,
+    frame:  u32,
+}
+
+impl<R: Read> ReplayReader<R> {
+    /// ヘッダを読んで検証する
+    pub fn new(mut input: R) -> Result<Self, ReplayError> {
+        let mut magic = [0u8; 4];
+        input.read_exact(&mut magic)?;
+        if magic != MAGIC {
+            return Err(ReplayError::BadMagic);
+        }
+        let version = u16::from_le_bytes(read_array(&mut input)?);
+        if version != REPLAY_VERSION {
+            return Err(ReplayError::UnsupportedVersion(version));
+        }
+        let seed = u64::from_le_bytes(read_array(&mut input)?);
+        Ok(Self { input, header: ReplayHeader { seed }, frame: 0 })
+    }
+
+    pub fn header(&self) -> ReplayHeader {
+        self.header
+    }
+
+    /// 次のレコードを (frame_id, event) で返す。ファイルの終わりなら None
+    pub fn next_event(&mut self) -> Result<Option<(u32, ReplayEvent)>, ReplayError> {
+        let Some(delta) = read_varint(&mut self.input)? else {
+            return Ok(None);
+        };
+        self.frame = self.frame.checked_add(delta).ok_or(ReplayError::Corrupt("frame_id overflow"))?;
+        let tag = read_array::<1>(&mut self.input)?[0];
+        let r = &mut self.input;
+        let event = match tag {
+            TAG_CHECKPOINT => {
+                let [player_x, player_y, player_hp] = [read_f32(r)?, read_f32(r)?, read_f32(r)?];
+                let [score, kill_count, level, exp, alive_enemies, bullets] =
+                    [read_u32(r)?, read_u32(r)?, read_u32(r)?, read_u32(r)?, read_u32(r)?, read_u32(r)?];
+                let checksum = u64::from_le_bytes(read_array(r)?);
+                ReplayEvent::Checkpoint(ReplayCheckpoint {
+                    player_x, player_y, player_hp, score, kill_count, level, exp, alive_enemies, bullets, checksum,
+                })
+            }
+            tag => ReplayEvent::Command(decode_command(tag, r)?),
+        };
+        Ok(Some((self.frame, event)))
+    }
+}
+
+impl<R: Read> Iterator for ReplayReader<R> {
+    type Item = Result<(u32, ReplayEvent), ReplayError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_event().transpose()
+    }
+}
+
+fn decode_command(tag: u8, r: &mut impl Read) -> Result<ReplayCommand, ReplayError> {
+    let read_u8 = |r: &mut dyn Read| read_array::<1>(r).map(|[b]| b);
+    Ok(match tag {
+        TAG_SET_INPUT => ReplayCommand::SetInput { dx: read_f32(r)?, dy: read_f32(r)? },
+        TAG_STEP => ReplayCommand::Step { delta_ms: read_f32(r)? },
+        TAG_SPAWN_ENEMIES => ReplayCommand::SpawnEnemies { kind_id: read_u8(r)?, count: read_u32(r)? },
+        TAG_SPAWN_ELITE => ReplayCommand::SpawnElite {
+            kind_id:       read_u8(r)?,
+            count:         read_u32(r)?,
+            hp_multiplier: f64::from_le_bytes(read_array(r)?),
+        },
+        TAG_SPAWN_BOSS => ReplayCommand::SpawnBoss { kind_id: read_u8(r)? },
+        TAG_ADD_WEAPON => ReplayCommand::AddWeapon { weapon_id: read_u8(r)? },
+        TAG_ADD_PASSIVE => ReplayCommand::AddPassive { passive_id: read_u8(r)? },
+        TAG_SKIP_LEVEL_UP => ReplayCommand::SkipLevelUp,
+        TAG_SET_WORLD_SIZE => ReplayCommand::SetWorldSize {
+            width:  f64::from_le_bytes(read_array(r)?),
+            height: f64::from_le_bytes(read_array(r)?),
+        },
+        TAG_SET_MAP_OBSTACLES => {
+            let n = read_len(r)?;
+            let mut obstacles = Vec::with_capacity(n.min(4096));
+            for _ in 0..n {
+                obstacles.push((read_f32(r)?, read_f32(r)?, read_f32(r)?, read_u8(r)?));
+            }
+            ReplayCommand::SetMapObstacles { obstacles }
+        }
+        TAG_LOAD_MAP => ReplayCommand::LoadMap { json: read_string(r)? },
+        TAG_SET_SPAWN_SCHEDULE => ReplayCommand::SetSpawnSchedule { json: read_string(r)? },
+        _ => return Err(ReplayError::Corrupt("unknown record tag")),
+    })
+}
+
+fn read_array<const N: usize>(r: &mut (impl Read + ?Sized)) -> Result<[u8; N], ReplayError> {
+    let mut buf = [0u8; N];
+    r.read_exact(&mut buf)?;
+    Ok(buf)
+}
+
+fn read_f32(r: &mut impl Read) -> Result<f32, ReplayError> {
+    Ok(f32::from_le_bytes(read_array(r)?))
+}
+
+fn read_u32(r: &mut impl Read) -> Result<u32, ReplayError> {
+    Ok(u32::from_le_bytes(read_array(r)?))
+}
+
+fn read_len(r: &mut impl Read) -> Result<usize, ReplayError> {
+    let n = read_u32(r)? as usize;
+    if n > MAX_PAYLOAD_LEN {
+        return Err(ReplayError::Corrupt("payload too long"));
+    }
+    Ok(n)
+}
+
+fn read_string(r: &mut impl Read) -> Result<String, ReplayError> {
+    let n = read_len(r)?;
+    let mut bytes = Vec::new();
+    r.take(n as u64).read_to_end(&mut bytes)?;
+    if bytes.len() != n {
+        return Err(ReplayError::Corrupt("unexpected end of file"));
+    }
+    String::from_utf8(bytes).map_err(|_| ReplayError::Corrupt("string is not UTF-8"))
+}
+
+/// LEB128 形式の符号なし可変長整数（フレーム差はほとんど 0 か 1 なので 1 バイトで済む）
+fn push_varint(buf: &mut Vec<u8>, mut v: u32) {
+    while v >= 0x80 {
+        buf.push((v as u8) | 0x80);
+        v >>= 7;
+    }
+    buf.push(v as u8);
+}
+
+/// 可変長整数を読む。レコードの先頭でファイルが終わっていれば None
+fn read_varint(r: &mut impl Read) -> Result<Option<u32>, ReplayError> {
+    let mut v = 0u32;
+    for i in 0..5 {
+        let mut byte = [0u8; 1];
+        if r.read(&mut byte)? == 0 {
+            return if i == 0 { Ok(None) } else { Err(ReplayError::Corrupt("unexpected end of file")) };
+        }
+        v |= ((byte[0] & 0x7F) as u32) << (7 * i);
+        if byte[0] & 0x80 == 0 {
+            return Ok(Some(v));
+        }
+    }
+    Err(ReplayError::Corrupt("varint too long"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Vec<(u32, ReplayEvent)> {
+        let cmd = ReplayEvent::Command;
+        vec![
+            (0, cmd(ReplayCommand::LoadMap { json: r#"{"tile_size": 64}"#.to_string() })),
+            (0, cmd(ReplayCommand::SetMapObstacles { obstacles: vec![(1.0, 2.0, 3.0, 1)] })),
+            (0, cmd(ReplayCommand::SetInput { dx: -1.0, dy: 0.5 })),
+            (0, cmd(ReplayCommand::Step { delta_ms: 1000.0 / 60.0 })),
+            (1, cmd(ReplayCommand::SpawnElite { kind_id: 2, count: 3, hp_multiplier: 0.0 })),
+            (1, cmd(ReplayCommand::Step { delta_ms: 16.0 })),
+            (300, cmd(ReplayCommand::SkipLevelUp)),
+            (300, ReplayEvent::Checkpoint(ReplayCheckpoint {
+                player_x: 10.5, player_y: -3.0, player_hp: 99.0, score: 40, kill_count: 4,
+                level: 2, exp: 7, alive_enemies: 12, bullets: 3, checksum: 0xDEAD_BEEF_0123_4567,
+            })),
+            (70_000, cmd(ReplayCommand::SpawnBoss { kind_id: 1 })),
+        ]
+    }
+
+    #[test]
+    fn events_round_trip() {
+        let mut writer = ReplayWriter::new(Vec::new(), ReplayHeader { seed: u64::MAX - 3 }).unwrap();
+        for (frame, event) in sample() {
+            writer.write(frame, &event).unwrap();
+        }
+        let bytes = writer.finish().unwrap();
+
+        let reader = ReplayReader::new(bytes.as_slice()).unwrap();
+        assert_eq!(reader.header().seed, u64::MAX - 3);
+        let events: Vec<_> = reader.collect::<Result<_, _>>().unwrap();
+        assert_eq!(events, sample());
+    }
+
+    #[test]
+    fn steps_are_compact() {
+        let mut writer = ReplayWriter::new(Vec::new(), ReplayHeader { seed: 1 }).unwrap();
+        for frame in 0..600 {
+            writer.write(frame, &ReplayEvent::Command(ReplayCommand::Step { delta_ms: 16.6 })).unwrap();
+        }
+        let header_len = 4 + 2 + 8;
+        assert_eq!(writer.finish().unwrap().len(), header_len + 600 * 6);
+    }
+
+    #[test]
+    fn rejects_bad_input() {
+        assert!(matches!(ReplayReader::new(&b"NOPE\x01\x00"[..]), Err(ReplayError::BadMagic)));
+        let mut future = b"EXRP".to_vec();
+        future.extend_from_slice(&99u16.to_le_bytes());
+        future.extend_from_slice(&0u64.to_le_bytes());
+        assert!(matches!(ReplayReader::new(future.as_slice()), Err(ReplayError::UnsupportedVersion(99))));
+
+        let mut writer = ReplayWriter::new(Vec::new(), ReplayHeader { seed: 1 }).unwrap();
+        writer.write(5, &ReplayEvent::Command(ReplayCommand::AddWeapon { weapon_id: 3 })).unwrap();
+        assert!(writer.write(4, &ReplayEvent::Command(ReplayCommand::SkipLevelUp)).is_err());
+        let mut bytes = writer.finish().unwrap();
+        bytes.pop();
+        let mut reader = ReplayReader::new(bytes.as_slice()).unwrap();
+        assert!(matches!(reader.next_event(), Err(ReplayError::Corrupt(_))));
+    }
+}
@@ -0,0 +1,334 @@
+//! Path: native/game_core/src/spawn_director.rs
+//! Summary: ウェーブスケジュール（SpawnSchedule）に従って敵の出現を決めるスポーンディレクター
+//!
+//! スケジュールはゲームごとのコンテンツファイル（JSON）で定義し、set_spawn_schedule NIF で渡す。
+//! ディレクターは「どの敵を何体（うちエリートは何体）どの隊形で出すか」だけを決め、
+//! スポーン位置の決定（SpawnPlacer）と EnemyWorld への追加は呼び出し側（physics_step）で行う。
+
+use crate::physics::rng::SimpleRng;
+use crate::physics::spawn_placement::SpawnFormation;
+use serde::Deserialize;
+use std::fmt;
+
+/// ウェーブ内の敵の出現比率
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+pub struct SpawnWeight {
+    /// 敵 ID（entity_params の enemies のインデックス）
+    pub enemy:  u8,
+    pub weight: u32,
+}
+
+/// 1 つの時間帯のスポーン設定。[start, end) の間、interval 秒ごとに count 体を出す
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct SpawnWave {
+    /// 開始時刻（ゲーム開始からの秒）
+    pub start:        f32,
+    /// 終了時刻（秒）。省略時は終わらない。複数のウェーブが同時に有効でもよい
+    #[serde(default)]
+    pub end:          Option<f32>,
+    /// スポーン間隔（秒）。ウェーブ開始直後に 1 回目を出す
+    pub interval:     f32,
+    /// 1 回あたりの体数
+    pub count:        u32,
+    /// 敵の出現比率（1 体ごとに重み付き抽選）
+    pub mix:          Vec<SpawnWeight>,
+    /// 1 体ごとにエリートになる確率（0.0..=1.0）
+    #[serde(default)]
+    pub elite_chance: f32,
+    /// このウェーブが出す上限（生存中の敵がこの数以上ならスポーンしない）。省略時はスケジュール全体の上限のみ
+    #[serde(default)]
+    pub max_enemies:  Option<usize>,
+    /// 1 回分の count 体の並べ方（省略時は 1 体ずつランダム）
+    #[serde(default)]
+    pub formation:    SpawnFormation,
+    /// HUD・ログに出すウェーブ名（省略可）
+    #[serde(default)]
+    pub label:        Option<String>,
+}
+
+/// ゲーム 1 回分のスポーンスケジュール
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct SpawnSchedule {
+    pub waves:       Vec<SpawnWave>,
+    /// 生存中の敵の上限（全ウェーブ共通）
+    #[serde(default = "default_max_enemies")]
+    pub max_enemies: usize,
+}
+
+fn default_max_enemies() -> usize { 10_000 }
+
+/// スケジュールの読み込み・検証エラー
+#[derive(Debug)]
+pub enum SpawnScheduleError {
+    /// JSON として解釈できない
+    Parse(String),
+    /// 値が不正（index: waves のインデックス）
+    Invalid { index: usize, reason: &'static str },
+}
+
+impl fmt::Display for SpawnScheduleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse(e) => write!(f, "spawn schedule parse error: {e}"),
+            Self::Invalid { index, reason } => write!(f, "invalid spawn schedule: waves[{index}]: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for SpawnScheduleError {}
+
+impl SpawnSchedule {
+    /// JSON 文字列からスケジュールを読み込み、検証する。enemy_count は登録済みの敵の数
+    pub fn from_json(src: &str, enemy_count: usize) -> Result<Self, SpawnScheduleError> {
+        let schedule: Self = serde_json::from_str(src)
+            .map_err(|e| SpawnScheduleError::Parse(e.to_string()))?;
+        schedule.validate(enemy_count)?;
+        Ok(schedule)
+    }
+
+    /// 値の妥当性を検証する
+    pub fn validate(&self, enemy_count: usize) -> Result<(), SpawnScheduleError> {
+        for (index, wave) in self.waves.iter().enumerate() {
+            let invalid = |reason| SpawnScheduleError::Invalid { index, reason };
+            if !(wave.start.is_finite() && wave.start >= 0.0) {
+                return Err(invalid("start must be >= 0"));
+            }
+            if wave.end.is_some_and(|end| end.is_nan() || end <= wave.start) {
+                return Err(invalid("end must be > start"));
+            }
+            if !(wave.interval.is_finite() && wave.interval > 0.0) {
+                return Err(invalid("interval must be > 0"));
+            }
+            if wave.mix.is_empty() || wave.mix.iter().all(|m| m.weight == 0) {
+                return Err(invalid("mix must have a positive weight"));
+            }
+            if wave.mix.iter().any(|m| m.enemy as usize >= enemy_count) {
+                return Err(invalid("mix enemy must be an enemy ID"));
+            }
+            if !(0.0..=1.0).contains(&wave.elite_chance) {
+                return Err(invalid("elite_chance must be within 0.0..=1.0"));
+            }
+            wave.formation.validate().map_err(invalid)?;
+        }
+        Ok(())
+    }
+}
+
+impl SpawnWave {
+    fn is_active(&self, elapsed: f32) -> bool {
+        elapsed >= self.start && self.end.is_none_or(|end| elapsed < end)
+    }
+
+    /// mix から 1 体分の敵 ID を重み付きで選ぶ
+    fn pick_enemy(&self, rng: &mut SimpleRng) -> u8 {
+        let total: u32 = self.mix.iter().map(|m| m.weight).sum();
+        let mut roll = rng.next_u32() % total.max(1);
+        for m in &self.mix {
+            if roll < m.weight {
+                return m.enemy;
+            }
+            roll -= m.weight;
+        }
+        self.mix[0].enemy
+    }
+}
+
+/// ディレクターが決めた 1 体分のスポーン
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SpawnOrder {
+    pub enemy: u8,
+    pub elite: bool,
+}
+
+/// 1 つのウェーブが 1 回に出す敵（formation に並べる）
+#[derive(Clone, Debug, PartialEq)]
+pub struct SpawnBatch {
+    pub formation: SpawnFormation,
+    pub orders:    Vec<SpawnOrder>,
+}
+
+/// スケジュールとウェーブごとのタイマーを持ち、毎フレーム出現させる敵を決める
+#[derive(Clone, Debug)]
+pub struct SpawnDirector {
+    schedule: SpawnSchedule,
+    /// waves ごとの次のスポーンまでの残り時間（秒）
+    timers:   Vec<f32>,
+}
+
+impl SpawnDirector {
+    pub fn new(schedule: SpawnSchedule) -> Self {
+        let timers = vec![0.0; schedule.waves.len()];
+        Self { schedule, timers }
+    }
+
+    pub fn schedule(&self) -> &SpawnSchedule {
+        &self.schedule
+    }
+
+    /// waves ごとの次のスポーンまでの残り時間（チェックサム用）
+    pub fn timers(&self) -> &[f32] {
+        &self.timers
+    }
+
+    /// elapsed 秒から再開できるようにタイマーを合わせる（セーブデータのロード用）。
+    /// 有効なウェーブは開始時刻から interval ごとのスポーン周期に揃え、それ以外は 0 に戻す
+    pub fn resume_at(&mut self, elapsed: f32) {
+        for (wave, timer) in self.schedule.waves.iter().zip(self.timers.iter_mut()) {
+            *timer = if wave.is_active(elapsed) {
+                let phase = (elapsed - wave.start).rem_euclid(wave.interval);
+                if phase > 0.0 { wave.interval - phase } else { 0.0 }
+            } else {
+                0.0
+            };
+        }
+    }
+
+    /// elapsed 秒時点で有効なウェーブのうち、最後に定義されたラベル付きのものの名前
+    pub fn wave_label(&self, elapsed: f32) -> Option<&str> {
+        self.schedule.waves.iter()
+            .filter(|wave| wave.is_active(elapsed))
+            .filter_map(|wave| wave.label.as_deref())
+            .next_back()
+    }
+
+    /// elapsed 秒時点で有効なウェーブのタイマーを dt 進め、出現させる敵をウェーブの 1 回分ずつ out に積む。
+    /// alive は現在生存中の敵の数（上限の判定に使い、このフレームに積んだ分も数える）
+    pub fn tick(&mut self, elapsed: f32, dt: f32, alive: usize, rng: &mut SimpleRng, out: &mut Vec<SpawnBatch>) {
+        let mut alive = alive;
+        for (wave, timer) in self.schedule.waves.iter().zip(self.timers.iter_mut()) {
+            if !wave.is_active(elapsed) {
+                // 未開始・終了済みのウェーブは、次に有効になった直後に出すようにしておく
+                *timer = 0.0;
+                continue;
+            }
+            *timer -= dt;
+            if *timer > 0.0 {
+                continue;
+            }
+            *timer += wave.interval;
+            // 長時間止まっていた場合でも 1 フレームに出すのは 1 回分まで
+            *timer = timer.max(0.0);
+
+            let cap = wave.max_enemies.map_or(self.schedule.max_enemies, |m| m.min(self.schedule.max_enemies));
+            let n = (wave.count as usize).min(cap.saturating_sub(alive));
+            if n == 0 {
+                continue;
+            }
+            let orders = (0..n)
+                .map(|_| {
+                    let enemy = wave.pick_enemy(rng);
+                    // 確率は 1/10000 単位で判定する
+                    let elite = wave.elite_chance > 0.0
+                        && rng.next_u32() % 10_000 < (wave.elite_chance * 10_000.0) as u32;
+                    SpawnOrder { enemy, elite }
+                })
+                .collect();
+            out.push(SpawnBatch { formation: wave.formation, orders });
+            alive += n;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCHEDULE: &str = r#"{
+        "max_enemies": 30,
+        "waves": [
+            { "start": 0.0, "end": 10.0, "interval": 2.0, "count": 5, "mix": [{ "enemy": 0, "weight": 1 }] },
+            { "start": 10.0, "interval": 1.0, "count": 20, "elite_chance": 0.5,
+              "formation": { "type": "arc", "spread": 1.5 },
+              "mix": [{ "enemy": 1, "weight": 3 }, { "enemy": 2, "weight": 1 }] }
+        ]
+    }"#;
+
+    fn run(director: &mut SpawnDirector, from: f32, to: f32, alive: usize, rng: &mut SimpleRng) -> Vec<SpawnOrder> {
+        let mut batches = Vec::new();
+        let dt = 0.5;
+        let mut t = from;
+        while t < to {
+            t += dt;
+            director.tick(t, dt, alive, rng, &mut batches);
+        }
+        batches.into_iter().flat_map(|b| b.orders).collect()
+    }
+
+    #[test]
+    fn waves_follow_time_windows_and_intervals() {
+        let mut director = SpawnDirector::new(SpawnSchedule::from_json(SCHEDULE, 3).unwrap());
+        let mut rng = SimpleRng::new(1);
+        // 0.5〜9.5 秒: 2 秒ごとに 5 体（0.5, 2, 4, 6, 8 秒の 5 回）
+        let early = run(&mut director, 0.0, 9.5, 0, &mut rng);
+        assert_eq!(early.len(), 25);
+        assert!(early.iter().all(|o| *o == SpawnOrder { enemy: 0, elite: false }));
+
+        // 10 秒以降は 2 つ目のウェーブのみ。上限 30 を超えない
+        let late = run(&mut director, 9.5, 10.0, 20, &mut rng);
+        assert_eq!(late.len(), 10);
+        assert!(late.iter().all(|o| o.enemy == 1 || o.enemy == 2));
+    }
+
+    #[test]
+    fn resumed_director_continues_the_spawn_cycle() {
+        let schedule = SpawnSchedule::from_json(SCHEDULE, 3).unwrap();
+        let mut played = SpawnDirector::new(schedule.clone());
+        run(&mut played, 0.0, 5.0, 0, &mut SimpleRng::new(1));
+
+        // 途中から再開しても、最初から進めた場合と同じタイマーになる
+        let mut resumed = SpawnDirector::new(schedule);
+        resumed.resume_at(5.0);
+        assert_eq!(resumed.timers(), played.timers());
+        assert_eq!(run(&mut resumed, 5.0, 9.5, 0, &mut SimpleRng::new(1)).len(), 10);
+    }
+
+    #[test]
+    fn same_seed_gives_same_spawns() {
+        let schedule = SpawnSchedule::from_json(SCHEDULE, 3).unwrap();
+        let a = run(&mut SpawnDirector::new(schedule.clone()), 9.5, 15.0, 0, &mut SimpleRng::new(9));
+        let b = run(&mut SpawnDirector::new(schedule), 9.5, 15.0, 0, &mut SimpleRng::new(9));
+        assert_eq!(a, b);
+        assert!(a.iter().any(|o| o.elite) && a.iter().any(|o| !o.elite));
+    }
+
+    #[test]
+    fn shipped_vampire_survivor_schedule_is_valid() {
+        let src = include_str!("../../../assets/vampire_survivor/spawn_schedule.json");
+        let schedule = SpawnSchedule::from_json(src, 5).unwrap();
+        assert_eq!(schedule.waves.len(), 6);
+        assert_eq!(schedule.max_enemies, 10_000);
+        assert!(schedule.waves.iter().all(|wave| wave.label.is_some()));
+    }
+
+    #[test]
+    fn wave_label_follows_active_waves() {
+        let src = r#"{ "waves": [
+            { "start": 0.0, "interval": 1.0, "count": 1, "mix": [{ "enemy": 0, "weight": 1 }], "label": "Base" },
+            { "start": 10.0, "end": 20.0, "interval": 1.0, "count": 1, "mix": [{ "enemy": 0, "weight": 1 }], "label": "Rush" },
+            { "start": 15.0, "interval": 1.0, "count": 1, "mix": [{ "enemy": 0, "weight": 1 }] }
+        ] }"#;
+        let director = SpawnDirector::new(SpawnSchedule::from_json(src, 1).unwrap());
+        assert_eq!(director.wave_label(5.0), Some("Base"));
+        // 重なっているときは後に定義されたウェーブ。ラベルのないウェーブは飛ばす
+        assert_eq!(director.wave_label(16.0), Some("Rush"));
+        assert_eq!(director.wave_label(20.0), Some("Base"));
+    }
+
+    #[test]
+    fn batches_carry_wave_formation() {
+        let mut director = SpawnDirector::new(SpawnSchedule::from_json(SCHEDULE, 3).unwrap());
+        let mut batches = Vec::new();
+        director.tick(10.0, 0.5, 0, &mut SimpleRng::new(1), &mut batches);
+        assert_eq!(batches.len(), 1);
+        assert_eq!(batches[0].formation, SpawnFormation::Arc { spread: 1.5 });
+        assert_eq!(batches[0].orders.len(), 20);
+    }
+
+    #[test]
+    fn rejects_unknown_enemy_ids() {
+        assert!(matches!(
+            SpawnSchedule::from_json(SCHEDULE, 2),
+            Err(SpawnScheduleError::Invalid { index: 1, .. })
+        ));
+    }
+}
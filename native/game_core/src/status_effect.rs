@@ -0,0 +1,277 @@
+//! Path: native/game_core/src/status_effect.rs
+//! Summary: 敵の状態異常（燃焼・毒・凍結・鈍足・弱体）の種別・重ね掛け規則・ティック処理
+//!
+//! 敵 1 体ぶんの状態を StatusEffects に固定長で持つ（種別ごとに 1 スロット）。
+//! 付与量は武器ごとに entity_params の `on_hit` で指定する。
+
+use serde::Deserialize;
+
+/// 継続ダメージ（Burn / Poison）のティック間隔（秒）
+pub const STATUS_TICK_INTERVAL: f32 = 0.5;
+
+/// 状態異常の種別数（StatusEffects のスロット数）
+pub const STATUS_KIND_COUNT: usize = 5;
+
+/// 状態異常の種別。potency の意味は種別ごとに異なる
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StatusKind {
+    /// 燃焼: 1 秒あたり potency ダメージ。重ね掛け不可（時間のみ更新）
+    Burn   = 0,
+    /// 毒: 1 スタックにつき 1 秒あたり potency ダメージ。最大 5 スタック
+    Poison = 1,
+    /// 凍結: 移動停止（potency は未使用）
+    Freeze = 2,
+    /// 鈍足: 移動速度を potency の割合だけ低下（0.0..1.0）
+    Slow   = 3,
+    /// 弱体: 接触ダメージを potency の割合だけ低下（0.0..1.0）
+    Weaken = 4,
+}
+
+impl StatusKind {
+    pub const ALL: [StatusKind; STATUS_KIND_COUNT] =
+        [Self::Burn, Self::Poison, Self::Freeze, Self::Slow, Self::Weaken];
+
+    pub fn as_u8(self) -> u8 {
+        self as u8
+    }
+
+    pub fn from_u8(v: u8) -> Option<Self> {
+        Self::ALL.get(v as usize).copied()
+    }
+
+    /// 重ね掛けできる最大スタック数
+    pub fn max_stacks(self) -> u8 {
+        match self {
+            Self::Poison => 5,
+            _ => 1,
+        }
+    }
+
+    /// 継続ダメージを与える種別か
+    pub fn deals_damage(self) -> bool {
+        matches!(self, Self::Burn | Self::Poison)
+    }
+
+    /// 描画時の乗算カラー [r, g, b, a]
+    pub fn tint(self) -> [f32; 4] {
+        match self {
+            Self::Burn   => [1.0, 0.55, 0.3, 1.0],
+            Self::Poison => [0.55, 1.0, 0.45, 1.0],
+            Self::Freeze => [0.55, 0.85, 1.0, 1.0],
+            Self::Slow   => [0.7, 0.75, 1.0, 1.0],
+            Self::Weaken => [0.8, 0.6, 0.9, 1.0],
+        }
+    }
+
+    /// レベルアップカード用の説明行
+    pub fn describe(self, apply: &StatusApply) -> String {
+        match self {
+            Self::Burn   => format!("Burn: {}/s for {:.1}s", apply.potency, apply.duration),
+            Self::Poison => format!("Poison: {}/s per stack for {:.1}s", apply.potency, apply.duration),
+            Self::Freeze => format!("Freeze for {:.1}s", apply.duration),
+            Self::Slow   => format!("Slow {}% for {:.1}s", (apply.potency * 100.0) as u32, apply.duration),
+            Self::Weaken => format!("Weaken {}% for {:.1}s", (apply.potency * 100.0) as u32, apply.duration),
+        }
+    }
+}
+
+/// ヒット時に付与する状態異常（entity_params の weapons[].on_hit）
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+pub struct StatusApply {
+    pub kind:     StatusKind,
+    /// 効果時間（秒）
+    pub duration: f32,
+    #[serde(default)]
+    pub potency:  f32,
+}
+
+/// 1 種別ぶんの状態（remaining <= 0 なら非アクティブ）
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct StatusSlot {
+    pub remaining:     f32,
+    pub stacks:        u8,
+    pub potency:       f32,
+    /// 付与した武器 ID（継続ダメージで倒したときの EnemyKilled 用）
+    pub source_weapon: u8,
+}
+
+impl StatusSlot {
+    pub fn is_active(&self) -> bool {
+        self.remaining > 0.0 && self.stacks > 0
+    }
+}
+
+/// 敵 1 体の状態異常一式（インデックス = StatusKind as usize）
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct StatusEffects {
+    pub slots:      [StatusSlot; STATUS_KIND_COUNT],
+    /// 継続ダメージのティック用タイマー
+    pub tick_timer: f32,
+}
+
+impl StatusEffects {
+    /// 状態異常を付与する。
+    ///
+    /// 重ね掛け規則: 効果時間は長い方に更新、potency は強い方を採用、
+    /// スタックは種別ごとの上限まで加算する。
+    pub fn apply(&mut self, apply: &StatusApply, source_weapon: u8) {
+        let slot = &mut self.slots[apply.kind as usize];
+        if slot.is_active() {
+            slot.remaining     = slot.remaining.max(apply.duration);
+            slot.potency       = slot.potency.max(apply.potency);
+            slot.stacks        = (slot.stacks + 1).min(apply.kind.max_stacks());
+            slot.source_weapon = source_weapon;
+        } else {
+            *slot = StatusSlot {
+                remaining: apply.duration,
+                stacks:    1,
+                potency:   apply.potency,
+                source_weapon,
+            };
+        }
+    }
+
+    pub fn get(&self, kind: StatusKind) -> &StatusSlot {
+        &self.slots[kind as usize]
+    }
+
+    pub fn has(&self, kind: StatusKind) -> bool {
+        self.get(kind).is_active()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        !self.slots.iter().any(StatusSlot::is_active)
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    /// 移動速度の倍率（凍結 > 鈍足の順に適用）
+    pub fn speed_multiplier(&self) -> f32 {
+        if self.has(StatusKind::Freeze) {
+            return 0.0;
+        }
+        let slow = self.get(StatusKind::Slow);
+        if slow.is_active() {
+            (1.0 - slow.potency).clamp(0.0, 1.0)
+        } else {
+            1.0
+        }
+    }
+
+    /// 敵が与えるダメージの倍率（弱体）
+    pub fn damage_multiplier(&self) -> f32 {
+        let weaken = self.get(StatusKind::Weaken);
+        if weaken.is_active() {
+            (1.0 - weaken.potency).clamp(0.0, 1.0)
+        } else {
+            1.0
+        }
+    }
+
+    /// 描画用の乗算カラー。複数かかっている場合は凍結 > 燃焼 > 毒 > 鈍足 > 弱体を優先
+    pub fn tint(&self) -> Option<[f32; 4]> {
+        [StatusKind::Freeze, StatusKind::Burn, StatusKind::Poison, StatusKind::Slow, StatusKind::Weaken]
+            .into_iter()
+            .find(|&k| self.has(k))
+            .map(StatusKind::tint)
+    }
+
+    /// dt 秒進める。ティックが発生したら (ダメージ, 付与武器 ID) を返す。
+    ///
+    /// ティックは付与から STATUS_TICK_INTERVAL ごとに発生し、効果時間が切れた
+    /// スロットはリセットされる。
+    pub fn tick(&mut self, dt: f32) -> Option<(f32, u8)> {
+        let mut damage = 0.0;
+        let mut source = None;
+        if StatusKind::ALL.iter().any(|&k| k.deals_damage() && self.has(k)) {
+            self.tick_timer += dt;
+            while self.tick_timer >= STATUS_TICK_INTERVAL {
+                self.tick_timer -= STATUS_TICK_INTERVAL;
+                for kind in [StatusKind::Burn, StatusKind::Poison] {
+                    let slot = self.get(kind);
+                    if slot.is_active() {
+                        damage += slot.potency * slot.stacks as f32 * STATUS_TICK_INTERVAL;
+                        source.get_or_insert(slot.source_weapon);
+                    }
+                }
+            }
+        } else {
+            self.tick_timer = 0.0;
+        }
+
+        for slot in &mut self.slots {
+            if slot.is_active() {
+                slot.remaining -= dt;
+                if slot.remaining <= 0.0 {
+                    *slot = StatusSlot::default();
+                }
+            }
+        }
+
+        source.filter(|_| damage > 0.0).map(|s| (damage, s))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply(kind: StatusKind, duration: f32, potency: f32) -> StatusApply {
+        StatusApply { kind, duration, potency }
+    }
+
+    #[test]
+    fn burn_refreshes_duration_without_stacking() {
+        let mut s = StatusEffects::default();
+        s.apply(&apply(StatusKind::Burn, 3.0, 6.0), 4);
+        s.tick(1.0);
+        s.apply(&apply(StatusKind::Burn, 3.0, 6.0), 4);
+        let burn = s.get(StatusKind::Burn);
+        assert_eq!(burn.stacks, 1);
+        assert!((burn.remaining - 3.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn poison_stacks_up_to_limit_and_scales_damage() {
+        let mut s = StatusEffects::default();
+        for _ in 0..7 {
+            s.apply(&apply(StatusKind::Poison, 4.0, 2.0), 1);
+        }
+        assert_eq!(s.get(StatusKind::Poison).stacks, 5);
+        // 5 スタック × 2/s × 0.5s = 5
+        let (dmg, src) = s.tick(STATUS_TICK_INTERVAL).unwrap();
+        assert!((dmg - 5.0).abs() < 0.001);
+        assert_eq!(src, 1);
+    }
+
+    #[test]
+    fn tick_damage_fires_on_interval_and_expires() {
+        let mut s = StatusEffects::default();
+        s.apply(&apply(StatusKind::Burn, 1.0, 4.0), 4);
+        assert!(s.tick(0.25).is_none());
+        assert!(s.tick(0.25).is_some());
+        assert!(s.tick(0.25).is_none());
+        assert!(s.tick(0.25).is_some());
+        assert!(s.is_empty());
+        assert!(s.tick(1.0).is_none());
+    }
+
+    #[test]
+    fn freeze_overrides_slow_and_weaken_reduces_damage() {
+        let mut s = StatusEffects::default();
+        s.apply(&apply(StatusKind::Slow, 2.0, 0.4), 7);
+        assert!((s.speed_multiplier() - 0.6).abs() < 0.001);
+        s.apply(&apply(StatusKind::Freeze, 1.0, 0.0), 7);
+        assert_eq!(s.speed_multiplier(), 0.0);
+        assert_eq!(s.tint(), Some(StatusKind::Freeze.tint()));
+        s.tick(1.0);
+        assert!((s.speed_multiplier() - 0.6).abs() < 0.001);
+
+        assert_eq!(s.damage_multiplier(), 1.0);
+        s.apply(&apply(StatusKind::Weaken, 1.0, 0.3), 6);
+        assert!((s.damage_multiplier() - 0.7).abs() < 0.001);
+    }
+}
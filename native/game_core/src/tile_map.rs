@@ -0,0 +1,272 @@
+//! Path: native/game_core/src/tile_map.rs
+//! Summary: タイルマップ（地面タイル層・障害物・スポーン領域・プレイヤー開始位置）の定義と JSON 読み込み
+//!
+//! マップファイルは load_map NIF で読み込む。ワールドの広さは width × height タイル、
+//! 障害物は CollisionWorld に、地面タイルは RenderFrame の tile_data に渡す。
+//! 座標はすべてワールド座標（px）で、障害物・開始位置は中心基準、スポーン領域は左上基準。
+
+use crate::physics::bounds::WorldBounds;
+use crate::physics::collider::ObstacleShape;
+use crate::physics::spatial_hash::StaticObstacle;
+use serde::Deserialize;
+use std::fmt;
+
+/// マップの横・縦のタイル数の上限（地面タイル層は最大 MAX_MAP_SIDE² 要素になる）
+pub const MAX_MAP_SIDE: u32 = 4096;
+
+/// 地面タイル 1 種類の見た目
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+pub struct TileDef {
+    /// 地面タイルスプライトに乗算する RGBA
+    pub color: [f32; 4],
+}
+
+/// 地面タイル層（layers の順に下から描く）
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct TileLayer {
+    #[serde(default)]
+    pub name: String,
+    /// 行優先（左上から右へ）で width × height 個のタイル ID。0 は空、n は tiles[n - 1]。
+    /// 省略時は全面が fill
+    #[serde(default)]
+    pub data: Vec<u16>,
+    #[serde(default)]
+    pub fill: u16,
+}
+
+/// 静的障害物（kind: 0 = 木, 1 = 岩）。形状は radius（円）・width と height（矩形）・
+/// points（凸多角形、(x, y) からの相対座標）のいずれかで指定する
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct MapObstacle {
+    pub x:     f32,
+    pub y:     f32,
+    #[serde(flatten)]
+    pub shape: ObstacleShape,
+    #[serde(default)]
+    pub kind:  u8,
+}
+
+/// 敵のスポーンを許可する矩形領域。1 つもなければワールド全体で出せる
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+pub struct SpawnZone {
+    pub x:      f32,
+    pub y:      f32,
+    pub width:  f32,
+    pub height: f32,
+}
+
+impl SpawnZone {
+    pub fn contains(&self, x: f32, y: f32) -> bool {
+        x >= self.x && x <= self.x + self.width && y >= self.y && y <= self.y + self.height
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+pub struct MapPoint {
+    pub x: f32,
+    pub y: f32,
+}
+
+/// 1 枚のマップ
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct TileMap {
+    /// タイル 1 枚の一辺（px）
+    pub tile_size:    f32,
+    /// 横・縦のタイル数
+    pub width:        u32,
+    pub height:       u32,
+    #[serde(default)]
+    pub tiles:        Vec<TileDef>,
+    #[serde(default)]
+    pub layers:       Vec<TileLayer>,
+    #[serde(default)]
+    pub obstacles:    Vec<MapObstacle>,
+    #[serde(default)]
+    pub spawn_zones:  Vec<SpawnZone>,
+    /// プレイヤーの開始位置（中心座標）。省略時はワールドの中央
+    #[serde(default)]
+    pub player_start: Option<MapPoint>,
+}
+
+/// マップの読み込み・検証エラー
+#[derive(Debug)]
+pub enum TileMapError {
+    /// JSON として解釈できない
+    Parse(String),
+    /// 値が不正
+    Invalid(&'static str),
+}
+
+impl fmt::Display for TileMapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse(e) => write!(f, "tile map parse error: {e}"),
+            Self::Invalid(reason) => write!(f, "invalid tile map: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for TileMapError {}
+
+impl TileMap {
+    /// JSON 文字列からマップを読み込み、検証する
+    pub fn from_json(src: &str) -> Result<Self, TileMapError> {
+        let map: Self = serde_json::from_str(src).map_err(|e| TileMapError::Parse(e.to_string()))?;
+        map.validate().map_err(TileMapError::Invalid)?;
+        Ok(map)
+    }
+
+    /// 値の妥当性を検証する
+    pub fn validate(&self) -> Result<(), &'static str> {
+        if !(self.tile_size.is_finite() && self.tile_size > 0.0) {
+            return Err("tile_size must be > 0");
+        }
+        if self.width == 0 || self.height == 0 {
+            return Err("width and height must be > 0");
+        }
+        if self.width > MAX_MAP_SIDE || self.height > MAX_MAP_SIDE {
+            return Err("width and height must be <= MAX_MAP_SIDE");
+        }
+        let cells = self.width as usize * self.height as usize;
+        let tile_count = self.tiles.len();
+        for layer in &self.layers {
+            if !layer.data.is_empty() && layer.data.len() != cells {
+                return Err("layer data must have width * height tiles");
+            }
+            if layer.fill as usize > tile_count || layer.data.iter().any(|&t| t as usize > tile_count) {
+                return Err("layer tile ID must refer to tiles");
+            }
+        }
+        for o in &self.obstacles {
+            if !(o.x.is_finite() && o.y.is_finite()) {
+                return Err("obstacle position must be finite");
+            }
+            o.shape.validate()?;
+        }
+        if self.spawn_zones.iter().any(|z| !(z.x.is_finite() && z.y.is_finite() && z.width > 0.0 && z.height > 0.0)) {
+            return Err("spawn zone size must be > 0");
+        }
+        if self.player_start.is_some_and(|p| !self.bounds().contains(p.x, p.y, 0.0)) {
+            return Err("player_start must be inside the map");
+        }
+        Ok(())
+    }
+
+    /// マップの広さ（width × height タイル）
+    pub fn bounds(&self) -> WorldBounds {
+        WorldBounds::new(self.width as f32 * self.tile_size, self.height as f32 * self.tile_size)
+    }
+
+    /// プレイヤーの開始位置（中心座標）
+    pub fn player_start(&self) -> (f32, f32) {
+        self.player_start.map_or_else(|| self.bounds().center(), |p| (p.x, p.y))
+    }
+
+    /// CollisionWorld::set_static に渡す障害物のリスト
+    pub fn static_obstacles(&self) -> Vec<StaticObstacle> {
+        self.obstacles
+            .iter()
+            .map(|o| StaticObstacle { x: o.x, y: o.y, shape: o.shape.clone(), kind: o.kind })
+            .collect()
+    }
+
+    /// 矩形 (x, y, w, h) に掛かる地面タイルを (左上 x, 左上 y, 一辺, 色) で層の順に out に積む
+    pub fn visible_tiles(&self, x: f32, y: f32, w: f32, h: f32, out: &mut Vec<(f32, f32, f32, [f32; 4])>) {
+        let ts = self.tile_size;
+        let col_range = |from: f32, to: f32, n: u32| {
+            let first = (from / ts).floor().max(0.0) as u32;
+            let last = ((to / ts).ceil().max(0.0) as u32).min(n);
+            first..last
+        };
+        let cols = col_range(x, x + w, self.width);
+        let rows = col_range(y, y + h, self.height);
+        for layer in &self.layers {
+            for row in rows.clone() {
+                for col in cols.clone() {
+                    let id = match layer.data.get(row as usize * self.width as usize + col as usize) {
+                        Some(&id) => id,
+                        None => layer.fill,
+                    };
+                    if let Some(tile) = id.checked_sub(1).and_then(|i| self.tiles.get(i as usize)) {
+                        out.push((col as f32 * ts, row as f32 * ts, ts, tile.color));
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAP: &str = r#"{
+        "tile_size": 64, "width": 4, "height": 3,
+        "tiles": [{ "color": [0.2, 0.5, 0.2, 1.0] }, { "color": [0.5, 0.4, 0.3, 1.0] }],
+        "layers": [
+            { "name": "ground", "fill": 1 },
+            { "name": "paths", "data": [0, 0, 0, 0,  2, 2, 2, 2,  0, 0, 0, 0] }
+        ],
+        "obstacles": [
+            { "x": 100, "y": 100, "radius": 30, "kind": 1 },
+            { "x": 128, "y": 20, "width": 200, "height": 16 },
+            { "x": 40, "y": 160, "points": [[-20, -20], [20, -20], [0, 20]] }
+        ],
+        "spawn_zones": [{ "x": 0, "y": 0, "width": 256, "height": 64 }],
+        "player_start": { "x": 200, "y": 150 }
+    }"#;
+
+    #[test]
+    fn loads_map_properties() {
+        let map = TileMap::from_json(MAP).unwrap();
+        assert_eq!(map.bounds(), WorldBounds::new(256.0, 192.0));
+        assert_eq!(map.player_start(), (200.0, 150.0));
+        let obstacles = map.static_obstacles();
+        assert_eq!(obstacles[0], StaticObstacle::circle(100.0, 100.0, 30.0, 1));
+        assert_eq!(obstacles[1].shape, ObstacleShape::Rect { width: 200.0, height: 16.0 });
+        assert!(matches!(&obstacles[2].shape, ObstacleShape::Polygon { points } if points.len() == 3));
+        assert!(map.spawn_zones[0].contains(10.0, 10.0));
+        assert!(!map.spawn_zones[0].contains(10.0, 100.0));
+    }
+
+    #[test]
+    fn visible_tiles_are_culled_to_the_view() {
+        let map = TileMap::from_json(MAP).unwrap();
+        let mut out = Vec::new();
+        // 2 列目〜3 列目・1 行目〜2 行目に掛かる範囲: fill 層 4 枚 + paths 層（2 行目）2 枚
+        map.visible_tiles(70.0, 10.0, 100.0, 100.0, &mut out);
+        assert_eq!(out.len(), 6);
+        assert_eq!(out[0].0, 64.0);
+        assert_eq!(out[4], (64.0, 64.0, 64.0, [0.5, 0.4, 0.3, 1.0]));
+
+        out.clear();
+        map.visible_tiles(-500.0, -500.0, 100.0, 100.0, &mut out);
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    fn shipped_forest_map_is_valid() {
+        let src = include_str!("../../../assets/maps/forest.json");
+        let map = TileMap::from_json(src).unwrap();
+        assert_eq!(map.bounds(), WorldBounds::new(4096.0, 4096.0));
+        assert_eq!(map.layers.len(), 2);
+    }
+
+    #[test]
+    fn rejects_inconsistent_maps() {
+        let short_layer = MAP.replace("0, 0, 0, 0,  2, 2", "2, 2");
+        assert!(matches!(TileMap::from_json(&short_layer), Err(TileMapError::Invalid(_))));
+        let bad_tile = MAP.replace(r#""fill": 1"#, r#""fill": 3"#);
+        assert!(matches!(TileMap::from_json(&bad_tile), Err(TileMapError::Invalid(_))));
+        let outside = MAP.replace(r#""x": 200, "y": 150"#, r#""x": 900, "y": 150"#);
+        assert!(TileMap::from_json(&outside).is_err());
+        let flat = MAP.replace(r#""width": 200, "height": 16"#, r#""width": 200, "height": 0"#);
+        assert!(matches!(TileMap::from_json(&flat), Err(TileMapError::Invalid(_))));
+        let huge = MAP.replace(r#""width": 4, "height": 3"#, r#""width": 65536, "height": 65536"#)
+            .replace("0, 0, 0, 0,  2, 2, 2, 2,  0, 0, 0, 0", "");
+        assert!(matches!(
+            TileMap::from_json(&huge),
+            Err(TileMapError::Invalid("width and height must be <= MAX_MAP_SIDE"))
+        ));
+    }
+}
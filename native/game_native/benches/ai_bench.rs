@@ -1,10 +1,9 @@
 //! Path: native/game_native/benches/ai_bench.rs
-//! Summary: Chase AI ベンチマーク（rayon スカラー版 vs SIMD 版）
+//! Summary: Chase AI ベンチマーク（rayon スカラー版 vs 各 SIMD 実装（スカラー・SSE2・AVX2・NEON））
 
 use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
-use game_native::{update_chase_ai, EnemyKind, EnemyWorld};
-#[cfg(target_arch = "x86_64")]
-use game_native::update_chase_ai_simd;
+use game_core::physics::chase::ChaseBackend;
+use game_native::{update_chase_ai, update_chase_ai_simd, update_chase_ai_with, EnemyKind, EnemyWorld};
 
 fn setup_enemies(n: usize) -> EnemyWorld {
     let mut enemies = EnemyWorld::new();
@@ -36,7 +35,7 @@ fn bench_chase_ai(c: &mut Criterion) {
         )
     });
 
-    #[cfg(target_arch = "x86_64")]
+    // 実行時に選ばれる実装
     c.bench_function("chase_ai_simd", |b| {
         b.iter_batched(
             || setup_enemies(n),
@@ -47,6 +46,20 @@ fn bench_chase_ai(c: &mut Criterion) {
             BatchSize::PerIteration,
         )
     });
+
+    // この CPU で使える実装ごと
+    for backend in ChaseBackend::available() {
+        c.bench_function(&format!("chase_ai_{}", backend.name()), |b| {
+            b.iter_batched(
+                || setup_enemies(n),
+                |mut enemies| {
+                    update_chase_ai_with(backend, &mut enemies, player_x, player_y, dt);
+                    enemies
+                },
+                BatchSize::PerIteration,
+            )
+        });
+    }
 }
 
 criterion_group!(benches, bench_chase_ai);
@@ -2,16 +2,21 @@
 //! Summary: 敵/弾/パーティクル増量時の physics_step ベンチマーク
 
 use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
-use game_core::constants::{CELL_SIZE, PARTICLE_RNG_SEED, PLAYER_SIZE, SCREEN_HEIGHT, SCREEN_WIDTH};
+use game_core::constants::{CELL_SIZE, PLAYER_SIZE, SCREEN_HEIGHT, SCREEN_WIDTH};
 use game_core::item::ItemWorld;
-use game_core::physics::rng::SimpleRng;
+use game_core::physics::bounds::WorldBounds;
+use game_core::physics::rng::RngStreams;
 use game_core::physics::spatial_hash::CollisionWorld;
+use game_core::player_stats::PlayerStats;
 use game_core::weapon::WeaponSlot;
 use game_native::{
-    run_physics_step_for_bench, BulletWorld, EnemyWorld, GameWorldInner, ParticleWorld, PlayerState,
+    run_physics_step_for_bench, BulletWorld, EnemyWorld, GameWorldInner, ParticleWorld, PlayerState, WeaponRegistry,
 };
 use std::sync::Mutex;
 
+/// パーティクルと乱数ストリームのシード（実行ごとに同じワールドで測る）
+const BENCH_SEED: u64 = 42;
+
 #[derive(Clone, Copy)]
 struct Scenario {
     name: &'static str,
@@ -47,7 +52,7 @@ fn build_world(s: Scenario) -> GameWorldInner {
         );
     }
 
-    let mut particles = ParticleWorld::new(PARTICLE_RNG_SEED);
+    let mut particles = ParticleWorld::new(BENCH_SEED);
     for i in 0..s.particles {
         let t = i as f32;
         particles.spawn_one(
@@ -76,8 +81,10 @@ fn build_world(s: Scenario) -> GameWorldInner {
         particles,
         items: ItemWorld::new(),
         magnet_timer: 0.0,
-        rng: SimpleRng::new(42),
+        rng: RngStreams::new(BENCH_SEED),
         collision: CollisionWorld::new(CELL_SIZE),
+        bounds: WorldBounds::default(),
+        tile_map: None,
         obstacle_query_buf: Vec::new(),
         last_frame_time_ms: 0.0,
         score: 0,
@@ -87,12 +94,22 @@ fn build_world(s: Scenario) -> GameWorldInner {
         level: 1,
         level_up_pending: false,
         weapon_slots: vec![WeaponSlot::new(0)],
-        boss: None,
+        passive_slots: Vec::new(),
+        player_stats: PlayerStats::default(),
+        weapon_registry: WeaponRegistry::from_entity_params(),
+        bosses: Vec::new(),
+        next_boss_id: 0,
+        spawn_director: None,
         frame_events: Vec::new(),
         pending_ui_action: Mutex::new(None),
         weapon_choices: Vec::new(),
         score_popups: Vec::new(),
         kill_count: 0,
+        prev_player_x: SCREEN_WIDTH / 2.0 - PLAYER_SIZE / 2.0,
+        prev_player_y: SCREEN_HEIGHT / 2.0 - PLAYER_SIZE / 2.0,
+        prev_tick_ms: 0,
+        curr_tick_ms: 0,
+        replay: None,
     }
 }
 
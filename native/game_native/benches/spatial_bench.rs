@@ -0,0 +1,109 @@
+//! Path: native/game_native/benches/spatial_bench.rs
+//! Summary: 近傍クエリ構造のベンチマーク（旧 FxHashMap の SpatialHash vs 密なグリッドの SpatialGrid、分離パスのシリアル版 vs 並列版）
+
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use game_core::constants::{CELL_SIZE, ENEMY_SEPARATION_FORCE, ENEMY_SEPARATION_RADIUS};
+use game_core::physics::separation::{apply_separation, apply_separation_serial};
+use game_core::physics::spatial_grid::SpatialGrid;
+use game_core::physics::spatial_hash::SpatialHash;
+use game_native::{EnemyKind, EnemyWorld};
+
+const ENEMIES: usize = 10_000;
+
+fn positions(n: usize) -> Vec<(f32, f32)> {
+    (0..n)
+        .map(|i| {
+            let x = ((i * 13) % 2400) as f32 + 100.0;
+            let y = ((i * 17) % 1400) as f32 + 100.0;
+            (x, y)
+        })
+        .collect()
+}
+
+fn bench_rebuild(c: &mut Criterion) {
+    let points = positions(ENEMIES);
+
+    // 毎フレーム clear → insert していた従来の方式
+    let mut hash = SpatialHash::new(CELL_SIZE);
+    c.bench_function("spatial_hash_rebuild_10k", |b| {
+        b.iter(|| {
+            hash.clear();
+            for (i, &(x, y)) in points.iter().enumerate() {
+                hash.insert(i, x, y);
+            }
+        })
+    });
+
+    let mut grid = SpatialGrid::new(CELL_SIZE);
+    c.bench_function("spatial_grid_rebuild_10k", |b| {
+        b.iter(|| grid.rebuild(points.iter().enumerate().map(|(i, &(x, y))| (i, x, y))))
+    });
+}
+
+fn bench_query(c: &mut Criterion) {
+    let points = positions(ENEMIES);
+    let mut hash = SpatialHash::new(CELL_SIZE);
+    for (i, &(x, y)) in points.iter().enumerate() {
+        hash.insert(i, x, y);
+    }
+    let mut grid = SpatialGrid::new(CELL_SIZE);
+    grid.rebuild(points.iter().enumerate().map(|(i, &(x, y))| (i, x, y)));
+    let mut buf = Vec::new();
+
+    c.bench_function("spatial_hash_query_10k", |b| {
+        b.iter(|| {
+            let mut total = 0;
+            for &(x, y) in &points {
+                hash.query_nearby_into(x, y, ENEMY_SEPARATION_RADIUS, &mut buf);
+                total += buf.len();
+            }
+            black_box(total)
+        })
+    });
+
+    c.bench_function("spatial_grid_query_10k", |b| {
+        b.iter(|| {
+            let mut total = 0;
+            for &(x, y) in &points {
+                grid.query_nearby_into(x, y, ENEMY_SEPARATION_RADIUS, &mut buf);
+                total += buf.len();
+            }
+            black_box(total)
+        })
+    });
+}
+
+fn bench_separation(c: &mut Criterion) {
+    let mut grid = SpatialGrid::new(CELL_SIZE);
+    let setup = |n: usize| {
+        let mut enemies = EnemyWorld::new();
+        enemies.spawn(&positions(n), EnemyKind::Slime as u8);
+        enemies
+    };
+
+    for n in [1_000, 5_000, 10_000] {
+        c.bench_function(&format!("separation_serial_{}k", n / 1000), |b| {
+            b.iter_batched(
+                || setup(n),
+                |mut enemies| {
+                    apply_separation_serial(&mut enemies, &mut grid, ENEMY_SEPARATION_RADIUS, ENEMY_SEPARATION_FORCE, 0.016);
+                    enemies
+                },
+                BatchSize::PerIteration,
+            )
+        });
+        c.bench_function(&format!("separation_parallel_{}k", n / 1000), |b| {
+            b.iter_batched(
+                || setup(n),
+                |mut enemies| {
+                    apply_separation(&mut enemies, &mut grid, ENEMY_SEPARATION_RADIUS, ENEMY_SEPARATION_FORCE, 0.016);
+                    enemies
+                },
+                BatchSize::PerIteration,
+            )
+        });
+    }
+}
+
+criterion_group!(benches, bench_rebuild, bench_query, bench_separation);
+criterion_main!(benches);
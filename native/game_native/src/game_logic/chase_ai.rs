@@ -2,6 +2,7 @@
 //! Summary: 敵 Chase AI と最近接探索（find_nearest_*）
 
 use crate::EnemyWorld;
+use game_core::physics::chase::{chase_one, ChaseBackend, ChaseSlices};
 use game_core::physics::spatial_hash::CollisionWorld;
 use rayon::prelude::*;
 
@@ -102,100 +103,27 @@ pub fn find_nearest_enemy_spatial_excluding(
     result.or_else(|| find_nearest_enemy_excluding(enemies, px, py, exclude))
 }
 
-/// 1 体分の Chase AI（スカラー版・SIMD フォールバック用）
-#[inline]
-fn scalar_chase_one(
-    enemies: &mut EnemyWorld,
-    i: usize,
-    player_x: f32,
-    player_y: f32,
-    dt: f32,
-) {
-    let dx = player_x - enemies.positions_x[i];
-    let dy = player_y - enemies.positions_y[i];
-    let dist = (dx * dx + dy * dy).sqrt().max(0.001);
-    let speed = enemies.speeds[i];
-    enemies.velocities_x[i] = (dx / dist) * speed;
-    enemies.velocities_y[i] = (dy / dist) * speed;
-    enemies.positions_x[i] += enemies.velocities_x[i] * dt;
-    enemies.positions_y[i] += enemies.velocities_y[i] * dt;
+fn chase_slices(enemies: &mut EnemyWorld) -> ChaseSlices<'_> {
+    ChaseSlices {
+        pos_x:  &mut enemies.positions_x,
+        pos_y:  &mut enemies.positions_y,
+        vel_x:  &mut enemies.velocities_x,
+        vel_y:  &mut enemies.velocities_y,
+        speeds: &enemies.speeds,
+        alive:  &enemies.alive,
+        stun:   &enemies.stun_timers,
+    }
 }
 
-/// SIMD（SSE2）版 Chase AI — x86_64 専用
-#[cfg(target_arch = "x86_64")]
-pub fn update_chase_ai_simd(
-    enemies: &mut EnemyWorld,
-    player_x: f32,
-    player_y: f32,
-    dt: f32,
-) {
-    use std::arch::x86_64::*;
-
-    let len = enemies.len();
-    let simd_len = (len / 4) * 4;
-
-    unsafe {
-        let px4 = _mm_set1_ps(player_x);
-        let py4 = _mm_set1_ps(player_y);
-        let dt4 = _mm_set1_ps(dt);
-        let eps4 = _mm_set1_ps(0.001_f32);
-
-        for base in (0..simd_len).step_by(4) {
-            let ex = _mm_loadu_ps(enemies.positions_x[base..].as_ptr());
-            let ey = _mm_loadu_ps(enemies.positions_y[base..].as_ptr());
-            let sp = _mm_loadu_ps(enemies.speeds[base..].as_ptr());
-
-            let dx = _mm_sub_ps(px4, ex);
-            let dy = _mm_sub_ps(py4, ey);
-            let dist_sq_val = _mm_add_ps(_mm_mul_ps(dx, dx), _mm_mul_ps(dy, dy));
-            let dist_sq_safe = _mm_max_ps(dist_sq_val, eps4);
-            let inv_dist = _mm_rsqrt_ps(dist_sq_safe);
-
-            let vx = _mm_mul_ps(_mm_mul_ps(dx, inv_dist), sp);
-            let vy = _mm_mul_ps(_mm_mul_ps(dy, inv_dist), sp);
-
-            let new_ex = _mm_add_ps(ex, _mm_mul_ps(vx, dt4));
-            let new_ey = _mm_add_ps(ey, _mm_mul_ps(vy, dt4));
-
-            let alive_mask = _mm_castsi128_ps(_mm_set_epi32(
-                if enemies.alive[base + 3] { -1i32 } else { 0 },
-                if enemies.alive[base + 2] { -1i32 } else { 0 },
-                if enemies.alive[base + 1] { -1i32 } else { 0 },
-                if enemies.alive[base + 0] { -1i32 } else { 0 },
-            ));
-
-            let old_vx = _mm_loadu_ps(enemies.velocities_x[base..].as_ptr());
-            let old_vy = _mm_loadu_ps(enemies.velocities_y[base..].as_ptr());
-
-            let final_ex = _mm_or_ps(
-                _mm_andnot_ps(alive_mask, ex),
-                _mm_and_ps(alive_mask, new_ex),
-            );
-            let final_ey = _mm_or_ps(
-                _mm_andnot_ps(alive_mask, ey),
-                _mm_and_ps(alive_mask, new_ey),
-            );
-            let final_vx = _mm_or_ps(
-                _mm_andnot_ps(alive_mask, old_vx),
-                _mm_and_ps(alive_mask, vx),
-            );
-            let final_vy = _mm_or_ps(
-                _mm_andnot_ps(alive_mask, old_vy),
-                _mm_and_ps(alive_mask, vy),
-            );
-
-            _mm_storeu_ps(enemies.positions_x[base..].as_mut_ptr(), final_ex);
-            _mm_storeu_ps(enemies.positions_y[base..].as_mut_ptr(), final_ey);
-            _mm_storeu_ps(enemies.velocities_x[base..].as_mut_ptr(), final_vx);
-            _mm_storeu_ps(enemies.velocities_y[base..].as_mut_ptr(), final_vy);
-        }
+/// SIMD 版 Chase AI。CPU 機能を実行時に判定し、AVX2（8 レーン）・SSE2・NEON・スカラーのうち
+/// 使える中で最も速い実装で処理する（判定は初回のみ）
+pub fn update_chase_ai_simd(enemies: &mut EnemyWorld, player_x: f32, player_y: f32, dt: f32) {
+    update_chase_ai_with(ChaseBackend::detect(), enemies, player_x, player_y, dt);
+}
 
-        for i in simd_len..len {
-            if enemies.alive[i] {
-                scalar_chase_one(enemies, i, player_x, player_y, dt);
-            }
-        }
-    }
+/// 実装を指定して Chase AI を実行する（ベンチマークで実装ごとに比べるため）
+pub fn update_chase_ai_with(backend: ChaseBackend, enemies: &mut EnemyWorld, player_x: f32, player_y: f32, dt: f32) {
+    backend.run(&mut chase_slices(enemies), player_x, player_y, dt);
 }
 
 /// Chase AI: 全敵をプレイヤーに向けて移動（rayon で並列化）
@@ -207,6 +135,7 @@ pub fn update_chase_ai(enemies: &mut EnemyWorld, player_x: f32, player_y: f32, d
     let velocities_y = &mut enemies.velocities_y[..len];
     let speeds       = &enemies.speeds[..len];
     let alive        = &enemies.alive[..len];
+    let stun_timers  = &enemies.stun_timers[..len];
 
     (
         positions_x,
@@ -215,18 +144,48 @@ pub fn update_chase_ai(enemies: &mut EnemyWorld, player_x: f32, player_y: f32, d
         velocities_y,
         speeds,
         alive,
+        stun_timers,
     )
         .into_par_iter()
-        .for_each(|(px, py, vx, vy, speed, is_alive)| {
-            if !*is_alive {
-                return;
+        .for_each(|(px, py, vx, vy, speed, is_alive, stun)| {
+            if *is_alive {
+                chase_one(px, py, vx, vy, *speed, *stun, player_x, player_y, dt);
             }
-            let dx   = player_x - *px;
-            let dy   = player_y - *py;
-            let dist = (dx * dx + dy * dy).sqrt().max(0.001);
-            *vx  = (dx / dist) * speed;
-            *vy  = (dy / dist) * speed;
-            *px += *vx * dt;
-            *py += *vy * dt;
         });
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// (0, 0) の敵をノックバックで左へ飛ばし、stun 秒のけぞらせた EnemyWorld
+    fn knocked_back_enemy(stun: f32) -> EnemyWorld {
+        let mut enemies = EnemyWorld::new();
+        enemies.spawn(&[(0.0, 0.0)], 0);
+        enemies.knock_back(0, -1.0, 0.0, 200.0, stun);
+        enemies
+    }
+
+    #[test]
+    fn stunned_enemy_drifts_instead_of_steering() {
+        let mut enemies = knocked_back_enemy(0.2);
+        let vx = enemies.velocities_x[0];
+        assert!(vx < 0.0);
+
+        update_chase_ai(&mut enemies, 1000.0, 0.0, 0.1);
+
+        assert_eq!(enemies.velocities_x[0], vx, "のけぞり中はプレイヤーへ向き直らない");
+        assert_eq!(enemies.positions_x[0], vx * 0.1);
+    }
+
+    #[test]
+    fn enemy_steers_toward_player_once_stun_ends() {
+        let mut enemies = knocked_back_enemy(0.2);
+        enemies.stun_timers[0] = 0.0;
+
+        update_chase_ai(&mut enemies, 1000.0, 0.0, 0.1);
+
+        assert_eq!(enemies.velocities_x[0], enemies.speeds[0]);
+        assert_eq!(enemies.velocities_y[0], 0.0);
+    }
+}
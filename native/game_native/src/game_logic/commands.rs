@@ -0,0 +1,169 @@
+//! Path: native/game_native/src/game_logic/commands.rs
+//! Summary: ワールドを変更するコマンドの適用（NIF・描画スレッドの入力・リプレイ再生の共通経路）
+//!
+//! 検証を通ったコマンドだけをリプレイに記録してから適用する。物理ステップ（Step）の記録は
+//! physics_step_inner 自身が行う（Rust ゲームループ・push_tick からも直接呼ばれるため）。
+
+use super::physics_step::physics_step_inner;
+use super::systems::spawn::get_spawn_positions_around_player;
+use crate::world::{BossState, GameWorldInner};
+use game_core::constants::{MAX_BOSSES, PLAYER_RADIUS, PLAYER_SIZE};
+use game_core::entity_params::{entity_params_counts, BossParams, EliteParams, PassiveParams};
+use game_core::physics::bounds::WorldBounds;
+use game_core::player_stats::{PassiveSlot, MAX_PASSIVE_SLOTS};
+use game_core::replay::ReplayCommand;
+use game_core::spawn_director::{SpawnDirector, SpawnSchedule};
+use game_core::tile_map::TileMap;
+use game_core::weapon::{WeaponSlot, MAX_WEAPON_LEVEL, MAX_WEAPON_SLOTS};
+use std::fmt;
+
+/// コマンドが適用できない（ワールドは変更せず、リプレイにも記録しない）
+#[derive(Debug)]
+pub(crate) enum CommandError {
+    /// 引数が不正（NIF では badarg）
+    BadArg,
+    /// マップ・スポーンスケジュールが読み込めない
+    Invalid(String),
+}
+
+impl fmt::Display for CommandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BadArg => write!(f, "bad argument"),
+            Self::Invalid(reason) => f.write_str(reason),
+        }
+    }
+}
+
+/// コマンドを検証し、リプレイに記録してから適用する
+pub(crate) fn apply_command(w: &mut GameWorldInner, cmd: ReplayCommand) -> Result<(), CommandError> {
+    match cmd {
+        ReplayCommand::SetInput { dx, dy } => {
+            // push_tick は毎 tick 入力を渡すので、変化したときだけ記録する
+            if (w.player.input_dx, w.player.input_dy) != (dx, dy) {
+                w.record_command(&cmd);
+                w.player.input_dx = dx;
+                w.player.input_dy = dy;
+            }
+        }
+        ReplayCommand::Step { delta_ms } => physics_step_inner(w, delta_ms as f64),
+        ReplayCommand::SpawnEnemies { kind_id, count } => {
+            check_enemy_id(kind_id)?;
+            w.record_command(&cmd);
+            let positions = get_spawn_positions_around_player(w, kind_id, false, count as usize);
+            w.enemies.spawn(&positions, kind_id);
+        }
+        ReplayCommand::SpawnElite { kind_id, count, hp_multiplier } => {
+            check_enemy_id(kind_id)?;
+            w.record_command(&cmd);
+            let positions = get_spawn_positions_around_player(w, kind_id, true, count as usize);
+            // hp_multiplier <= 0 ならコンテンツの EliteParams に従う
+            let hp_multiplier = if hp_multiplier > 0.0 {
+                hp_multiplier as f32
+            } else {
+                EliteParams::get().hp_multiplier
+            };
+            w.enemies.spawn_elite(&positions, kind_id, hp_multiplier);
+        }
+        ReplayCommand::SpawnBoss { kind_id } => {
+            w.record_command(&cmd);
+            spawn_boss(w, kind_id);
+        }
+        ReplayCommand::AddWeapon { weapon_id } => {
+            let (_, weapon_kinds, _) = entity_params_counts();
+            if weapon_id as usize >= weapon_kinds {
+                return Err(CommandError::BadArg);
+            }
+            w.record_command(&cmd);
+            if let Some(slot) = w.weapon_slots.iter_mut().find(|s| s.kind_id == weapon_id) {
+                slot.level = (slot.level + 1).min(MAX_WEAPON_LEVEL);
+            } else if w.weapon_slots.len() < MAX_WEAPON_SLOTS {
+                w.weapon_slots.push(WeaponSlot::new(weapon_id));
+            }
+            w.complete_level_up();
+        }
+        ReplayCommand::AddPassive { passive_id } => {
+            w.record_command(&cmd);
+            if let Some(pp) = PassiveParams::try_get(passive_id) {
+                if let Some(slot) = w.passive_slots.iter_mut().find(|s| s.kind_id == passive_id) {
+                    slot.level = (slot.level + 1).min(pp.max_level);
+                } else if w.passive_slots.len() < MAX_PASSIVE_SLOTS {
+                    w.passive_slots.push(PassiveSlot::new(passive_id));
+                }
+                w.refresh_player_stats();
+            }
+            w.complete_level_up();
+        }
+        ReplayCommand::SkipLevelUp => {
+            w.record_command(&cmd);
+            w.complete_level_up();
+        }
+        ReplayCommand::SetWorldSize { width, height } => {
+            if !(width.is_finite() && height.is_finite() && width > 0.0 && height > 0.0) {
+                return Err(CommandError::BadArg);
+            }
+            w.record_command(&cmd);
+            w.bounds = WorldBounds::new(width as f32, height as f32);
+            let (x, y) = w.bounds.clamp_top_left(w.player.x, w.player.y, PLAYER_SIZE);
+            move_player_to(w, x, y);
+        }
+        ReplayCommand::SetMapObstacles { ref obstacles } => {
+            w.record_command(&cmd);
+            w.collision.rebuild_static(obstacles);
+        }
+        ReplayCommand::LoadMap { ref json } => {
+            let map = TileMap::from_json(json).map_err(|e| CommandError::Invalid(e.to_string()))?;
+            w.record_command(&cmd);
+            w.bounds = map.bounds();
+            w.collision.set_static(map.static_obstacles());
+            let (sx, sy) = map.player_start();
+            let (x, y) = w.bounds.clamp_top_left(sx - PLAYER_SIZE / 2.0, sy - PLAYER_SIZE / 2.0, PLAYER_SIZE);
+            move_player_to(w, x, y);
+            w.tile_map = Some(map);
+        }
+        ReplayCommand::SetSpawnSchedule { ref json } => {
+            // 敵 ID は読み込み済みの entity_params で検証する
+            let (enemy_kinds, _, _) = entity_params_counts();
+            let schedule = SpawnSchedule::from_json(json, enemy_kinds)
+                .map_err(|e| CommandError::Invalid(e.to_string()))?;
+            w.record_command(&cmd);
+            w.spawn_director = Some(SpawnDirector::new(schedule));
+        }
+    }
+    Ok(())
+}
+
+/// 読み込み済みの entity_params にない敵 ID は badarg（スポーン時に参照できないため）
+fn check_enemy_id(kind_id: u8) -> Result<(), CommandError> {
+    let (enemy_kinds, _, _) = entity_params_counts();
+    if kind_id as usize >= enemy_kinds {
+        return Err(CommandError::BadArg);
+    }
+    Ok(())
+}
+
+/// プレイヤーを (x, y)（左上座標）に移す。補間用の前フレーム位置も合わせる
+fn move_player_to(w: &mut GameWorldInner, x: f32, y: f32) {
+    w.player.x = x;
+    w.player.y = y;
+    w.prev_player_x = x;
+    w.prev_player_y = y;
+}
+
+fn spawn_boss(w: &mut GameWorldInner, kind_id: u8) {
+    if w.bosses.len() >= MAX_BOSSES {
+        return;
+    }
+    let (_, _, boss_kinds) = entity_params_counts();
+    if (kind_id as usize) < boss_kinds {
+        let bp = BossParams::get(kind_id);
+        let px = w.player.x + PLAYER_RADIUS;
+        let py = w.player.y + PLAYER_RADIUS;
+        // 既存のボスと重ならないよう、出現数に応じてプレイヤー周りの方向をずらす
+        let angle = w.bosses.len() as f32 * std::f32::consts::TAU / MAX_BOSSES as f32;
+        let (bx, by) = w.bounds.clamp_center(px + angle.cos() * 600.0, py + angle.sin() * 600.0, bp.radius);
+        let id = w.next_boss_id;
+        w.next_boss_id += 1;
+        w.bosses.push(BossState::new(id, kind_id, bx, by));
+    }
+}
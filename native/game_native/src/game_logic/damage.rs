@@ -0,0 +1,258 @@
+//! Path: native/game_native/src/game_logic/damage.rs
+//! Summary: 敵・ボスへのダメージ適用と撃破処理の共通パイプライン
+//!
+//! 弾丸・Whip・Lightning・Garlic・状態異常の継続ダメージなど、すべてのダメージ源は
+//! apply_damage を通す。会心・耐性の補正と、撃破時のスコア・経験値・レベルアップ判定・
+//! FrameEvent・パーティクル・ドロップをここで一括して扱う。
+
+use super::systems::evolution::try_evolve_weapon;
+use super::systems::leveling::compute_weapon_choices;
+use crate::world::{FrameEvent, GameWorldInner};
+use game_core::constants::CRIT_MULTIPLIER;
+use game_core::entity_params::{BossParams, EliteParams, EnemyParams, WeaponParams};
+use game_core::loot::LootTable;
+use game_core::physics::rng::SimpleRng;
+use game_core::util::exp_required_for_next;
+
+/// ダメージの対象
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DamageTarget {
+    /// EnemyWorld のスロットインデックス
+    Enemy(usize),
+    /// BossState::id（スロット位置は撃破で詰まるため ID で指す）
+    Boss(u32),
+}
+
+/// apply_damage の結果
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct DamageOutcome {
+    /// 補正後に与えたダメージ（対象が死亡済み・不在・無敵なら 0）
+    pub dealt:  f32,
+    pub crit:   bool,
+    /// このダメージで対象を倒した
+    pub killed: bool,
+}
+
+/// 対象にダメージを与え、倒した場合は撃破処理まで行う。
+///
+/// 補正の順序: 会心（武器の crit_chance）→ 耐性（対象の resistance）。
+/// source_weapon は EnemyKilled イベントと会心率の参照に使う武器 ID。
+pub fn apply_damage(
+    w: &mut GameWorldInner,
+    target: DamageTarget,
+    amount: f32,
+    source_weapon: u8,
+) -> DamageOutcome {
+    let resistance = match target {
+        DamageTarget::Enemy(i) => {
+            if !w.enemies.alive[i] {
+                return DamageOutcome::default();
+            }
+            EnemyParams::get(w.enemies.kind_ids[i]).resistance
+        }
+        DamageTarget::Boss(id) => match w.boss_index(id).map(|bi| &w.bosses[bi]) {
+            Some(boss) if !boss.invincible => BossParams::get(boss.kind_id).resistance,
+            _ => return DamageOutcome::default(),
+        },
+    };
+
+    let chance = WeaponParams::get(source_weapon).crit_chance * w.player_stats.luck;
+    let crit = roll_crit(&mut w.rng.combat, chance);
+    let dealt = modified_damage(amount, crit, resistance);
+    if dealt <= 0.0 {
+        return DamageOutcome { dealt: 0.0, crit, killed: false };
+    }
+
+    let killed = match target {
+        DamageTarget::Enemy(i) => {
+            w.enemies.hp[i] -= dealt;
+            let dead = w.enemies.hp[i] <= 0.0;
+            if dead {
+                kill_enemy(w, i, source_weapon);
+            }
+            dead
+        }
+        DamageTarget::Boss(id) => {
+            let dead = w.boss_index(id).is_some_and(|bi| {
+                let boss = &mut w.bosses[bi];
+                boss.hp -= dealt;
+                boss.hp <= 0.0
+            });
+            if dead {
+                kill_boss(w, id);
+            }
+            dead
+        }
+    };
+
+    if crit && !killed {
+        if let Some((x, y)) = target_center(w, target) {
+            w.particles.emit(x, y, 4, [1.0, 1.0, 1.0, 1.0]);
+        }
+    }
+
+    DamageOutcome { dealt, crit, killed }
+}
+
+/// 会心判定（chance は武器の crit_chance × Luck。0 なら乱数を消費しない）
+fn roll_crit(rng: &mut SimpleRng, chance: f32) -> bool {
+    chance > 0.0 && rng.next_f32() < chance
+}
+
+/// 会心（CRIT_MULTIPLIER 倍）→ 耐性（resistance の割合を軽減）の順に補正したダメージ
+fn modified_damage(amount: f32, crit: bool, resistance: f32) -> f32 {
+    let amount = if crit { amount * CRIT_MULTIPLIER } else { amount };
+    amount * (1.0 - resistance)
+}
+
+/// 対象の中心座標（パーティクル位置用）
+fn target_center(w: &GameWorldInner, target: DamageTarget) -> Option<(f32, f32)> {
+    match target {
+        DamageTarget::Enemy(i) => {
+            let r = w.enemies.radius(i);
+            Some((w.enemies.positions_x[i] + r, w.enemies.positions_y[i] + r))
+        }
+        DamageTarget::Boss(id) => w.boss_index(id).map(|bi| (w.bosses[bi].x, w.bosses[bi].y)),
+    }
+}
+
+/// 経験値を加算し、必要量に達したらレベルアップ待ちにして選択肢を用意する
+fn gain_exp(w: &mut GameWorldInner, exp: u32) {
+    w.exp += exp;
+    if !w.level_up_pending {
+        let required = exp_required_for_next(w.level);
+        if w.exp >= required {
+            let new_lv = w.level + 1;
+            w.level_up_pending = true;
+            w.weapon_choices = compute_weapon_choices(w);
+            w.frame_events.push(FrameEvent::LevelUp { new_level: new_lv });
+        }
+    }
+}
+
+/// 敵の撃破処理（スコア・イベント・経験値・パーティクル・ドロップ）。
+/// エリートは EliteParams の倍率で経験値・スコアが増え、ルートテーブルを複数回抽選する
+fn kill_enemy(w: &mut GameWorldInner, i: usize, source_weapon: u8) {
+    let kind_id = w.enemies.kind_ids[i];
+    let is_elite = w.enemies.is_elite[i];
+    let ep = EnemyParams::get(kind_id);
+    let r = w.enemies.radius(i);
+    let x = w.enemies.positions_x[i] + r;
+    let y = w.enemies.positions_y[i] + r;
+    let (exp_reward, loot_rolls) = if is_elite {
+        let elite = EliteParams::get();
+        ((ep.exp_reward as f32 * elite.exp_multiplier).round() as u32, elite.loot_rolls)
+    } else {
+        (ep.exp_reward, 1)
+    };
+    w.enemies.kill(i);
+    w.kill_count += 1;
+    w.score_popups.push((x, y - 20.0, exp_reward * 2, 0.8));
+    w.frame_events.push(FrameEvent::EnemyKilled {
+        enemy_kind:  kind_id,
+        weapon_kind: source_weapon,
+        is_elite,
+    });
+    w.score += exp_reward * 2;
+    gain_exp(w, exp_reward);
+    w.particles.emit(x, y, if is_elite { 16 } else { 8 }, ep.particle_color);
+    for _ in 0..loot_rolls {
+        spawn_loot(w, &ep.loot, x, y, exp_reward);
+    }
+}
+
+/// ルートテーブルを抽選し、撃破位置（scatter があれば周囲）にアイテムを置く
+fn spawn_loot(w: &mut GameWorldInner, loot: &LootTable, x: f32, y: f32, exp_reward: u32) {
+    let mut drops = Vec::new();
+    loot.roll(&mut w.rng.loot, w.player_stats.luck, exp_reward, &mut drops);
+    for (kind, value) in drops {
+        let (ox, oy) = if loot.scatter > 0.0 {
+            (
+                (w.rng.loot.next_f32() - 0.5) * loot.scatter,
+                (w.rng.loot.next_f32() - 0.5) * loot.scatter,
+            )
+        } else {
+            (0.0, 0.0)
+        };
+        w.items.spawn(x + ox, y + oy, kind, value);
+    }
+}
+
+/// ボスの撃破処理（1.2.9: 経験値 + ルートテーブルのドロップ。組み込みは Gem を周囲にばら撒く）
+fn kill_boss(w: &mut GameWorldInner, id: u32) {
+    let Some(bi) = w.boss_index(id) else { return };
+    let boss = w.bosses.remove(bi);
+    let bp = BossParams::get(boss.kind_id);
+    let exp_reward = bp.exp_reward;
+    w.kill_count += 1;
+    w.score_popups
+        .push((boss.x, boss.y - 20.0, exp_reward * 2, 0.8));
+    w.frame_events
+        .push(FrameEvent::BossDefeated { boss_kind: boss.kind_id, boss_id: boss.id });
+    w.score += exp_reward * 2;
+    gain_exp(w, exp_reward);
+    w.particles
+        .emit(boss.x, boss.y, 40, [1.0, 0.5, 0.0, 1.0]);
+    spawn_loot(w, &bp.loot, boss.x, boss.y, exp_reward);
+    // ボス撃破で武器進化を判定する
+    try_evolve_weapon(w);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::world::BossState;
+
+    #[test]
+    fn crit_doubles_before_resistance_is_applied() {
+        assert_eq!(modified_damage(10.0, false, 0.0), 10.0);
+        assert_eq!(modified_damage(10.0, true, 0.0), 10.0 * CRIT_MULTIPLIER);
+        assert_eq!(modified_damage(10.0, false, 0.5), 5.0);
+        assert_eq!(modified_damage(10.0, true, 0.25), 10.0 * CRIT_MULTIPLIER * 0.75);
+        assert_eq!(modified_damage(10.0, true, 1.0), 0.0);
+    }
+
+    #[test]
+    fn zero_crit_chance_never_crits_and_keeps_the_rng_untouched() {
+        let mut rng = SimpleRng::new(3);
+        let before = rng.state();
+        assert!((0..100).all(|_| !roll_crit(&mut rng, 0.0)));
+        assert_eq!(rng.state(), before);
+        assert!((0..100).all(|_| roll_crit(&mut rng, 1.0)));
+    }
+
+    #[test]
+    fn lethal_damage_removes_the_boss_and_emits_boss_defeated() {
+        let mut w = GameWorldInner::new(1);
+        let mut boss = BossState::new(7, 0, 500.0, 500.0);
+        boss.hp = 5.0;
+        w.bosses.push(boss);
+
+        let outcome = apply_damage(&mut w, DamageTarget::Boss(7), 10.0, 0);
+        assert!(outcome.killed);
+        assert_eq!(outcome.dealt, 10.0);
+        assert!(w.bosses.is_empty());
+        assert_eq!(w.kill_count, 1);
+        assert!(w.score > 0);
+        assert!(w
+            .frame_events
+            .iter()
+            .any(|e| matches!(e, FrameEvent::BossDefeated { boss_kind: 0, boss_id: 7 })));
+
+        // 撃破済みの ID には何も起きない
+        assert_eq!(apply_damage(&mut w, DamageTarget::Boss(7), 10.0, 0), DamageOutcome::default());
+    }
+
+    #[test]
+    fn invincible_boss_takes_no_damage() {
+        let mut w = GameWorldInner::new(1);
+        let mut boss = BossState::new(0, 0, 500.0, 500.0);
+        boss.invincible = true;
+        let hp = boss.hp;
+        w.bosses.push(boss);
+
+        assert_eq!(apply_damage(&mut w, DamageTarget::Boss(0), 10.0, 0), DamageOutcome::default());
+        assert_eq!(w.bosses[0].hp, hp);
+        assert!(w.frame_events.is_empty());
+    }
+}
@@ -9,16 +9,22 @@ pub(crate) fn drain_frame_events_inner(w: &mut GameWorldInner) -> Vec<(Atom, u32
     w.frame_events
         .drain(..)
         .map(|e| match e {
-            FrameEvent::EnemyKilled { enemy_kind, weapon_kind } =>
+            FrameEvent::EnemyKilled { enemy_kind, weapon_kind, is_elite: false } =>
                 (crate::enemy_killed(), enemy_kind as u32, weapon_kind as u32),
+            FrameEvent::EnemyKilled { enemy_kind, weapon_kind, is_elite: true } =>
+                (crate::elite_enemy_killed(), enemy_kind as u32, weapon_kind as u32),
             FrameEvent::PlayerDamaged { damage } =>
                 (crate::player_damaged(), (damage * 1000.0) as u32, 0),
             FrameEvent::LevelUp { new_level } =>
                 (crate::level_up_event(), new_level as u32, 0),
             FrameEvent::ItemPickup { item_kind } =>
                 (crate::item_pickup(), item_kind as u32, 0),
-            FrameEvent::BossDefeated { boss_kind } =>
-                (crate::boss_defeated(), boss_kind as u32, 0),
+            FrameEvent::BossDefeated { boss_kind, boss_id } =>
+                (crate::boss_defeated(), boss_kind as u32, boss_id),
+            FrameEvent::BossPhaseChanged { boss_id, phase } =>
+                (crate::boss_phase_changed(), boss_id, phase),
+            FrameEvent::WeaponEvolved { from_weapon, to_weapon } =>
+                (crate::weapon_evolved(), from_weapon as u32, to_weapon as u32),
         })
         .collect()
 }
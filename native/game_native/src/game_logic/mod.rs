@@ -1,18 +1,26 @@
 //! Path: native/game_native/src/game_logic/mod.rs
-//! Summary: 物理ステップ・Chase AI・イベント drain
+//! Summary: 物理ステップ・Chase AI・ダメージ処理・イベント drain・コマンド適用・リプレイ再生
 
 mod chase_ai;
+mod commands;
+mod damage;
 mod events;
 mod physics_step;
+mod replay;
 mod systems;
+mod weapon_behavior;
 
 pub use chase_ai::{
     find_nearest_enemy, find_nearest_enemy_excluding, find_nearest_enemy_spatial,
-    find_nearest_enemy_spatial_excluding, update_chase_ai, update_chase_ai_simd,
+    find_nearest_enemy_spatial_excluding, update_chase_ai, update_chase_ai_simd, update_chase_ai_with,
 };
+pub use damage::{apply_damage, DamageOutcome, DamageTarget};
+pub(crate) use commands::{apply_command, CommandError};
 pub(crate) use events::drain_frame_events_inner;
 pub(crate) use physics_step::physics_step_inner;
-pub(crate) use systems::spawn::get_spawn_positions_around_player;
+pub(crate) use weapon_behavior::check_behaviors;
+pub use replay::{play_replay, ReplayPlaybackError, ReplaySummary};
+pub use weapon_behavior::{register_weapon_behavior, FireContext, Targeting, WeaponBehavior, WeaponRegistry};
 
 /// ベンチマーク用の physics_step 実行ヘルパー。
 ///
@@ -1,24 +1,29 @@
 //! Path: native/game_native/src/game_logic/physics_step.rs
 //! Summary: 物理ステップ内部実装
 
-#[cfg(not(target_arch = "x86_64"))]
+#[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
 use super::chase_ai::update_chase_ai;
-#[cfg(target_arch = "x86_64")]
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
 use super::chase_ai::update_chase_ai_simd;
 use super::systems::boss::update_boss;
-use super::systems::collision::resolve_obstacles_enemy;
+use super::systems::collision::{clamp_enemies_to_bounds, resolve_obstacles_enemy};
 use super::systems::effects::{update_particles, update_score_popups};
+use super::systems::elite::update_elite_attacks;
 use super::systems::items::update_items;
-use super::systems::projectiles::update_projectiles_and_enemy_hits;
+use super::systems::knockback::update_hit_stun;
+use super::systems::projectiles::{update_hostile_projectile_hits, update_projectiles_and_enemy_hits};
+use super::systems::spawn::update_spawn_director;
+use super::systems::status::update_status_effects;
 use super::systems::weapons::update_weapon_attacks;
 use crate::world::{FrameEvent, GameWorldInner};
 use game_core::constants::{
     ENEMY_SEPARATION_FORCE, ENEMY_SEPARATION_RADIUS, FRAME_BUDGET_MS, INVINCIBLE_DURATION,
-    MAP_HEIGHT, MAP_WIDTH, PLAYER_RADIUS, PLAYER_SIZE, PLAYER_SPEED,
+    PLAYER_RADIUS, PLAYER_SIZE,
 };
-use game_core::entity_params::EnemyParams;
+use game_core::entity_params::{EliteParams, EnemyParams};
 use game_core::physics::obstacle_resolve;
 use game_core::physics::separation::apply_separation;
+use game_core::replay::{ReplayCommand, REPLAY_CHECKPOINT_INTERVAL};
 
 /// 1.5.1: 物理ステップの内部実装（NIF と Rust ゲームループスレッドの両方から呼ぶ）
 pub(crate) fn physics_step_inner(w: &mut GameWorldInner, delta_ms: f64) {
@@ -26,6 +31,8 @@ pub(crate) fn physics_step_inner(w: &mut GameWorldInner, delta_ms: f64) {
     log::trace!("physics_step: delta={}ms frame_id={}", delta_ms, w.frame_id);
     let t_start = std::time::Instant::now();
 
+    // リプレイ記録中ならステップを記録する（NIF・Rust ゲームループ・push_tick 共通）
+    w.record_command(&ReplayCommand::Step { delta_ms: delta_ms as f32 });
     w.frame_id += 1;
 
     let dt = delta_ms as f32 / 1000.0;
@@ -38,11 +45,12 @@ pub(crate) fn physics_step_inner(w: &mut GameWorldInner, delta_ms: f64) {
     let dx = w.player.input_dx;
     let dy = w.player.input_dy;
 
-    // 斜め移動を正規化して速度を一定に保つ
+    // 斜め移動を正規化して速度を一定に保つ（速度はパッシブ補正込み）
     let len = (dx * dx + dy * dy).sqrt();
     if len > 0.001 {
-        w.player.x += (dx / len) * PLAYER_SPEED * dt;
-        w.player.y += (dy / len) * PLAYER_SPEED * dt;
+        let speed = w.player_stats.move_speed;
+        w.player.x += (dx / len) * speed * dt;
+        w.player.y += (dy / len) * speed * dt;
     }
 
     // 1.5.2: プレイヤー vs 障害物（重なったら押し出し）
@@ -53,25 +61,34 @@ pub(crate) fn physics_step_inner(w: &mut GameWorldInner, delta_ms: f64) {
         &mut w.obstacle_query_buf,
     );
 
-    w.player.x = w.player.x.clamp(0.0, MAP_WIDTH  - PLAYER_SIZE);
-    w.player.y = w.player.y.clamp(0.0, MAP_HEIGHT - PLAYER_SIZE);
+    (w.player.x, w.player.y) = w.bounds.clamp_top_left(w.player.x, w.player.y, PLAYER_SIZE);
 
-    // Chase AI（x86_64 では SIMD 版、それ以外は rayon 版）
+    // ── スポーンディレクター（ウェーブスケジュールに従って敵を出す）──
+    update_spawn_director(w, dt);
+
+    // ── 状態異常: 継続ダメージ・効果時間・凍結/鈍足の速度反映 ────
+    update_status_effects(w, dt);
+
+    // ── のけぞりタイマー更新 + ノックバック速度の減衰 ──────────
+    update_hit_stun(w, dt);
+
+    // Chase AI（x86_64 / aarch64 では SIMD 版（AVX2・SSE2・NEON を実行時に選択）、それ以外は rayon 版）
     let px = w.player.x + PLAYER_RADIUS;
     let py = w.player.y + PLAYER_RADIUS;
-    #[cfg(target_arch = "x86_64")]
+    #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
     update_chase_ai_simd(&mut w.enemies, px, py, dt);
-    #[cfg(not(target_arch = "x86_64"))]
+    #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
     update_chase_ai(&mut w.enemies, px, py, dt);
 
     // 敵同士の重なりを解消する分離パス
-    apply_separation(&mut w.enemies, ENEMY_SEPARATION_RADIUS, ENEMY_SEPARATION_FORCE, dt);
+    apply_separation(&mut w.enemies, &mut w.collision.dynamic, ENEMY_SEPARATION_RADIUS, ENEMY_SEPARATION_FORCE, dt);
 
     // 1.5.2: 敵 vs 障害物（Ghost 以外は押し出し）
     resolve_obstacles_enemy(w);
+    clamp_enemies_to_bounds(w);
 
     // ── 1.1.10: 衝突判定（Spatial Hash）────────────────────────
-    // 1. 動的 Spatial Hash を再構築
+    // 1. 動的グリッドを再構築（分離・障害物の押し出し後の位置で作り直す）
     w.rebuild_collision();
 
     // 無敵タイマーを更新
@@ -81,7 +98,7 @@ pub(crate) fn physics_step_inner(w: &mut GameWorldInner, delta_ms: f64) {
 
     // 2. プレイヤー周辺の敵を取得して円-円判定
     // 最大の敵半径（Golem: 32px）を考慮してクエリ半径を広げる
-    let max_enemy_radius = 32.0_f32;
+    let max_enemy_radius = EnemyParams::max_radius();
     let query_radius = PLAYER_RADIUS + max_enemy_radius;
     let candidates = w.collision.dynamic.query_nearby(px, py, query_radius);
 
@@ -89,9 +106,8 @@ pub(crate) fn physics_step_inner(w: &mut GameWorldInner, delta_ms: f64) {
         if !w.enemies.alive[idx] {
             continue;
         }
-        let kind_id = w.enemies.kind_ids[idx];
-        let params = EnemyParams::get(kind_id);
-        let enemy_r = params.radius;
+        let params = EnemyParams::get(w.enemies.kind_ids[idx]);
+        let enemy_r = w.enemies.radius(idx);
         let hit_radius = PLAYER_RADIUS + enemy_r;
         let ex = w.enemies.positions_x[idx] + enemy_r;
         let ey = w.enemies.positions_y[idx] + enemy_r;
@@ -102,7 +118,10 @@ pub(crate) fn physics_step_inner(w: &mut GameWorldInner, delta_ms: f64) {
         if dist_sq < hit_radius * hit_radius {
             // 敵→プレイヤーへのダメージ（無敵時間中は無効）
             if w.player.invincible_timer <= 0.0 && w.player.hp > 0.0 {
-                let dmg = params.damage_per_sec * dt;
+                // 弱体中の敵は接触ダメージが下がる。エリートは上がる。Armor で更に軽減
+                let elite_mult = if w.enemies.is_elite[idx] { EliteParams::get().damage_multiplier } else { 1.0 };
+                let dmg = params.damage_per_sec * dt * elite_mult * w.enemies.statuses[idx].damage_multiplier();
+                let dmg = w.player_stats.mitigate(dmg);
                 w.player.hp = (w.player.hp - dmg).max(0.0);
                 w.player.invincible_timer = INVINCIBLE_DURATION;
                 w.frame_events.push(FrameEvent::PlayerDamaged { damage: dmg });
@@ -123,12 +142,23 @@ pub(crate) fn physics_step_inner(w: &mut GameWorldInner, delta_ms: f64) {
     // ── 1.2.4: アイテム更新（磁石エフェクト + 自動収集） ─────
     update_items(w, dt, px, py);
 
+    // ── エリートの弾幕攻撃 + 発射待ちの敵弾 ─────────────────────
+    update_elite_attacks(w, dt, px, py);
+    w.bullets.update_pending(dt);
+
     // ── 弾丸移動 + 弾丸 vs 敵衝突判定 ───────────────────────────
     update_projectiles_and_enemy_hits(w, dt);
 
+    // ── 敵陣営の弾 vs プレイヤー ────────────────────────────────
+    update_hostile_projectile_hits(w, px, py);
+
     // ── 1.2.9: ボス更新 ─────────────────────────────────────────
     update_boss(w, dt);
 
+    if w.replay.is_some() && w.frame_id.is_multiple_of(REPLAY_CHECKPOINT_INTERVAL) {
+        w.record_checkpoint();
+    }
+
     // ── 1.1.12: フレーム時間計測 ────────────────────────────────
     let elapsed_ms = t_start.elapsed().as_secs_f64() * 1000.0;
     w.last_frame_time_ms = elapsed_ms;
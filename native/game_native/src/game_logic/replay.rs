@@ -0,0 +1,150 @@
+//! Path: native/game_native/src/game_logic/replay.rs
+//! Summary: リプレイ再生（記録したコマンドを新しいワールドに順に適用し、チェックポイントで状態のずれを検出する）
+//!
+//! entity_params はワールドの外（プロセス全体）の状態なので、記録時と同じパラメータを
+//! 読み込んだ状態で再生すること。
+
+use super::commands::apply_command;
+use crate::world::GameWorldInner;
+use game_core::replay::{ReplayCheckpoint, ReplayError, ReplayEvent, ReplayReader};
+use std::fmt;
+use std::io::Read;
+
+/// 再生結果
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ReplaySummary {
+    /// 再生後の frame_id
+    pub frames:      u32,
+    pub commands:    u32,
+    /// 一致を確認したチェックポイントの数
+    pub checkpoints: u32,
+}
+
+/// 再生の失敗
+#[derive(Debug)]
+pub enum ReplayPlaybackError {
+    /// ファイルが読めない・壊れている
+    Read(ReplayError),
+    /// 記録時には適用できたコマンドが失敗した
+    Command { frame_id: u32, reason: String },
+    /// レコードのフレーム ID が再生中のワールドと合わない（記録の途中から始まっている等）
+    FrameMismatch { expected: u32, actual: u32 },
+    /// チェックポイントで状態が記録と一致しない
+    Diverged { frame_id: u32, expected: ReplayCheckpoint, actual: ReplayCheckpoint },
+}
+
+impl fmt::Display for ReplayPlaybackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Read(e) => e.fmt(f),
+            Self::Command { frame_id, reason } => write!(f, "replay command failed at frame {frame_id}: {reason}"),
+            Self::FrameMismatch { expected, actual } => {
+                write!(f, "replay record is for frame {expected} but the world is at frame {actual}")
+            }
+            Self::Diverged { frame_id, expected, actual } => {
+                write!(f, "replay diverged at frame {frame_id}: expected {expected:?}, got {actual:?}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ReplayPlaybackError {}
+
+impl From<ReplayError> for ReplayPlaybackError {
+    fn from(e: ReplayError) -> Self {
+        Self::Read(e)
+    }
+}
+
+/// リプレイを記録時のシードで作った新しいワールドに再生する。最初にずれたところで失敗する
+pub fn play_replay(input: impl Read) -> Result<ReplaySummary, ReplayPlaybackError> {
+    let mut reader = ReplayReader::new(input)?;
+    let mut w = GameWorldInner::new(reader.header().seed);
+    let mut summary = ReplaySummary { frames: 0, commands: 0, checkpoints: 0 };
+
+    while let Some((frame_id, event)) = reader.next_event()? {
+        if frame_id != w.frame_id {
+            return Err(ReplayPlaybackError::FrameMismatch { expected: frame_id, actual: w.frame_id });
+        }
+        match event {
+            ReplayEvent::Command(cmd) => {
+                apply_command(&mut w, cmd)
+                    .map_err(|e| ReplayPlaybackError::Command { frame_id, reason: e.to_string() })?;
+                // 再生中は誰も drain しないので溜めない（ゲームプレイはイベントを参照しない）
+                w.frame_events.clear();
+                summary.commands += 1;
+            }
+            ReplayEvent::Checkpoint(expected) => {
+                let actual = w.checkpoint();
+                if actual != expected {
+                    return Err(ReplayPlaybackError::Diverged { frame_id, expected, actual });
+                }
+                summary.checkpoints += 1;
+            }
+        }
+    }
+    summary.frames = w.frame_id;
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::world::ReplayRecorder;
+    use game_core::replay::ReplayCommand;
+    use std::fs::File;
+    use std::path::{Path, PathBuf};
+
+    /// テストごとに別名の一時ファイル
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("game_native_{name}_{}.replay", std::process::id()))
+    }
+
+    /// seed のワールドで記録しながら 180 フレーム進め、記録を閉じたワールドを返す。
+    /// tamper はフレームごとに呼ばれ、記録しないままワールドを書き換えられる
+    fn record(path: &Path, seed: u64, mut tamper: impl FnMut(&mut GameWorldInner)) -> GameWorldInner {
+        let mut w = GameWorldInner::new(seed);
+        w.replay = Some(ReplayRecorder::create(path.to_str().unwrap(), seed).unwrap());
+        apply_command(&mut w, ReplayCommand::SpawnEnemies { kind_id: 0, count: 20 }).unwrap();
+        apply_command(&mut w, ReplayCommand::AddWeapon { weapon_id: 2 }).unwrap();
+        // 未知の ID は適用も記録もされない
+        assert!(apply_command(&mut w, ReplayCommand::SpawnEnemies { kind_id: u8::MAX, count: 1 }).is_err());
+        assert!(apply_command(&mut w, ReplayCommand::AddWeapon { weapon_id: u8::MAX }).is_err());
+        for frame in 0..180 {
+            let dx = if frame / 60 % 2 == 0 { 1.0 } else { -1.0 };
+            apply_command(&mut w, ReplayCommand::SetInput { dx, dy: 0.0 }).unwrap();
+            apply_command(&mut w, ReplayCommand::Step { delta_ms: 1000.0 / 60.0 }).unwrap();
+            tamper(&mut w);
+        }
+        w.replay.take().unwrap().finish().unwrap();
+        w
+    }
+
+    #[test]
+    fn recorded_session_plays_back_identically() {
+        let path = temp_path("round_trip");
+        let recorded = record(&path, 11, |_| {});
+        let summary = play_replay(File::open(&path).unwrap()).unwrap();
+        std::fs::remove_file(&path).ok();
+        assert_eq!(summary.frames, recorded.frame_id);
+        assert_eq!(summary.checkpoints, 180 / game_core::replay::REPLAY_CHECKPOINT_INTERVAL);
+        // SpawnEnemies・AddWeapon と、向きが変わったときの SetInput 3 回と Step 180 回
+        assert_eq!(summary.commands, 2 + 3 + 180);
+    }
+
+    #[test]
+    fn state_changed_outside_commands_is_reported_as_diverged() {
+        let path = temp_path("diverged");
+        // 記録されない経路でスコアを書き換える
+        record(&path, 11, |w| if w.frame_id == 30 { w.score += 100 });
+        let result = play_replay(File::open(&path).unwrap());
+        std::fs::remove_file(&path).ok();
+        match result {
+            Err(ReplayPlaybackError::Diverged { frame_id, expected, actual }) => {
+                assert_eq!(frame_id, game_core::replay::REPLAY_CHECKPOINT_INTERVAL);
+                assert_eq!(expected.score, actual.score + 100);
+            }
+            other => panic!("expected Diverged, got {other:?}"),
+        }
+    }
+}
@@ -1,62 +1,63 @@
-use super::leveling::compute_weapon_choices;
+use super::spawn::get_summon_positions;
+use crate::game_logic::damage::{apply_damage, DamageTarget};
 use crate::world::{FrameEvent, GameWorldInner};
 use crate::BULLET_KIND_ROCK;
-use game_core::constants::{BULLET_RADIUS, INVINCIBLE_DURATION, PLAYER_RADIUS, SCREEN_HEIGHT, SCREEN_WIDTH};
-use game_core::entity_params::{BossParams, BOSS_ID_BAT_LORD, BOSS_ID_SLIME_KING, BOSS_ID_STONE_GOLEM};
-use game_core::item::ItemKind;
-use game_core::util::exp_required_for_next;
+use game_core::boss::{BossAttack, BossMovement};
+use game_core::constants::{BULLET_RADIUS, INVINCIBLE_DURATION, PLAYER_RADIUS};
+use game_core::entity_params::{entity_params_counts, BossParams};
+use game_core::physics::collider::sweep_point_circle;
 
 /// 1.2.9: ボス更新（Elixir が spawn_boss で生成したボスを毎フレーム動かす）
 pub(crate) fn update_boss(w: &mut GameWorldInner, dt: f32) {
+    // 更新中に撃破されたボスは bosses から取り除かれるため、ID で位置を確かめながら進む
+    let mut bi = 0;
+    while bi < w.bosses.len() {
+        let id = w.bosses[bi].id;
+        update_one_boss(w, bi, dt);
+        if w.bosses.get(bi).is_some_and(|b| b.id == id) {
+            bi += 1;
+        }
+    }
+}
+
+/// bosses[bi] の 1 体分のフェーズ移行・移動・攻撃・被弾処理。
+/// 行動はすべて BossParams::phases のデータに従う
+fn update_one_boss(w: &mut GameWorldInner, bi: usize, dt: f32) {
     // 借用競合を避けるため、副作用データを先に収集する
     struct BossEffect {
-        spawn_slimes: bool,
-        spawn_rocks: bool,
-        bat_dash: bool,
-        special_x: f32,
-        special_y: f32,
+        /// このフレームに発動した攻撃（Dash はボス自身に適用済み）
+        attacks: Vec<BossAttack>,
+        /// フェーズが切り替わった場合の新フェーズ
+        entered_phase: Option<usize>,
         hurt_player: bool,
-        hurt_x: f32,
-        hurt_y: f32,
         boss_damage: f32,
         bullet_hits: Vec<(usize, f32, bool)>, // (bullet_idx, dmg, kill_bullet)
         boss_x: f32,
         boss_y: f32,
         boss_invincible: bool,
         boss_r: f32,
-        boss_exp_reward: u32,
-        boss_killed: bool,
-        exp_reward: u32,
-        kill_x: f32,
-        kill_y: f32,
     }
     let mut eff = BossEffect {
-        spawn_slimes: false,
-        spawn_rocks: false,
-        bat_dash: false,
-        special_x: 0.0,
-        special_y: 0.0,
+        attacks: Vec::new(),
+        entered_phase: None,
         hurt_player: false,
-        hurt_x: 0.0,
-        hurt_y: 0.0,
         boss_damage: 0.0,
         bullet_hits: Vec::new(),
         boss_x: 0.0,
         boss_y: 0.0,
         boss_invincible: false,
         boss_r: 0.0,
-        boss_exp_reward: 0,
-        boss_killed: false,
-        exp_reward: 0,
-        kill_x: 0.0,
-        kill_y: 0.0,
     };
 
     // フェーズ1: boss の移動・タイマー更新（boss のみを借用）
-    if let Some(boss) = w.boss.as_mut() {
-        // プレイヤー座標をコピーして boss 借用前に取得
-        let px = w.player.x + PLAYER_RADIUS;
-        let py = w.player.y + PLAYER_RADIUS;
+    // プレイヤー座標をコピーして boss 借用前に取得
+    let px = w.player.x + PLAYER_RADIUS;
+    let py = w.player.y + PLAYER_RADIUS;
+    let boss_id = w.bosses[bi].id;
+    let bounds = w.bounds;
+    {
+        let boss = &mut w.bosses[bi];
+        let bp = BossParams::get(boss.kind_id);
 
         // 無敵タイマー
         if boss.invincible_timer > 0.0 {
@@ -66,218 +67,255 @@ pub(crate) fn update_boss(w: &mut GameWorldInner, dt: f32) {
             }
         }
 
-        // 移動 AI
-        let bp = BossParams::get(boss.kind_id);
-        match boss.kind_id {
-            BOSS_ID_SLIME_KING | BOSS_ID_STONE_GOLEM => {
-                let ddx = px - boss.x;
-                let ddy = py - boss.y;
-                let dist = (ddx * ddx + ddy * ddy).sqrt().max(0.001);
-                let spd = bp.speed;
-                boss.x += (ddx / dist) * spd * dt;
-                boss.y += (ddy / dist) * spd * dt;
-            }
-            BOSS_ID_BAT_LORD => {
-                if boss.is_dashing {
-                    boss.x += boss.dash_vx * dt;
-                    boss.y += boss.dash_vy * dt;
-                    boss.dash_timer -= dt;
-                    if boss.dash_timer <= 0.0 {
-                        boss.is_dashing = false;
-                        boss.invincible = false;
-                        boss.invincible_timer = 0.0;
-                    }
-                } else {
-                    let ddx = px - boss.x;
-                    let ddy = py - boss.y;
-                    let dist = (ddx * ddx + ddy * ddy).sqrt().max(0.001);
-                    boss.x += (ddx / dist) * bp.speed * dt;
-                    boss.y += (ddy / dist) * bp.speed * dt;
-                }
+        // フェーズ移行（HP 割合 or 経過時間）。1 フレームに進むのは 1 フェーズまで
+        boss.phase_elapsed += dt;
+        let hp_ratio = boss.hp / boss.max_hp;
+        if let Some(next) = bp.phases.get(boss.phase + 1) {
+            if next.should_enter(hp_ratio, boss.phase_elapsed) {
+                boss.enter_phase(boss.phase + 1);
+                eff.entered_phase = Some(boss.phase);
             }
-            _ => {}
         }
-        boss.x = boss.x.clamp(bp.radius, SCREEN_WIDTH - bp.radius);
-        boss.y = boss.y.clamp(bp.radius, SCREEN_HEIGHT - bp.radius);
+        let phase = boss.current_phase();
 
-        // 特殊行動タイマー
-        boss.phase_timer -= dt;
-        if boss.phase_timer <= 0.0 {
-            boss.phase_timer = bp.special_interval;
-            match boss.kind_id {
-                BOSS_ID_SLIME_KING => {
-                    eff.spawn_slimes = true;
-                    eff.special_x = boss.x;
-                    eff.special_y = boss.y;
-                }
-                BOSS_ID_BAT_LORD => {
-                    let ddx = px - boss.x;
-                    let ddy = py - boss.y;
-                    let dist = (ddx * ddx + ddy * ddy).sqrt().max(0.001);
-                    boss.dash_vx = (ddx / dist) * 500.0;
-                    boss.dash_vy = (ddy / dist) * 500.0;
-                    boss.is_dashing = true;
-                    boss.dash_timer = 0.6;
-                    boss.invincible = true;
-                    boss.invincible_timer = 0.6;
-                    eff.bat_dash = true;
-                    eff.special_x = boss.x;
-                    eff.special_y = boss.y;
+        // 移動（突進中は突進を優先）
+        let ddx = px - boss.x;
+        let ddy = py - boss.y;
+        let dist = (ddx * ddx + ddy * ddy).sqrt().max(0.001);
+        let spd = bp.speed * phase.speed_multiplier;
+        if boss.is_dashing {
+            boss.x += boss.dash_vx * dt;
+            boss.y += boss.dash_vy * dt;
+            boss.dash_timer -= dt;
+            if boss.dash_timer <= 0.0 {
+                boss.is_dashing = false;
+                boss.invincible = false;
+                boss.invincible_timer = 0.0;
+            }
+        } else {
+            match phase.movement {
+                BossMovement::Chase => {
+                    boss.x += (ddx / dist) * spd * dt;
+                    boss.y += (ddy / dist) * spd * dt;
                 }
-                BOSS_ID_STONE_GOLEM => {
-                    eff.spawn_rocks = true;
-                    eff.special_x = boss.x;
-                    eff.special_y = boss.y;
+                BossMovement::Stationary => {}
+                BossMovement::Orbit { radius } => {
+                    // プレイヤーから見たボス方向 (ux, uy)。半径の誤差を詰めつつ接線方向へ回る
+                    let (ux, uy) = (-ddx / dist, -ddy / dist);
+                    let radial = (radius - dist).clamp(-spd, spd);
+                    let vx = ux * radial - uy * spd;
+                    let vy = uy * radial + ux * spd;
+                    let vlen = (vx * vx + vy * vy).sqrt().max(0.001);
+                    let scale = spd.min(vlen) / vlen;
+                    boss.x += vx * scale * dt;
+                    boss.y += vy * scale * dt;
                 }
-                _ => {}
             }
         }
+        (boss.x, boss.y) = bounds.clamp_center(boss.x, boss.y, bp.radius);
+
+        // 攻撃タイマー（cooldown ごとに発動）
+        for (slot, timer) in phase.attacks.iter().zip(boss.attack_timers.iter_mut()) {
+            *timer -= dt;
+            if *timer > 0.0 {
+                continue;
+            }
+            *timer = slot.cooldown;
+            if let BossAttack::Dash { speed, duration } = slot.attack {
+                boss.dash_vx = (ddx / dist) * speed;
+                boss.dash_vy = (ddy / dist) * speed;
+                boss.is_dashing = true;
+                boss.dash_timer = duration;
+                boss.invincible = true;
+                boss.invincible_timer = duration;
+            }
+            eff.attacks.push(slot.attack);
+        }
 
         // ボス vs プレイヤー接触ダメージ: フラグだけ立てる
-        let boss_r = bp.radius;
-        let hit_r = PLAYER_RADIUS + boss_r;
-        let ddx = px - boss.x;
-        let ddy = py - boss.y;
-        if ddx * ddx + ddy * ddy < hit_r * hit_r {
+        let hit_r = PLAYER_RADIUS + bp.radius;
+        let cdx = px - boss.x;
+        let cdy = py - boss.y;
+        if cdx * cdx + cdy * cdy < hit_r * hit_r {
             eff.hurt_player = true;
-            eff.hurt_x = px;
-            eff.hurt_y = py;
             eff.boss_damage = bp.damage_per_sec;
         }
 
         // 弾丸 vs ボス: ヒット判定に必要なデータをコピー
         eff.boss_invincible = boss.invincible;
         eff.boss_r = bp.radius;
-        eff.boss_exp_reward = bp.exp_reward;
         eff.boss_x = boss.x;
         eff.boss_y = boss.y;
     }
 
     // 弾丸 vs ボス: boss 借用の外で処理
-    if w.boss.is_some() && !eff.boss_invincible {
+    if !eff.boss_invincible {
         let bullet_len = w.bullets.positions_x.len();
         for bi in 0..bullet_len {
             if !w.bullets.alive[bi] {
                 continue;
             }
             let dmg = w.bullets.damage[bi];
-            if dmg == 0 {
+            if dmg == 0 || w.bullets.hostile[bi] {
                 continue;
             }
-            let bx = w.bullets.positions_x[bi];
-            let by = w.bullets.positions_y[bi];
+            // 移動線分で判定する（速い弾がボスをすり抜けないように）
+            let (x0, y0) = (w.bullets.prev_x[bi], w.bullets.prev_y[bi]);
+            let dx = w.bullets.positions_x[bi] - x0;
+            let dy = w.bullets.positions_y[bi] - y0;
             let hit_r2 = BULLET_RADIUS + eff.boss_r;
-            let ddx2 = bx - eff.boss_x;
-            let ddy2 = by - eff.boss_y;
-            if ddx2 * ddx2 + ddy2 * ddy2 < hit_r2 * hit_r2 {
+            if sweep_point_circle(x0 - eff.boss_x, y0 - eff.boss_y, dx, dy, hit_r2).is_some() {
                 eff.bullet_hits
                     .push((bi, dmg as f32, !w.bullets.piercing[bi]));
             }
         }
-        // ダメージ適用
-        let total_dmg: f32 = eff.bullet_hits.iter().map(|&(_, d, _)| d).sum();
-        if total_dmg > 0.0 {
-            if let Some(ref mut boss) = w.boss {
-                boss.hp -= total_dmg;
-                if boss.hp <= 0.0 {
-                    eff.boss_killed = true;
-                    eff.exp_reward = eff.boss_exp_reward;
-                    eff.kill_x = boss.x;
-                    eff.kill_y = boss.y;
-                }
-            }
-        }
     }
 
     // フェーズ2: boss 借用を解放してから副作用を適用
-    if eff.hurt_player {
-        if w.player.invincible_timer <= 0.0 && w.player.hp > 0.0 {
-            let dmg = eff.boss_damage * dt;
-            w.player.hp = (w.player.hp - dmg).max(0.0);
-            w.player.invincible_timer = INVINCIBLE_DURATION;
-            w.frame_events.push(FrameEvent::PlayerDamaged { damage: dmg });
-            w.particles
-                .emit(eff.hurt_x, eff.hurt_y, 8, [1.0, 0.15, 0.15, 1.0]);
-        }
+    if let Some(phase) = eff.entered_phase {
+        w.frame_events.push(FrameEvent::BossPhaseChanged { boss_id, phase: phase as u32 });
+        w.particles
+            .emit(eff.boss_x, eff.boss_y, 30, [1.0, 0.3, 0.3, 1.0]);
+    }
+
+    if eff.hurt_player && w.player.invincible_timer <= 0.0 && w.player.hp > 0.0 {
+        let dmg = w.player_stats.mitigate(eff.boss_damage * dt);
+        w.player.hp = (w.player.hp - dmg).max(0.0);
+        w.player.invincible_timer = INVINCIBLE_DURATION;
+        w.frame_events.push(FrameEvent::PlayerDamaged { damage: dmg });
+        w.particles
+            .emit(px, py, 8, [1.0, 0.15, 0.15, 1.0]);
     }
 
-    // 弾丸ヒットパーティクル & 弾丸消去
+    // 弾丸ヒットパーティクル & ダメージ適用 & 弾丸消去（撃破は apply_damage が処理する）
     if !eff.bullet_hits.is_empty() {
         w.particles.emit(eff.boss_x, eff.boss_y, 4, [1.0, 0.8, 0.2, 1.0]);
-        for &(bi, _, kill_bullet) in &eff.bullet_hits {
+        for &(bi, dmg, kill_bullet) in &eff.bullet_hits {
+            let weapon_k = w.bullets.weapon_kind[bi];
+            apply_damage(w, DamageTarget::Boss(boss_id), dmg, weapon_k);
             if kill_bullet {
                 w.bullets.kill(bi);
             }
         }
     }
 
-    // 特殊行動の副作用
-    if eff.spawn_slimes {
-        let positions: Vec<(f32, f32)> = (0..8)
-            .map(|i| {
-                let angle = i as f32 * std::f32::consts::TAU / 8.0;
-                (
-                    eff.special_x + angle.cos() * 120.0,
-                    eff.special_y + angle.sin() * 120.0,
-                )
-            })
-            .collect();
-        w.enemies.spawn(&positions, 0); // Slime
-        w.particles
-            .emit(eff.special_x, eff.special_y, 16, [0.2, 1.0, 0.2, 1.0]);
+    // 攻撃の副作用（撃破されていても、このフレームに発動した分は出す）
+    let aim = (py - eff.boss_y).atan2(px - eff.boss_x);
+    for attack in eff.attacks {
+        perform_attack(w, attack, eff.boss_x, eff.boss_y, aim);
     }
-    if eff.spawn_rocks {
-        for (dx_dir, dy_dir) in [
-            (1.0_f32, 0.0_f32),
-            (-1.0, 0.0),
-            (0.0, 1.0),
-            (0.0, -1.0),
-        ] {
-            w.bullets.spawn_ex(
-                eff.special_x,
-                eff.special_y,
-                dx_dir * 200.0,
-                dy_dir * 200.0,
-                50,
-                3.0,
-                false,
-                BULLET_KIND_ROCK,
-                0,
-            );
+}
+
+/// 発動した攻撃をワールドに反映する（Dash の移動は update_one_boss 側で設定済み）。
+/// aim はボスからプレイヤーへの角度（ラジアン）
+fn perform_attack(w: &mut GameWorldInner, attack: BossAttack, x: f32, y: f32, aim: f32) {
+    match attack {
+        BossAttack::Summon { enemy, count, radius } => {
+            if enemy as usize >= entity_params_counts().0 {
+                return;
+            }
+            let positions = get_summon_positions(w, enemy, count as usize, x, y, radius);
+            w.enemies.spawn(&positions, enemy);
+            w.particles.emit(x, y, 16, [0.2, 1.0, 0.2, 1.0]);
+        }
+        BossAttack::Dash { .. } => {
+            w.particles.emit(x, y, 12, [0.8, 0.2, 1.0, 1.0]);
+        }
+        BossAttack::Pattern(pattern) => {
+            w.bullets.fire_pattern(&pattern, x, y, aim, &mut w.rng.ai, BULLET_KIND_ROCK);
+            w.particles.emit(x, y, 10, [0.6, 0.6, 0.6, 1.0]);
         }
-        w.particles
-            .emit(eff.special_x, eff.special_y, 10, [0.6, 0.6, 0.6, 1.0]);
-    }
-    if eff.bat_dash {
-        w.particles
-            .emit(eff.special_x, eff.special_y, 12, [0.8, 0.2, 1.0, 1.0]);
     }
-    if eff.boss_killed {
-        let boss_k = w.boss.as_ref().map(|b| b.kind_id).unwrap_or(0);
-        w.kill_count += 1;
-        w.score_popups
-            .push((eff.kill_x, eff.kill_y - 20.0, eff.exp_reward * 2, 0.8));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::world::BossState;
+    use game_core::boss::BossPhase;
+    use game_core::entity_params::{install_entity_params, EntityParamsTable, BOSS_ID_SLIME_KING};
+    use std::borrow::Cow;
+
+    fn phase_changes(w: &GameWorldInner) -> Vec<(u32, u32)> {
         w.frame_events
-            .push(FrameEvent::BossDefeated { boss_kind: boss_k });
-        w.score += eff.exp_reward * 2;
-        w.exp += eff.exp_reward;
-        if !w.level_up_pending {
-            let required = exp_required_for_next(w.level);
-            if w.exp >= required {
-                let new_lv = w.level + 1;
-                w.level_up_pending = true;
-                w.weapon_choices = compute_weapon_choices(w);
-                w.frame_events.push(FrameEvent::LevelUp { new_level: new_lv });
-            }
-        }
-        w.particles
-            .emit(eff.kill_x, eff.kill_y, 40, [1.0, 0.5, 0.0, 1.0]);
-        for _ in 0..10 {
-            let ox = (w.rng.next_f32() - 0.5) * 200.0;
-            let oy = (w.rng.next_f32() - 0.5) * 200.0;
-            w.items
-                .spawn(eff.kill_x + ox, eff.kill_y + oy, ItemKind::Gem, eff.exp_reward / 10);
+            .iter()
+            .filter_map(|e| match e {
+                FrameEvent::BossPhaseChanged { boss_id, phase } => Some((*boss_id, *phase)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn dropping_below_hp_threshold_enters_next_phase() {
+        let mut w = GameWorldInner::new(1);
+        w.bosses.push(BossState::new(4, BOSS_ID_SLIME_KING, 300.0, 300.0));
+
+        w.bosses[0].hp = w.bosses[0].max_hp * 0.6;
+        update_boss(&mut w, 0.016);
+        assert!(phase_changes(&w).is_empty());
+
+        w.bosses[0].hp = w.bosses[0].max_hp * 0.4;
+        update_boss(&mut w, 0.016);
+
+        assert_eq!(phase_changes(&w), [(4, 1)]);
+        let boss = &w.bosses[0];
+        assert_eq!(boss.phase, 1);
+        // 新しいフェーズの攻撃タイマーで始め、移行したフレームの分だけ進んでいる
+        let timers: Vec<f32> = boss.current_phase().attacks.iter().map(|a| a.cooldown - 0.016).collect();
+        assert_eq!(boss.attack_timers, timers);
+    }
+
+    #[test]
+    fn summon_avoids_obstacles_and_world_edges() {
+        let mut w = GameWorldInner::new(1);
+        // 左上隅のボスの右隣に障害物を置き、召喚円の一部をワールド外・障害物にかける
+        let (bx, by) = (60.0, 60.0);
+        w.collision.rebuild_static(&[(bx + 120.0, by, 40.0, 0)]);
+        let attack = BossAttack::Summon { enemy: 0, count: 8, radius: 120.0 };
+
+        perform_attack(&mut w, attack, bx, by, 0.0);
+
+        assert_eq!(w.enemies.count, 8);
+        let mut buf = Vec::new();
+        for i in 0..w.enemies.len() {
+            let r = w.enemies.radius(i);
+            let (x, y) = (w.enemies.positions_x[i] + r, w.enemies.positions_y[i] + r);
+            assert!(w.bounds.contains_circle(x, y, r), "({x}, {y}) is outside the world");
+            w.collision.query_static_nearby_into(x, y, r, &mut buf);
+            assert!(buf.is_empty(), "({x}, {y}) overlaps an obstacle");
         }
-        w.boss = None;
+    }
+
+    /// 組み込みテーブルに、1 秒ごとにフェーズが進むボスを 1 体足して登録し、その ID を返す。
+    /// 既存 ID の値は変えないため、並列に走る他のテストには影響しない
+    fn install_timed_boss() -> u8 {
+        let mut table = EntityParamsTable::default();
+        let mut boss = table.bosses[BOSS_ID_SLIME_KING as usize].clone();
+        let calm = BossPhase { attacks: Cow::Borrowed(&[]), tint: None, ..boss.phases[0].clone() };
+        let timed = BossPhase { after_secs: Some(1.0), ..calm.clone() };
+        boss.phases = Cow::Owned(vec![calm, timed.clone(), timed]);
+        table.bosses.push(boss);
+        let kind_id = (table.bosses.len() - 1) as u8;
+        install_entity_params(table).unwrap();
+        kind_id
+    }
+
+    #[test]
+    fn phase_timer_enters_one_phase_per_frame() {
+        let kind_id = install_timed_boss();
+        let mut w = GameWorldInner::new(1);
+        w.bosses.push(BossState::new(0, kind_id, 300.0, 300.0));
+
+        update_boss(&mut w, 0.5);
+        assert!(phase_changes(&w).is_empty());
+
+        // 2 フェーズ分の時間が経っていても 1 フレームで進むのは 1 フェーズまで
+        update_boss(&mut w, 2.5);
+        assert_eq!(phase_changes(&w), [(0, 1)]);
+
+        update_boss(&mut w, 1.0);
+        assert_eq!(phase_changes(&w), [(0, 1), (0, 2)]);
+        assert_eq!(w.bosses[0].phase, 2);
     }
 }
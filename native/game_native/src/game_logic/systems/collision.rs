@@ -1,6 +1,20 @@
 use crate::world::GameWorldInner;
 use game_core::entity_params::EnemyParams;
 
+/// 敵をワールドの内側に収める（ワールド外からスポーンした敵も境界に沿って入ってくる）
+pub(crate) fn clamp_enemies_to_bounds(w: &mut GameWorldInner) {
+    let bounds = w.bounds;
+    for i in 0..w.enemies.len() {
+        if !w.enemies.alive[i] {
+            continue;
+        }
+        let size = w.enemies.radius(i) * 2.0;
+        let (x, y) = bounds.clamp_top_left(w.enemies.positions_x[i], w.enemies.positions_y[i], size);
+        w.enemies.positions_x[i] = x;
+        w.enemies.positions_y[i] = y;
+    }
+}
+
 /// 1.5.2: 敵が障害物と重なっている場合に押し出す（Ghost はスキップ）
 pub(crate) fn resolve_obstacles_enemy(w: &mut GameWorldInner) {
     let collision = &w.collision;
@@ -9,21 +23,12 @@ pub(crate) fn resolve_obstacles_enemy(w: &mut GameWorldInner) {
         if !w.enemies.alive[i] || EnemyParams::passes_through_obstacles(w.enemies.kind_ids[i]) {
             continue;
         }
-        let r = EnemyParams::get(w.enemies.kind_ids[i]).radius;
+        let r = w.enemies.radius(i);
         let cx = w.enemies.positions_x[i] + r;
         let cy = w.enemies.positions_y[i] + r;
-        collision.query_static_nearby_into(cx, cy, r, buf);
-        for &idx in buf.iter() {
-            if let Some(o) = collision.obstacles.get(idx) {
-                let dx = cx - o.x;
-                let dy = cy - o.y;
-                let dist = (dx * dx + dy * dy).sqrt().max(0.001);
-                let overlap = (r + o.radius) - dist;
-                if overlap > 0.0 {
-                    w.enemies.positions_x[i] += (dx / dist) * overlap;
-                    w.enemies.positions_y[i] += (dy / dist) * overlap;
-                }
-            }
-        }
+        // 敵は数が多いので 1 パスのみ（残っためり込みは次フレームで解消する）
+        let (dx, dy) = collision.resolve_circle(cx, cy, r, 1, buf);
+        w.enemies.positions_x[i] += dx;
+        w.enemies.positions_y[i] += dy;
     }
 }
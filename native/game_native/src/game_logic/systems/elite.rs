@@ -0,0 +1,23 @@
+use crate::world::GameWorldInner;
+use crate::BULLET_KIND_ROCK;
+use game_core::entity_params::EliteParams;
+
+/// エリートの弾幕攻撃（EliteParams::attack）。shot_timers が切れたエリートがプレイヤーへ撃つ
+pub(crate) fn update_elite_attacks(w: &mut GameWorldInner, dt: f32, px: f32, py: f32) {
+    let Some(attack) = EliteParams::get().attack else { return };
+    for i in 0..w.enemies.len() {
+        if !w.enemies.alive[i] || !w.enemies.is_elite[i] {
+            continue;
+        }
+        w.enemies.shot_timers[i] -= dt;
+        if w.enemies.shot_timers[i] > 0.0 {
+            continue;
+        }
+        w.enemies.shot_timers[i] = attack.cooldown;
+        let r = w.enemies.radius(i);
+        let ex = w.enemies.positions_x[i] + r;
+        let ey = w.enemies.positions_y[i] + r;
+        let aim = (py - ey).atan2(px - ex);
+        w.bullets.fire_pattern(&attack.pattern, ex, ey, aim, &mut w.rng.ai, BULLET_KIND_ROCK);
+    }
+}
@@ -0,0 +1,122 @@
+use crate::world::{FrameEvent, GameWorldInner};
+use game_core::constants::PLAYER_RADIUS;
+use game_core::entity_params::EvolutionParams;
+use game_core::weapon::{WeaponSlot, MAX_WEAPON_LEVEL};
+
+/// 最大 Lv の武器のうち、対になるパッシブを所持しているものを 1 つ進化させる。
+/// ボス撃破時・宝箱取得時に呼ぶ。進化した場合は true
+pub(crate) fn try_evolve_weapon(w: &mut GameWorldInner) -> bool {
+    let candidate = w.weapon_slots.iter().enumerate().find_map(|(si, slot)| {
+        if slot.level < MAX_WEAPON_LEVEL {
+            return None;
+        }
+        let (passive_id, evolved_id) = EvolutionParams::for_weapon(slot.kind_id)?;
+        let has_passive = w.passive_slots.iter().any(|p| p.kind_id == passive_id);
+        // 進化後の挙動が登録されていない武器には置き換えない
+        (has_passive && w.weapon_registry.get(evolved_id).is_some()).then_some((si, evolved_id))
+    });
+    let Some((si, evolved_id)) = candidate else { return false };
+
+    let from_weapon = w.weapon_slots[si].kind_id;
+    // 進化後もレベルは最大のまま（以降のレベルアップ選択肢には出ない）
+    w.weapon_slots[si] = WeaponSlot {
+        kind_id:        evolved_id,
+        level:          MAX_WEAPON_LEVEL,
+        cooldown_timer: 0.0,
+    };
+    w.frame_events.push(FrameEvent::WeaponEvolved { from_weapon, to_weapon: evolved_id });
+    let px = w.player.x + PLAYER_RADIUS;
+    let py = w.player.y + PLAYER_RADIUS;
+    w.particles.emit(px, py, 24, [1.0, 0.85, 0.3, 1.0]);
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_logic::damage::{apply_damage, DamageTarget};
+    use crate::game_logic::systems::items::update_items;
+    use crate::world::BossState;
+    use game_core::entity_params::{PassiveParams, WeaponParams};
+    use game_core::item::ItemKind;
+    use game_core::player_stats::PassiveSlot;
+
+    fn weapon(name: &str) -> u8 {
+        WeaponParams::find_id(name).unwrap()
+    }
+
+    fn passive(name: &str) -> u8 {
+        PassiveParams::find_id(name).unwrap()
+    }
+
+    /// magic_wand を level で持ち、passives を所持したワールド
+    fn world(level: u32, passives: &[&str]) -> GameWorldInner {
+        let mut w = GameWorldInner::new(1);
+        w.weapon_slots[0].level = level;
+        w.passive_slots = passives.iter().map(|&name| PassiveSlot::new(passive(name))).collect();
+        w
+    }
+
+    fn evolved(w: &GameWorldInner) -> bool {
+        w.frame_events
+            .iter()
+            .any(|e| matches!(e, FrameEvent::WeaponEvolved { .. }))
+    }
+
+    #[test]
+    fn boss_kill_evolves_a_max_level_weapon_with_its_passive() {
+        let mut w = world(MAX_WEAPON_LEVEL, &["spinach", "empty_tome"]);
+        let mut boss = BossState::new(0, 0, 500.0, 500.0);
+        boss.hp = 1.0;
+        w.bosses.push(boss);
+
+        assert!(apply_damage(&mut w, DamageTarget::Boss(0), 10.0, 0).killed);
+
+        assert_eq!(w.weapon_slots[0].kind_id, weapon("holy_wand"));
+        assert_eq!(w.weapon_slots[0].level, MAX_WEAPON_LEVEL);
+        assert!(w.frame_events.iter().any(|e| matches!(
+            e,
+            FrameEvent::WeaponEvolved { from_weapon, to_weapon }
+                if *from_weapon == weapon("magic_wand") && *to_weapon == weapon("holy_wand")
+        )));
+    }
+
+    #[test]
+    fn evolution_needs_max_level_and_the_paired_passive() {
+        for (level, passives) in [
+            (MAX_WEAPON_LEVEL - 1, &["empty_tome"][..]),
+            (MAX_WEAPON_LEVEL, &["spinach"][..]),
+            (MAX_WEAPON_LEVEL, &[][..]),
+        ] {
+            let mut w = world(level, passives);
+            assert!(!try_evolve_weapon(&mut w), "level {level}, passives {passives:?}");
+            assert_eq!(w.weapon_slots[0].kind_id, weapon("magic_wand"));
+            assert!(!evolved(&w));
+        }
+    }
+
+    #[test]
+    fn chest_evolves_instead_of_giving_score() {
+        let mut w = world(MAX_WEAPON_LEVEL, &["empty_tome"]);
+        let (px, py) = (w.player.x, w.player.y);
+        w.items.spawn(px, py, ItemKind::Chest, 100);
+
+        update_items(&mut w, 0.016, px, py);
+
+        assert_eq!(w.weapon_slots[0].kind_id, weapon("holy_wand"));
+        assert_eq!(w.score, 0);
+    }
+
+    #[test]
+    fn chest_without_evolution_turns_into_score() {
+        let mut w = world(MAX_WEAPON_LEVEL - 1, &["empty_tome"]);
+        let (px, py) = (w.player.x, w.player.y);
+        w.items.spawn(px, py, ItemKind::Chest, 100);
+
+        update_items(&mut w, 0.016, px, py);
+
+        assert_eq!(w.weapon_slots[0].kind_id, weapon("magic_wand"));
+        assert_eq!(w.score, 100);
+        assert!(!evolved(&w));
+    }
+}
@@ -1,3 +1,4 @@
+use super::evolution::try_evolve_weapon;
 use crate::world::{FrameEvent, GameWorldInner};
 use game_core::item::ItemKind;
 
@@ -26,8 +27,8 @@ pub(crate) fn update_items(w: &mut GameWorldInner, dt: f32, px: f32, py: f32) {
         }
     }
 
-    // 自動収集判定（通常: 60px、磁石中: 全画面）
-    let collect_r = if w.magnet_timer > 0.0 { 9999.0_f32 } else { 60.0_f32 };
+    // 自動収集判定（通常: player_stats.pickup_radius、磁石中: 全画面）
+    let collect_r = if w.magnet_timer > 0.0 { 9999.0_f32 } else { w.player_stats.pickup_radius };
     let collect_r_sq = collect_r * collect_r;
     let item_len = w.items.len();
     for i in 0..item_len {
@@ -54,6 +55,13 @@ pub(crate) fn update_items(w: &mut GameWorldInner, dt: f32, px: f32, py: f32) {
                     // 磁石パーティクル（黄）
                     w.particles.emit(px, py, 8, [1.0, 0.9, 0.2, 1.0]);
                 }
+                ItemKind::Chest => {
+                    // 進化できる武器が無ければスコアに換算する
+                    if !try_evolve_weapon(w) {
+                        w.score += w.items.value[i];
+                        w.particles.emit(px, py, 10, [1.0, 0.8, 0.25, 1.0]);
+                    }
+                }
             }
             w.frame_events
                 .push(FrameEvent::ItemPickup { item_kind: item_k as u8 });
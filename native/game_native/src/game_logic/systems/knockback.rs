@@ -0,0 +1,73 @@
+use crate::world::GameWorldInner;
+use game_core::constants::KNOCKBACK_DAMPING;
+use game_core::entity_params::WeaponParams;
+
+/// 武器の knockback / hit_stun で敵を (dir_x, dir_y) 方向へ吹き飛ばす（弾丸の進行方向など）
+pub(crate) fn knock_back_along(w: &mut GameWorldInner, ei: usize, dir_x: f32, dir_y: f32, weapon_id: u8) {
+    let wp = WeaponParams::get(weapon_id);
+    w.enemies.knock_back(ei, dir_x, dir_y, wp.knockback, wp.hit_stun);
+}
+
+/// 武器の knockback / hit_stun で敵を (src_x, src_y) から遠ざかる方向へ吹き飛ばす（Whip / Garlic 用）
+pub(crate) fn knock_back_from(w: &mut GameWorldInner, ei: usize, src_x: f32, src_y: f32, weapon_id: u8) {
+    let r = w.enemies.radius(ei);
+    let dir_x = w.enemies.positions_x[ei] + r - src_x;
+    let dir_y = w.enemies.positions_y[ei] + r - src_y;
+    knock_back_along(w, ei, dir_x, dir_y, weapon_id);
+}
+
+/// のけぞりタイマーを進め、ノックバック速度を減衰させる（Chase AI の前に呼ぶ）
+pub(crate) fn update_hit_stun(w: &mut GameWorldInner, dt: f32) {
+    let damping = (-KNOCKBACK_DAMPING * dt).exp();
+    let enemies = &mut w.enemies;
+    for i in 0..enemies.len() {
+        if !enemies.alive[i] || enemies.stun_timers[i] <= 0.0 {
+            continue;
+        }
+        enemies.stun_timers[i] = (enemies.stun_timers[i] - dt).max(0.0);
+        enemies.velocities_x[i] *= damping;
+        enemies.velocities_y[i] *= damping;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use game_core::entity_params::EnemyParams;
+
+    const SLIME: u8 = 0;
+    const GOLEM: u8 = 2;
+    const WHIP: u8 = 3;
+
+    #[test]
+    fn knockback_speed_is_divided_by_mass() {
+        let mut w = GameWorldInner::new(1);
+        w.enemies.spawn(&[(100.0, 100.0)], SLIME);
+        w.enemies.spawn(&[(300.0, 100.0)], GOLEM);
+        let wp = WeaponParams::get(WHIP);
+
+        for i in 0..2 {
+            knock_back_along(&mut w, i, 2.0, 0.0, WHIP);
+        }
+
+        for (i, kind_id) in [SLIME, GOLEM].into_iter().enumerate() {
+            assert_eq!(w.enemies.velocities_x[i], wp.knockback / EnemyParams::get(kind_id).mass);
+            assert_eq!(w.enemies.velocities_y[i], 0.0);
+            assert_eq!(w.enemies.stun_timers[i], wp.hit_stun);
+        }
+        assert!(w.enemies.velocities_x[1] < w.enemies.velocities_x[0], "重い敵ほど飛ばない");
+    }
+
+    #[test]
+    fn hit_stun_counts_down_and_damps_velocity() {
+        let mut w = GameWorldInner::new(1);
+        w.enemies.spawn(&[(100.0, 100.0)], SLIME);
+        knock_back_along(&mut w, 0, 1.0, 0.0, WHIP);
+        let (vx, stun) = (w.enemies.velocities_x[0], w.enemies.stun_timers[0]);
+
+        update_hit_stun(&mut w, 0.1);
+
+        assert!((w.enemies.stun_timers[0] - (stun - 0.1)).abs() < 1e-6);
+        assert!(w.enemies.velocities_x[0] < vx);
+    }
+}
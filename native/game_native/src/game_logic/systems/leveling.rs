@@ -1,38 +1,74 @@
 use crate::world::GameWorldInner;
-use game_core::entity_params::{
-    WEAPON_ID_AXE, WEAPON_ID_CROSS, WEAPON_ID_FIREBALL, WEAPON_ID_GARLIC, WEAPON_ID_LIGHTNING,
-    WEAPON_ID_MAGIC_WAND, WEAPON_ID_WHIP,
-};
+use game_core::entity_params::{EvolutionParams, PassiveParams, WeaponParams};
+use game_core::player_stats::MAX_PASSIVE_SLOTS;
+use game_core::weapon::{MAX_WEAPON_LEVEL, MAX_WEAPON_SLOTS};
 
-/// 1.7.5: レベルアップ時の武器選択肢を計算（未所持優先 → 低レベル順、Lv8 除外）
-pub(crate) fn compute_weapon_choices(w: &GameWorldInner) -> Vec<String> {
-    const ALL: &[(&str, u8)] = &[
-        ("magic_wand", WEAPON_ID_MAGIC_WAND),
-        ("axe", WEAPON_ID_AXE),
-        ("cross", WEAPON_ID_CROSS),
-        ("whip", WEAPON_ID_WHIP),
-        ("fireball", WEAPON_ID_FIREBALL),
-        ("lightning", WEAPON_ID_LIGHTNING),
-        ("garlic", WEAPON_ID_GARLIC),
-    ];
+/// レベルアップ時の選択肢の数
+const CHOICE_COUNT: usize = 3;
+/// 選択肢のうちパッシブアイテムに割り当てる枠（候補が足りなければ武器で埋める）
+const PASSIVE_CHOICE_COUNT: usize = 1;
 
-    let mut choices: Vec<(i32, String)> = ALL
-        .iter()
-        .filter_map(|(name, wid)| {
+/// 1.7.5: レベルアップ時の選択肢を計算（未所持優先 → 低レベル順、最大 Lv 除外）
+/// 候補は weapon_registry に挙動が登録されている武器と、パッシブアイテム
+/// （どちらもスロットが埋まっていれば所持中のもののみ）。
+/// 進化後の武器と、進化後の形を所持している進化元の武器は候補に含めない。
+/// 武器 2 + パッシブ 1 を基本とし、片方の候補が足りない分はもう片方で埋める。
+pub(crate) fn compute_weapon_choices(w: &GameWorldInner) -> Vec<String> {
+    let weapon_slots_full = w.weapon_slots.len() >= MAX_WEAPON_SLOTS;
+    let mut weapons: Vec<(i32, String)> = w
+        .weapon_registry
+        .weapon_ids()
+        .filter(|&wid| {
+            !EvolutionParams::is_evolved(wid)
+                && !w.weapon_slots.iter().any(|s| EvolutionParams::base_of(s.kind_id) == Some(wid))
+        })
+        .filter_map(|wid| {
             let lv = w
                 .weapon_slots
                 .iter()
-                .find(|s| s.kind_id == *wid)
+                .find(|s| s.kind_id == wid)
                 .map(|s| s.level)
                 .unwrap_or(0);
-            if lv >= 8 {
+            if lv >= MAX_WEAPON_LEVEL || (weapon_slots_full && lv == 0) {
                 return None;
             }
-            let sort_key = if lv == 0 { -1i32 } else { lv as i32 };
-            Some((sort_key, (*name).to_string()))
+            Some((choice_sort_key(lv), WeaponParams::get(wid).name.to_string()))
         })
         .collect();
 
-    choices.sort_by_key(|(k, _)| *k);
-    choices.into_iter().take(3).map(|(_, n)| n).collect()
+    let passive_slots_full = w.passive_slots.len() >= MAX_PASSIVE_SLOTS;
+    let mut passives: Vec<(i32, String)> = (0..PassiveParams::count() as u8)
+        .filter_map(|pid| {
+            let pp = PassiveParams::get(pid);
+            let lv = w
+                .passive_slots
+                .iter()
+                .find(|s| s.kind_id == pid)
+                .map(|s| s.level)
+                .unwrap_or(0);
+            if lv >= pp.max_level || (passive_slots_full && lv == 0) {
+                return None;
+            }
+            Some((choice_sort_key(lv), pp.name.to_string()))
+        })
+        .collect();
+
+    weapons.sort_by_key(|(k, _)| *k);
+    passives.sort_by_key(|(k, _)| *k);
+
+    let passive_n = PASSIVE_CHOICE_COUNT
+        .max(CHOICE_COUNT.saturating_sub(weapons.len()))
+        .min(passives.len());
+    let weapon_n = (CHOICE_COUNT - passive_n).min(weapons.len());
+    weapons
+        .into_iter()
+        .take(weapon_n)
+        .chain(passives.into_iter().take(passive_n))
+        .map(|(_, n)| n)
+        .collect()
+}
+
+/// 未所持（Lv0）を最優先し、所持中は低レベル順
+fn choice_sort_key(lv: u32) -> i32 {
+    if lv == 0 { -1 } else { lv as i32 }
 }
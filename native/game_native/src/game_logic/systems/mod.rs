@@ -1,8 +1,12 @@
 pub(super) mod boss;
 pub(super) mod collision;
 pub(super) mod effects;
+pub(super) mod elite;
+pub(super) mod evolution;
 pub(super) mod items;
+pub(super) mod knockback;
 pub(super) mod leveling;
 pub(super) mod projectiles;
 pub(super) mod spawn;
+pub(super) mod status;
 pub(super) mod weapons;
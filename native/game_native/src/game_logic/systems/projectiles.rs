@@ -1,9 +1,28 @@
-use super::leveling::compute_weapon_choices;
+use super::knockback::knock_back_along;
+use super::status::apply_on_hit_status;
+use crate::game_logic::damage::{apply_damage, DamageTarget};
 use crate::world::{FrameEvent, GameWorldInner};
-use game_core::constants::{BULLET_RADIUS, MAP_HEIGHT, MAP_WIDTH};
-use game_core::entity_params::EnemyParams;
-use game_core::item::ItemKind;
-use game_core::util::exp_required_for_next;
+use game_core::constants::{BULLET_RADIUS, INVINCIBLE_DURATION, PLAYER_RADIUS};
+use game_core::entity_params::{BossParams, EnemyParams};
+use game_core::physics::collider::sweep_point_circle;
+
+/// 弾丸 bi の移動線分（始点と移動量）
+fn bullet_segment(w: &GameWorldInner, bi: usize) -> (f32, f32, f32, f32) {
+    let (x0, y0) = (w.bullets.prev_x[bi], w.bullets.prev_y[bi]);
+    (x0, y0, w.bullets.positions_x[bi] - x0, w.bullets.positions_y[bi] - y0)
+}
+
+/// 移動線分 (x0, y0) + t * (dx, dy) が無敵でないボスに最初に当たる時刻（当たらなければ f32::INFINITY）
+fn first_boss_hit(w: &GameWorldInner, x0: f32, y0: f32, dx: f32, dy: f32) -> f32 {
+    w.bosses
+        .iter()
+        .filter(|boss| !boss.invincible)
+        .filter_map(|boss| {
+            let hit_r = BULLET_RADIUS + BossParams::get(boss.kind_id).radius;
+            sweep_point_circle(x0 - boss.x, y0 - boss.y, dx, dy, hit_r)
+        })
+        .fold(f32::INFINITY, f32::min)
+}
 
 pub(crate) fn update_projectiles_and_enemy_hits(w: &mut GameWorldInner, dt: f32) {
     // 弾丸を移動・寿命更新
@@ -12,6 +31,10 @@ pub(crate) fn update_projectiles_and_enemy_hits(w: &mut GameWorldInner, dt: f32)
         if !w.bullets.alive[i] {
             continue;
         }
+        let x0 = w.bullets.positions_x[i];
+        let y0 = w.bullets.positions_y[i];
+        w.bullets.prev_x[i] = x0;
+        w.bullets.prev_y[i] = y0;
         w.bullets.positions_x[i] += w.bullets.velocities_x[i] * dt;
         w.bullets.positions_y[i] += w.bullets.velocities_y[i] * dt;
         w.bullets.lifetime[i] -= dt;
@@ -19,95 +42,159 @@ pub(crate) fn update_projectiles_and_enemy_hits(w: &mut GameWorldInner, dt: f32)
             w.bullets.kill(i);
             continue;
         }
-        // 1.5.2: 障害物に当たったら弾を消す
+        // 1.5.2: 障害物に当たったら弾を消す。移動線分で判定し、速い弾が薄い障害物をすり抜けないようにする
         let bx = w.bullets.positions_x[i];
         let by = w.bullets.positions_y[i];
-        w.collision
-            .query_static_nearby_into(bx, by, BULLET_RADIUS, &mut w.obstacle_query_buf);
-        if !w.obstacle_query_buf.is_empty() {
-            w.bullets.kill(i);
+        if let Some(t) = w.collision.sweep_static(x0, y0, bx, by, BULLET_RADIUS, &mut w.obstacle_query_buf) {
+            // 接触点で止め、このフレームの敵・ボス・プレイヤーへの命中判定は接触点までの線分で行う。
+            // 寿命を 0 にしておき、次の更新で消す
+            w.bullets.positions_x[i] = x0 + (bx - x0) * t;
+            w.bullets.positions_y[i] = y0 + (by - y0) * t;
+            w.bullets.lifetime[i] = 0.0;
             continue;
         }
-        // 画面外に出た弾丸も消す
-        if bx < -100.0 || bx > MAP_WIDTH + 100.0 || by < -100.0 || by > MAP_HEIGHT + 100.0 {
+        // ワールド外に出た弾丸も消す
+        if !w.bounds.contains(bx, by, 100.0) {
             w.bullets.kill(i);
         }
     }
 
-    // 弾丸 vs 敵 衝突判定
-    let bullet_query_r = BULLET_RADIUS + 32.0_f32;
+    // 弾丸 vs 敵 衝突判定（移動線分に沿って当たる敵を接触時刻順に処理する）
+    let bullet_query_r = BULLET_RADIUS + EnemyParams::max_radius();
+    let mut hits: Vec<(f32, usize)> = Vec::new();
     for bi in 0..bullet_len {
         if !w.bullets.alive[bi] {
             continue;
         }
         let dmg = w.bullets.damage[bi];
-        // ダメージ 0 はエフェクト専用弾（Whip / Lightning）— 衝突判定をスキップ
-        if dmg == 0 {
+        // ダメージ 0 はエフェクト専用弾（Whip / Lightning）、敵陣営の弾は敵に当たらない
+        if dmg == 0 || w.bullets.hostile[bi] {
             continue;
         }
-        let bx = w.bullets.positions_x[bi];
-        let by = w.bullets.positions_y[bi];
+        let (x0, y0, dx, dy) = bullet_segment(w, bi);
         let piercing = w.bullets.piercing[bi];
 
-        let nearby = w.collision.dynamic.query_nearby(bx, by, bullet_query_r);
+        let reach = (dx * dx + dy * dy).sqrt() / 2.0 + bullet_query_r;
+        let nearby = w.collision.dynamic.query_nearby(x0 + dx / 2.0, y0 + dy / 2.0, reach);
+        hits.clear();
         for ei in nearby {
             if !w.enemies.alive[ei] {
                 continue;
             }
-            let kind_id = w.enemies.kind_ids[ei];
-            let ep = EnemyParams::get(kind_id);
-            let enemy_r = ep.radius;
-            let hit_r = BULLET_RADIUS + enemy_r;
+            let enemy_r = w.enemies.radius(ei);
+            let ex = w.enemies.positions_x[ei] + enemy_r;
+            let ey = w.enemies.positions_y[ei] + enemy_r;
+            if let Some(t) = sweep_point_circle(x0 - ex, y0 - ey, dx, dy, BULLET_RADIUS + enemy_r) {
+                hits.push((t, ei));
+            }
+        }
+        hits.sort_unstable_by(|a, b| a.0.total_cmp(&b.0));
+        if !piercing {
+            // 通常弾は最初に当たる 1 体だけ。ボスの方が手前なら敵には当てず、ボスの判定（update_boss）に回す
+            let boss_t = first_boss_hit(w, x0, y0, dx, dy);
+            hits.truncate(1);
+            hits.retain(|&(t, _)| t < boss_t);
+        }
+        for &(_, ei) in &hits {
+            let enemy_r = w.enemies.radius(ei);
             let ex = w.enemies.positions_x[ei] + enemy_r;
             let ey = w.enemies.positions_y[ei] + enemy_r;
-            let ddx = bx - ex;
-            let ddy = by - ey;
-            if ddx * ddx + ddy * ddy < hit_r * hit_r {
-                w.enemies.hp[ei] -= dmg as f32;
-                if w.enemies.hp[ei] <= 0.0 {
-                    let weapon_k = w.bullets.weapon_kind[bi];
-                    w.enemies.kill(ei);
-                    w.kill_count += 1;
-                    w.score_popups.push((ex, ey - 20.0, ep.exp_reward * 2, 0.8));
-                    w.frame_events.push(FrameEvent::EnemyKilled {
-                        enemy_kind: kind_id,
-                        weapon_kind: weapon_k,
-                    });
-                    w.score += ep.exp_reward * 2;
-                    w.exp += ep.exp_reward;
-                    if !w.level_up_pending {
-                        let required = exp_required_for_next(w.level);
-                        if w.exp >= required {
-                            let new_lv = w.level + 1;
-                            w.level_up_pending = true;
-                            w.weapon_choices = compute_weapon_choices(w);
-                            w.frame_events.push(FrameEvent::LevelUp { new_level: new_lv });
-                        }
-                    }
-                    w.particles.emit(ex, ey, 8, ep.particle_color);
-                    let roll = w.rng.next_u32() % 100;
-                    let (item_kind, item_value) = if roll < 2 {
-                        (ItemKind::Magnet, 0)
-                    } else if roll < 7 {
-                        (ItemKind::Potion, 20)
-                    } else {
-                        (ItemKind::Gem, ep.exp_reward)
-                    };
-                    w.items.spawn(ex, ey, item_kind, item_value);
+            let weapon_k = w.bullets.weapon_kind[bi];
+            let outcome = apply_damage(w, DamageTarget::Enemy(ei), dmg as f32, weapon_k);
+            if !outcome.killed {
+                let hit_color = if piercing {
+                    [1.0, 0.4, 0.0, 1.0]
                 } else {
-                    let hit_color = if piercing {
-                        [1.0, 0.4, 0.0, 1.0]
-                    } else {
-                        [1.0, 0.9, 0.3, 1.0]
-                    };
-                    w.particles.emit(ex, ey, 3, hit_color);
-                }
-                // 貫通弾は消えない、通常弾は消す
-                if !piercing {
-                    w.bullets.kill(bi);
-                    break;
-                }
+                    [1.0, 0.9, 0.3, 1.0]
+                };
+                w.particles.emit(ex, ey, 3, hit_color);
+                apply_on_hit_status(w, ei, weapon_k);
+                // 弾の進行方向へ吹き飛ばす
+                let (bvx, bvy) = (w.bullets.velocities_x[bi], w.bullets.velocities_y[bi]);
+                knock_back_along(w, ei, bvx, bvy, weapon_k);
+            }
+            // 貫通弾は消えない、通常弾は消す
+            if !piercing {
+                w.bullets.kill(bi);
+                break;
             }
         }
     }
 }
+
+/// 敵陣営の弾 vs プレイヤー衝突判定（無敵時間中は弾がすり抜ける）
+pub(crate) fn update_hostile_projectile_hits(w: &mut GameWorldInner, px: f32, py: f32) {
+    if w.player.invincible_timer > 0.0 || w.player.hp <= 0.0 {
+        return;
+    }
+    let hit_r = BULLET_RADIUS + PLAYER_RADIUS;
+    for bi in 0..w.bullets.len() {
+        if !w.bullets.alive[bi] || !w.bullets.hostile[bi] {
+            continue;
+        }
+        let (x0, y0, dx, dy) = bullet_segment(w, bi);
+        if sweep_point_circle(x0 - px, y0 - py, dx, dy, hit_r).is_none() {
+            continue;
+        }
+        let dmg = w.player_stats.mitigate(w.bullets.damage[bi] as f32);
+        w.player.hp = (w.player.hp - dmg).max(0.0);
+        w.player.invincible_timer = INVINCIBLE_DURATION;
+        w.frame_events.push(FrameEvent::PlayerDamaged { damage: dmg });
+        w.particles.emit(px, py, 6, [1.0, 0.15, 0.15, 1.0]);
+        w.bullets.kill(bi);
+        // 被弾で無敵時間に入るため、同フレームの残りの弾はすり抜ける
+        break;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// プレイヤー中心に止まった敵弾を 1 発置いたワールド
+    fn world_with_hostile_bullet() -> (GameWorldInner, f32, f32) {
+        let mut w = GameWorldInner::new(1);
+        let (px, py) = (w.player.x, w.player.y);
+        w.bullets.spawn_hostile(px, py, 0.0, 0.0, 15, 1.0, 0);
+        (w, px, py)
+    }
+
+    #[test]
+    fn hostile_bullet_damages_the_player() {
+        let (mut w, px, py) = world_with_hostile_bullet();
+        let hp = w.player.hp;
+
+        update_hostile_projectile_hits(&mut w, px, py);
+
+        assert_eq!(w.player.hp, hp - w.player_stats.mitigate(15.0));
+        assert_eq!(w.player.invincible_timer, INVINCIBLE_DURATION);
+        assert!(!w.bullets.alive[0]);
+        assert!(matches!(w.frame_events[..], [FrameEvent::PlayerDamaged { .. }]));
+    }
+
+    #[test]
+    fn hostile_bullet_passes_through_an_invincible_player() {
+        let (mut w, px, py) = world_with_hostile_bullet();
+        w.player.invincible_timer = 0.2;
+        let hp = w.player.hp;
+
+        update_hostile_projectile_hits(&mut w, px, py);
+
+        assert_eq!(w.player.hp, hp);
+        assert!(w.bullets.alive[0]);
+        assert!(w.frame_events.is_empty());
+    }
+
+    #[test]
+    fn player_bullets_never_hit_the_player() {
+        let mut w = GameWorldInner::new(1);
+        let (px, py) = (w.player.x, w.player.y);
+        w.bullets.spawn(px, py, 0.0, 0.0, 15, 1.0, 0);
+        let hp = w.player.hp;
+
+        update_hostile_projectile_hits(&mut w, px, py);
+
+        assert_eq!(w.player.hp, hp);
+        assert!(w.bullets.alive[0]);
+    }
+}
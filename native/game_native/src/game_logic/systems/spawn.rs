@@ -1,12 +1,88 @@
 use crate::world::GameWorldInner;
 use game_core::constants::PLAYER_RADIUS;
-use game_core::util::spawn_position_around_player;
+use game_core::entity_params::{entity_params_counts, EliteParams, EnemyParams};
+use game_core::physics::spawn_placement::{SpawnBody, SpawnFormation, SpawnPlacer};
 
-/// プレイヤー周囲 800〜1200px の円周上にスポーン位置を生成（spawn_enemies / spawn_elite_enemy 共通）
-pub(crate) fn get_spawn_positions_around_player(w: &mut GameWorldInner, count: usize) -> Vec<(f32, f32)> {
+/// スポーンディレクター（set_spawn_schedule で設定した場合のみ）が決めた敵をプレイヤー周囲に出す
+pub(crate) fn update_spawn_director(w: &mut GameWorldInner, dt: f32) {
+    let Some(director) = w.spawn_director.as_mut() else { return };
+    let mut batches = Vec::new();
+    director.tick(w.elapsed_seconds, dt, w.enemies.count, &mut w.rng.spawn, &mut batches);
+
+    // スケジュール設定後に entity_params が差し替えられた場合に備え、未登録の敵 ID は飛ばす
+    let (enemy_kinds, _, _) = entity_params_counts();
+    let elite_hp = EliteParams::get().hp_multiplier;
+    for batch in batches {
+        let orders: Vec<_> = batch.orders.into_iter().filter(|o| (o.enemy as usize) < enemy_kinds).collect();
+        let bodies: Vec<_> = orders.iter().map(|o| spawn_body(o.enemy, o.elite)).collect();
+        let positions = get_formation_positions(w, batch.formation, &bodies);
+        for (order, &pos) in orders.iter().zip(&positions) {
+            if order.elite {
+                w.enemies.spawn_elite(&[pos], order.enemy, elite_hp);
+            } else {
+                w.enemies.spawn(&[pos], order.enemy);
+            }
+        }
+    }
+}
+
+/// 敵 1 体分の当たり判定（エリートは size_multiplier 倍。Ghost など障害物をすり抜ける敵は solid = false）
+fn spawn_body(kind_id: u8, elite: bool) -> SpawnBody {
+    let radius = EnemyParams::get(kind_id).radius;
+    SpawnBody {
+        radius: if elite { radius * EliteParams::get().size_multiplier } else { radius },
+        solid:  !EnemyParams::passes_through_obstacles(kind_id),
+    }
+}
+
+/// プレイヤー周囲 800〜1200px の円環上に count 体分のスポーン位置（左上座標）を生成
+/// （spawn_enemies / spawn_elite_enemy 共通）。障害物と重なる位置・ワールド外の位置は避ける
+pub(crate) fn get_spawn_positions_around_player(
+    w: &mut GameWorldInner,
+    kind_id: u8,
+    elite: bool,
+    count: usize,
+) -> Vec<(f32, f32)> {
+    let bodies = vec![spawn_body(kind_id, elite); count];
+    get_formation_positions(w, SpawnFormation::Scatter, &bodies)
+}
+
+/// ボスの召喚位置（左上座標）。中心 (x, y)・半径 radius の円周上に count 体を等間隔に並べ、
+/// 障害物・スポーン領域外・ワールド外にかかる個体だけ同じ円周上の空き位置に置き直す
+pub(crate) fn get_summon_positions(
+    w: &mut GameWorldInner,
+    kind_id: u8,
+    count: usize,
+    x: f32,
+    y: f32,
+    radius: f32,
+) -> Vec<(f32, f32)> {
+    let bodies = vec![spawn_body(kind_id, false); count];
+    get_formation_positions_at(w, x, y, Some(radius), SpawnFormation::Ring, &bodies)
+}
+
+/// bodies を formation に並べたスポーン位置（左上座標）をプレイヤー周囲に生成
+fn get_formation_positions(w: &mut GameWorldInner, formation: SpawnFormation, bodies: &[SpawnBody]) -> Vec<(f32, f32)> {
     let px = w.player.x + PLAYER_RADIUS;
     let py = w.player.y + PLAYER_RADIUS;
-    (0..count)
-        .map(|_| spawn_position_around_player(&mut w.rng, px, py, 800.0, 1200.0))
-        .collect()
+    get_formation_positions_at(w, px, py, None, formation, bodies)
+}
+
+/// 中心 (cx, cy) から dist の距離（None なら SpawnPlacer 既定の円環）に bodies を並べる
+fn get_formation_positions_at(
+    w: &mut GameWorldInner,
+    cx: f32,
+    cy: f32,
+    dist: Option<f32>,
+    formation: SpawnFormation,
+    bodies: &[SpawnBody],
+) -> Vec<(f32, f32)> {
+    let zones = w.tile_map.as_ref().map_or(&[][..], |m| &m.spawn_zones);
+    let mut placer = SpawnPlacer::new(&w.collision, w.bounds, cx, cy).with_zones(zones);
+    if let Some(d) = dist {
+        placer = placer.with_distance(d, d);
+    }
+    let mut centers = Vec::with_capacity(bodies.len());
+    placer.formation(formation, &mut w.rng.spawn, bodies, &mut w.obstacle_query_buf, &mut centers);
+    centers.iter().zip(bodies).map(|(&(x, y), b)| (x - b.radius, y - b.radius)).collect()
 }
@@ -0,0 +1,98 @@
+use crate::game_logic::damage::{apply_damage, DamageTarget};
+use crate::world::GameWorldInner;
+use game_core::entity_params::{EnemyParams, WeaponParams};
+
+/// 武器の on_hit 状態異常をヒットした敵に付与する（生存中の敵のみ）
+pub(crate) fn apply_on_hit_status(w: &mut GameWorldInner, ei: usize, weapon_id: u8) {
+    if !w.enemies.alive[ei] {
+        return;
+    }
+    if let Some(apply) = WeaponParams::get(weapon_id).on_hit {
+        w.enemies.statuses[ei].apply(&apply, weapon_id);
+    }
+}
+
+/// 状態異常の時間経過・継続ダメージ・移動速度の反映
+pub(crate) fn update_status_effects(w: &mut GameWorldInner, dt: f32) {
+    for i in 0..w.enemies.len() {
+        if !w.enemies.alive[i] {
+            continue;
+        }
+        let ep = EnemyParams::get(w.enemies.kind_ids[i]);
+        let tick = w.enemies.statuses[i].tick(dt);
+        // 凍結・鈍足は基本速度に倍率をかけて speeds を上書きする（切れたら元に戻る）
+        w.enemies.speeds[i] = ep.speed * w.enemies.statuses[i].speed_multiplier();
+
+        let Some((dmg, weapon_kind)) = tick else { continue };
+        let r = w.enemies.radius(i);
+        let hit_x = w.enemies.positions_x[i] + r;
+        let hit_y = w.enemies.positions_y[i] + r;
+        if !apply_damage(w, DamageTarget::Enemy(i), dmg, weapon_kind).killed {
+            if let Some(tint) = w.enemies.statuses[i].tint() {
+                w.particles.emit(hit_x, hit_y, 2, tint);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::world::FrameEvent;
+    use game_core::status_effect::{StatusApply, StatusKind, STATUS_TICK_INTERVAL};
+
+    const GOLEM: u8 = 2;
+    const FIREBALL: u8 = 4;
+
+    fn world_with_enemy(kind_id: u8) -> GameWorldInner {
+        let mut w = GameWorldInner::new(1);
+        w.enemies.spawn(&[(200.0, 200.0)], kind_id);
+        w
+    }
+
+    fn inflict(w: &mut GameWorldInner, kind: StatusKind, duration: f32, potency: f32) {
+        w.enemies.statuses[0].apply(&StatusApply { kind, duration, potency }, FIREBALL);
+    }
+
+    #[test]
+    fn burn_and_poison_tick_damage_every_interval() {
+        let mut w = world_with_enemy(GOLEM);
+        let max_hp = w.enemies.hp[0];
+        inflict(&mut w, StatusKind::Burn, 3.0, 6.0);
+        inflict(&mut w, StatusKind::Poison, 3.0, 4.0);
+
+        update_status_effects(&mut w, STATUS_TICK_INTERVAL / 2.0);
+        assert_eq!(w.enemies.hp[0], max_hp, "ティック間隔に満たなければダメージなし");
+
+        update_status_effects(&mut w, STATUS_TICK_INTERVAL / 2.0);
+        assert_eq!(w.enemies.hp[0], max_hp - (6.0 + 4.0) * STATUS_TICK_INTERVAL);
+    }
+
+    #[test]
+    fn lethal_tick_credits_the_inflicting_weapon() {
+        let mut w = world_with_enemy(GOLEM);
+        w.enemies.hp[0] = 1.0;
+        inflict(&mut w, StatusKind::Burn, 3.0, 6.0);
+
+        update_status_effects(&mut w, STATUS_TICK_INTERVAL);
+
+        assert!(!w.enemies.alive[0]);
+        assert!(w.frame_events.iter().any(|e| matches!(
+            e,
+            FrameEvent::EnemyKilled { enemy_kind: GOLEM, weapon_kind: FIREBALL, .. }
+        )));
+    }
+
+    #[test]
+    fn freeze_zeroes_speed_until_it_expires() {
+        let mut w = world_with_enemy(GOLEM);
+        let base = EnemyParams::get(GOLEM).speed;
+        inflict(&mut w, StatusKind::Freeze, 1.0, 0.0);
+
+        update_status_effects(&mut w, 0.1);
+        assert_eq!(w.enemies.speeds[0], 0.0);
+
+        update_status_effects(&mut w, 1.0);
+        assert_eq!(w.enemies.speeds[0], base);
+    }
+}
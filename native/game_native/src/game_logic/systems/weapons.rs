@@ -1,15 +1,30 @@
-use super::leveling::compute_weapon_choices;
+use super::knockback::knock_back_from;
+use super::status::apply_on_hit_status;
+use crate::game_logic::damage::{apply_damage, DamageTarget};
+use crate::game_logic::weapon_behavior::{
+    stat_upgrade_lines, FireContext, Targeting, WeaponBehavior,
+};
 use crate::game_logic::{find_nearest_enemy_spatial, find_nearest_enemy_spatial_excluding};
-use crate::world::{FrameEvent, GameWorldInner};
+use crate::world::GameWorldInner;
 use crate::{BULLET_KIND_LIGHTNING, BULLET_KIND_WHIP};
 use game_core::constants::{BULLET_LIFETIME, BULLET_SPEED, WEAPON_SEARCH_RADIUS};
 use game_core::entity_params::{
-    garlic_radius, lightning_chain_count, whip_range, EnemyParams, WeaponParams, WEAPON_ID_AXE,
-    WEAPON_ID_CROSS, WEAPON_ID_FIREBALL, WEAPON_ID_GARLIC, WEAPON_ID_LIGHTNING,
-    WEAPON_ID_MAGIC_WAND, WEAPON_ID_WHIP,
+    garlic_radius, lightning_chain_count, whip_range, WeaponParams,
 };
-use game_core::item::ItemKind;
-use game_core::util::exp_required_for_next;
+use std::sync::Arc;
+
+/// 組み込みの武器挙動（entity_params の name / behavior で ID に割り当てる）
+pub(crate) fn builtin_behaviors() -> Vec<Arc<dyn WeaponBehavior>> {
+    vec![
+        Arc::new(MagicWand),
+        Arc::new(Axe),
+        Arc::new(Cross),
+        Arc::new(Whip),
+        Arc::new(Fireball),
+        Arc::new(Lightning),
+        Arc::new(Garlic),
+    ]
+}
 
 pub(crate) fn update_weapon_attacks(w: &mut GameWorldInner, dt: f32, px: f32, py: f32) {
     // level_up_pending 中は発射を止めてゲームを一時停止する
@@ -36,436 +51,450 @@ pub(crate) fn update_weapon_attacks(w: &mut GameWorldInner, dt: f32, px: f32, py
             continue;
         }
 
-        let kind_id = w.weapon_slots[si].kind_id;
-        let wp = WeaponParams::get(kind_id);
-        // 1.2.2: レベルに応じたクールダウン・ダメージ・弾数を使用
-        let cd = w.weapon_slots[si].effective_cooldown();
-        let dmg = w.weapon_slots[si].effective_damage();
-        let level = w.weapon_slots[si].level;
-        let bcount = w.weapon_slots[si].bullet_count();
-
-        match kind_id {
-            WEAPON_ID_MAGIC_WAND => fire_magic_wand(w, si, px, py, dmg, bcount, wp.as_u8, cd),
-            WEAPON_ID_AXE => fire_axe(w, si, px, py, dmg, wp.as_u8, cd),
-            WEAPON_ID_CROSS => fire_cross(w, si, px, py, dmg, bcount, wp.as_u8, cd),
-            WEAPON_ID_WHIP => fire_whip(w, si, px, py, dmg, level, kind_id, wp.as_u8, cd, facing_angle),
-            WEAPON_ID_FIREBALL => fire_fireball(w, si, px, py, dmg, wp.as_u8, cd),
-            WEAPON_ID_LIGHTNING => {
-                fire_lightning(w, si, px, py, dmg, level, kind_id, wp.as_u8, cd)
+        let weapon_id = w.weapon_slots[si].kind_id;
+        let Some(behavior) = w.weapon_registry.get(weapon_id) else {
+            continue;
+        };
+        let target = match behavior.targeting() {
+            Targeting::Nearest { required } => {
+                let t = find_nearest_enemy_spatial(&w.collision, &w.enemies, px, py, WEAPON_SEARCH_RADIUS);
+                if required && t.is_none() {
+                    continue;
+                }
+                t
             }
-            WEAPON_ID_GARLIC => fire_garlic(w, si, px, py, dmg, level, kind_id, wp.as_u8, cd),
-            _ => {}
-        }
+            Targeting::Untargeted => None,
+        };
+
+        // 1.2.2: レベルに応じたクールダウン・ダメージ・弾数に、パッシブの能力値補正を掛ける
+        let slot = &w.weapon_slots[si];
+        let stats = &w.player_stats;
+        let cd = slot.effective_cooldown() * stats.cooldown_multiplier;
+        let ctx = FireContext {
+            slot_index:   si,
+            weapon_id,
+            level:        slot.level,
+            damage:       stats.scale_damage(slot.effective_damage()),
+            bullet_count: slot.bullet_count() + stats.extra_projectiles,
+            px,
+            py,
+            facing_angle,
+            target,
+        };
+        behavior.fire(w, &ctx);
+        w.weapon_slots[si].cooldown_timer = cd;
     }
 }
 
-fn fire_magic_wand(
-    w: &mut GameWorldInner,
-    si: usize,
-    px: f32,
-    py: f32,
-    dmg: i32,
-    bcount: usize,
-    weapon_kind: u8,
-    cd: f32,
-) {
-    if let Some(ti) = find_nearest_enemy_spatial(&w.collision, &w.enemies, px, py, WEAPON_SEARCH_RADIUS) {
-        let target_r = EnemyParams::get(w.enemies.kind_ids[ti]).radius;
-        let tx = w.enemies.positions_x[ti] + target_r;
-        let ty = w.enemies.positions_y[ti] + target_r;
-        let bdx = tx - px;
-        let bdy = ty - py;
-        // bcount 発同時発射（Lv3 で 2 発、Lv5 で 3 発）
+/// 照準点（敵中心）へ向かう角度
+fn aim_angle(w: &GameWorldInner, ti: usize, px: f32, py: f32) -> f32 {
+    let target_r = w.enemies.radius(ti);
+    let tx = w.enemies.positions_x[ti] + target_r;
+    let ty = w.enemies.positions_y[ti] + target_r;
+    (ty - py).atan2(tx - px)
+}
+
+/// base_angle を中心に spread 間隔で count 発分の角度を並べる（扇状の発射用）
+fn fan_angles(base_angle: f32, count: usize, spread: f32) -> impl Iterator<Item = f32> {
+    let half = (count as f32 - 1.0) / 2.0;
+    (0..count).map(move |i| base_angle + (i as f32 - half) * spread)
+}
+
+// ─── MagicWand ─────────────────────────────────────────────────
+
+/// 最近接敵に向けて扇状に弾を発射（Lv3 で 2 発、Lv5 で 3 発）
+pub(crate) struct MagicWand;
+
+impl WeaponBehavior for MagicWand {
+    fn name(&self) -> &'static str { "magic_wand" }
+
+    fn targeting(&self) -> Targeting { Targeting::Nearest { required: true } }
+
+    fn fire(&self, w: &mut GameWorldInner, ctx: &FireContext) {
+        let Some(ti) = ctx.target else { return };
         // 複数発は少しずつ角度をずらして扇状に発射
-        let base_angle = bdy.atan2(bdx);
+        let base_angle = aim_angle(w, ti, ctx.px, ctx.py);
         let spread = std::f32::consts::PI * 0.08; // 約 14 度の広がり
-        let half = (bcount as f32 - 1.0) / 2.0;
-        for bi in 0..bcount {
-            let angle = base_angle + (bi as f32 - half) * spread;
+        for angle in fan_angles(base_angle, ctx.bullet_count, spread) {
             let vx = angle.cos() * BULLET_SPEED;
             let vy = angle.sin() * BULLET_SPEED;
             w.bullets
-                .spawn(px, py, vx, vy, dmg, BULLET_LIFETIME, weapon_kind);
+                .spawn(ctx.px, ctx.py, vx, vy, ctx.damage, BULLET_LIFETIME, ctx.weapon_id);
         }
-        w.weapon_slots[si].cooldown_timer = cd;
     }
-}
 
-fn fire_axe(
-    w: &mut GameWorldInner,
-    si: usize,
-    px: f32,
-    py: f32,
-    dmg: i32,
-    weapon_kind: u8,
-    cd: f32,
-) {
-    // 上方向に直進（簡易実装）
-    w.bullets
-        .spawn(px, py, 0.0, -BULLET_SPEED, dmg, BULLET_LIFETIME, weapon_kind);
-    w.weapon_slots[si].cooldown_timer = cd;
+    fn upgrade_desc(&self, weapon_id: u8, current_lv: u32) -> Vec<String> {
+        let mut lines = stat_upgrade_lines(weapon_id, current_lv);
+        let wp = WeaponParams::get(weapon_id);
+        let bullets_now = wp.bullet_count(current_lv.max(1));
+        let bullets_next = wp.bullet_count(current_lv + 1);
+        if bullets_next > bullets_now {
+            lines.push(format!("Shots: {} -> {} (+)", bullets_now, bullets_next));
+        } else {
+            lines.push(format!("Shots: {}", bullets_now));
+        }
+        lines
+    }
 }
 
-fn fire_cross(
-    w: &mut GameWorldInner,
-    si: usize,
-    px: f32,
-    py: f32,
-    dmg: i32,
-    bcount: usize,
-    weapon_kind: u8,
-    cd: f32,
-) {
-    // Lv1〜3: 上下左右 4 方向、Lv4 以上: 斜め 4 方向も追加
-    let dirs_4: [(f32, f32); 4] = [(0.0, -1.0), (0.0, 1.0), (-1.0, 0.0), (1.0, 0.0)];
-    let diag = std::f32::consts::FRAC_1_SQRT_2;
-    let dirs_8: [(f32, f32); 8] = [
-        (0.0, -1.0),
-        (0.0, 1.0),
-        (-1.0, 0.0),
-        (1.0, 0.0),
-        (diag, -diag),
-        (-diag, -diag),
-        (diag, diag),
-        (-diag, diag),
-    ];
-    let dirs: &[(f32, f32)] = if bcount >= 8 { &dirs_8 } else { &dirs_4 };
-    for &(dx_dir, dy_dir) in dirs {
-        w.bullets.spawn(
-            px,
-            py,
-            dx_dir * BULLET_SPEED,
-            dy_dir * BULLET_SPEED,
-            dmg,
-            BULLET_LIFETIME,
-            weapon_kind,
-        );
+// ─── Axe ───────────────────────────────────────────────────────
+
+/// 上方向に直進（簡易実装）。複数発は上方向を中心に扇状に投げる
+pub(crate) struct Axe;
+
+impl WeaponBehavior for Axe {
+    fn name(&self) -> &'static str { "axe" }
+
+    fn targeting(&self) -> Targeting { Targeting::Untargeted }
+
+    fn fire(&self, w: &mut GameWorldInner, ctx: &FireContext) {
+        let spread = std::f32::consts::PI * 0.1; // 18 度間隔
+        for angle in fan_angles(-std::f32::consts::FRAC_PI_2, ctx.bullet_count, spread) {
+            let vx = angle.cos() * BULLET_SPEED;
+            let vy = angle.sin() * BULLET_SPEED;
+            w.bullets
+                .spawn(ctx.px, ctx.py, vx, vy, ctx.damage, BULLET_LIFETIME, ctx.weapon_id);
+        }
+    }
+
+    fn upgrade_desc(&self, weapon_id: u8, current_lv: u32) -> Vec<String> {
+        let mut lines = stat_upgrade_lines(weapon_id, current_lv);
+        lines.push("Throws upward".to_string());
+        lines
     }
-    w.weapon_slots[si].cooldown_timer = cd;
 }
 
-fn fire_whip(
-    w: &mut GameWorldInner,
-    si: usize,
-    px: f32,
-    py: f32,
-    dmg: i32,
-    level: u32,
-    kind_id: u8,
-    weapon_kind: u8,
-    cd: f32,
-    facing_angle: f32,
-) {
-    // プレイヤーの移動方向に扇状の判定を出す（弾丸を生成しない直接判定）
-    let range = whip_range(kind_id, level);
-    let whip_half_angle = std::f32::consts::PI * 0.3; // 108度 / 2 = 54度
-    // facing_angle 方向の中間点にエフェクト弾を生成（kind=10: 黄緑の横長楕円）
-    let eff_x = px + facing_angle.cos() * range * 0.5;
-    let eff_y = py + facing_angle.sin() * range * 0.5;
-    w.bullets.spawn_effect(eff_x, eff_y, 0.12, BULLET_KIND_WHIP);
-    // 空間ハッシュで範囲内の候補のみ取得し、全敵ループを回避
-    let whip_range_sq = range * range;
-    let candidates = w.collision.dynamic.query_nearby(px, py, range);
-    for ei in candidates {
-        if !w.enemies.alive[ei] {
-            continue;
-        }
-        let ex = w.enemies.positions_x[ei];
-        let ey = w.enemies.positions_y[ei];
-        let ddx = ex - px;
-        let ddy = ey - py;
-        // sqrt を避けて二乗比較で正確な円形クリップ
-        if ddx * ddx + ddy * ddy > whip_range_sq {
-            continue;
-        }
-        let angle = ddy.atan2(ddx);
-        // π/-π をまたぐ場合に正しく動作するよう -π〜π に正規化
-        let mut diff = angle - facing_angle;
-        if diff > std::f32::consts::PI {
-            diff -= std::f32::consts::TAU;
+// ─── Cross ─────────────────────────────────────────────────────
+
+/// Lv1〜3: 上下左右 4 方向、Lv4 以上: 斜め 4 方向も追加。
+/// 弾数（パッシブの追加分を含む）だけ全周に等間隔で発射する
+pub(crate) struct Cross;
+
+impl WeaponBehavior for Cross {
+    fn name(&self) -> &'static str { "cross" }
+
+    fn targeting(&self) -> Targeting { Targeting::Untargeted }
+
+    fn fire(&self, w: &mut GameWorldInner, ctx: &FireContext) {
+        // 上方向から時計回りに等間隔（4 発で上下左右、8 発で斜めも含む）
+        let step = std::f32::consts::TAU / ctx.bullet_count.max(1) as f32;
+        for k in 0..ctx.bullet_count {
+            let angle = -std::f32::consts::FRAC_PI_2 + k as f32 * step;
+            w.bullets.spawn(
+                ctx.px,
+                ctx.py,
+                angle.cos() * BULLET_SPEED,
+                angle.sin() * BULLET_SPEED,
+                ctx.damage,
+                BULLET_LIFETIME,
+                ctx.weapon_id,
+            );
         }
-        if diff < -std::f32::consts::PI {
-            diff += std::f32::consts::TAU;
+    }
+
+    fn upgrade_desc(&self, weapon_id: u8, current_lv: u32) -> Vec<String> {
+        let mut lines = stat_upgrade_lines(weapon_id, current_lv);
+        let wp = WeaponParams::get(weapon_id);
+        let dirs_now = wp.bullet_count(current_lv.max(1));
+        let dirs_next = wp.bullet_count(current_lv + 1);
+        if dirs_next > dirs_now {
+            lines.push(format!("Dirs: {} -> {} (+)", dirs_now, dirs_next));
+        } else {
+            lines.push(format!("{}-way fire", dirs_now));
         }
-        if diff.abs() < whip_half_angle {
-            let enemy_r = EnemyParams::get(w.enemies.kind_ids[ei]).radius;
-            let hit_x = ex + enemy_r;
-            let hit_y = ey + enemy_r;
-            w.enemies.hp[ei] -= dmg as f32;
-            if w.enemies.hp[ei] <= 0.0 {
-                let kind_e = w.enemies.kind_ids[ei];
-                let ep_hit = EnemyParams::get(kind_e);
-                w.enemies.kill(ei);
-                w.kill_count += 1;
-                w.score_popups
-                    .push((hit_x, hit_y - 20.0, ep_hit.exp_reward * 2, 0.8));
-                w.frame_events.push(FrameEvent::EnemyKilled {
-                    enemy_kind: kind_e,
-                    weapon_kind,
-                });
-                w.score += ep_hit.exp_reward * 2;
-                w.exp += ep_hit.exp_reward;
-                if !w.level_up_pending {
-                    let required = exp_required_for_next(w.level);
-                    if w.exp >= required {
-                        let new_lv = w.level + 1;
-                        w.level_up_pending = true;
-                        w.weapon_choices = compute_weapon_choices(w);
-                        w.frame_events.push(FrameEvent::LevelUp { new_level: new_lv });
-                    }
+        lines
+    }
+}
+
+// ─── Whip ──────────────────────────────────────────────────────
+
+/// プレイヤーの移動方向に扇状の判定を出す（弾丸を生成しない直接判定）
+pub(crate) struct Whip;
+
+impl WeaponBehavior for Whip {
+    fn name(&self) -> &'static str { "whip" }
+
+    fn targeting(&self) -> Targeting { Targeting::Untargeted }
+
+    fn fire(&self, w: &mut GameWorldInner, ctx: &FireContext) {
+        let (px, py, dmg, facing_angle) = (ctx.px, ctx.py, ctx.damage, ctx.facing_angle);
+        let weapon_kind = ctx.weapon_id;
+        let range = whip_range(ctx.weapon_id, ctx.level);
+        let whip_half_angle = std::f32::consts::PI * 0.3; // 108度 / 2 = 54度
+        // facing_angle 方向の中間点にエフェクト弾を生成（kind=10: 黄緑の横長楕円）
+        let eff_x = px + facing_angle.cos() * range * 0.5;
+        let eff_y = py + facing_angle.sin() * range * 0.5;
+        w.bullets.spawn_effect(eff_x, eff_y, 0.12, BULLET_KIND_WHIP);
+        // 空間ハッシュで範囲内の候補のみ取得し、全敵ループを回避
+        let whip_range_sq = range * range;
+        let candidates = w.collision.dynamic.query_nearby(px, py, range);
+        for ei in candidates {
+            if !w.enemies.alive[ei] {
+                continue;
+            }
+            let ex = w.enemies.positions_x[ei];
+            let ey = w.enemies.positions_y[ei];
+            let ddx = ex - px;
+            let ddy = ey - py;
+            // sqrt を避けて二乗比較で正確な円形クリップ
+            if ddx * ddx + ddy * ddy > whip_range_sq {
+                continue;
+            }
+            if in_fan(ddy.atan2(ddx), facing_angle, whip_half_angle) {
+                let enemy_r = w.enemies.radius(ei);
+                let hit_x = ex + enemy_r;
+                let hit_y = ey + enemy_r;
+                if !apply_damage(w, DamageTarget::Enemy(ei), dmg as f32, weapon_kind).killed {
+                    w.particles.emit(hit_x, hit_y, 3, [1.0, 0.6, 0.1, 1.0]);
+                    apply_on_hit_status(w, ei, weapon_kind);
+                    knock_back_from(w, ei, px, py, weapon_kind);
                 }
-                w.particles.emit(hit_x, hit_y, 8, ep_hit.particle_color);
-                let roll = w.rng.next_u32() % 100;
-                let (item_kind, item_value) = if roll < 2 {
-                    (ItemKind::Magnet, 0)
-                } else if roll < 7 {
-                    (ItemKind::Potion, 20)
-                } else {
-                    (ItemKind::Gem, ep_hit.exp_reward)
-                };
-                w.items.spawn(hit_x, hit_y, item_kind, item_value);
-            } else {
-                w.particles.emit(hit_x, hit_y, 3, [1.0, 0.6, 0.1, 1.0]);
             }
         }
-    }
-    // 1.2.9: Whip vs ボス
-    {
-        let whip_range_sq = range * range;
-        let boss_hit_pos: Option<(f32, f32)> = if let Some(ref boss) = w.boss {
-            if !boss.invincible {
+        // 1.2.9: Whip vs ボス
+        let boss_hits: Vec<(u32, f32, f32)> = w
+            .bosses
+            .iter()
+            .filter(|boss| {
                 let ddx = boss.x - px;
                 let ddy = boss.y - py;
-                if ddx * ddx + ddy * ddy <= whip_range_sq {
-                    let angle = ddy.atan2(ddx);
-                    let mut diff = angle - facing_angle;
-                    if diff > std::f32::consts::PI {
-                        diff -= std::f32::consts::TAU;
-                    }
-                    if diff < -std::f32::consts::PI {
-                        diff += std::f32::consts::TAU;
-                    }
-                    if diff.abs() < whip_half_angle {
-                        Some((boss.x, boss.y))
-                    } else {
-                        None
-                    }
-                } else {
-                    None
-                }
-            } else {
-                None
-            }
-        } else {
-            None
-        };
-        if let Some((bx, by)) = boss_hit_pos {
-            if let Some(ref mut boss) = w.boss {
-                boss.hp -= dmg as f32;
+                !boss.invincible
+                    && ddx * ddx + ddy * ddy <= whip_range_sq
+                    && in_fan(ddy.atan2(ddx), facing_angle, whip_half_angle)
+            })
+            .map(|boss| (boss.id, boss.x, boss.y))
+            .collect();
+        for (id, bx, by) in boss_hits {
+            if !apply_damage(w, DamageTarget::Boss(id), dmg as f32, weapon_kind).killed {
+                w.particles.emit(bx, by, 4, [1.0, 0.8, 0.2, 1.0]);
             }
-            w.particles.emit(bx, by, 4, [1.0, 0.8, 0.2, 1.0]);
         }
     }
-    w.weapon_slots[si].cooldown_timer = cd;
+
+    fn upgrade_desc(&self, weapon_id: u8, current_lv: u32) -> Vec<String> {
+        let mut lines = stat_upgrade_lines(weapon_id, current_lv);
+        lines.push(format!(
+            "Range: {}px -> {}px",
+            whip_range(weapon_id, current_lv.max(1)) as u32,
+            whip_range(weapon_id, current_lv + 1) as u32,
+        ));
+        lines.push("Fan sweep (108°)".to_string());
+        lines
+    }
 }
 
-fn fire_fireball(
-    w: &mut GameWorldInner,
-    si: usize,
-    px: f32,
-    py: f32,
-    dmg: i32,
-    weapon_kind: u8,
-    cd: f32,
-) {
-    // 最近接敵に向かって貫通弾を発射
-    if let Some(ti) = find_nearest_enemy_spatial(&w.collision, &w.enemies, px, py, WEAPON_SEARCH_RADIUS) {
-        let target_r = EnemyParams::get(w.enemies.kind_ids[ti]).radius;
-        let tx = w.enemies.positions_x[ti] + target_r;
-        let ty = w.enemies.positions_y[ti] + target_r;
-        let bdx = tx - px;
-        let bdy = ty - py;
-        let base_angle = bdy.atan2(bdx);
-        let vx = base_angle.cos() * BULLET_SPEED;
-        let vy = base_angle.sin() * BULLET_SPEED;
-        w.bullets
-            .spawn_piercing(px, py, vx, vy, dmg, BULLET_LIFETIME, weapon_kind);
-        w.weapon_slots[si].cooldown_timer = cd;
+/// angle が facing_angle ± half_angle の扇内か（π/-π をまたぐ場合も正しく判定）
+fn in_fan(angle: f32, facing_angle: f32, half_angle: f32) -> bool {
+    let mut diff = angle - facing_angle;
+    if diff > std::f32::consts::PI {
+        diff -= std::f32::consts::TAU;
+    }
+    if diff < -std::f32::consts::PI {
+        diff += std::f32::consts::TAU;
+    }
+    diff.abs() < half_angle
+}
+
+// ─── Fireball ──────────────────────────────────────────────────
+
+/// 最近接敵に向かって貫通弾を発射（複数発は扇状）
+pub(crate) struct Fireball;
+
+impl WeaponBehavior for Fireball {
+    fn name(&self) -> &'static str { "fireball" }
+
+    fn targeting(&self) -> Targeting { Targeting::Nearest { required: true } }
+
+    fn fire(&self, w: &mut GameWorldInner, ctx: &FireContext) {
+        let Some(ti) = ctx.target else { return };
+        let base_angle = aim_angle(w, ti, ctx.px, ctx.py);
+        let spread = std::f32::consts::PI * 0.08;
+        for angle in fan_angles(base_angle, ctx.bullet_count, spread) {
+            let vx = angle.cos() * BULLET_SPEED;
+            let vy = angle.sin() * BULLET_SPEED;
+            w.bullets
+                .spawn_piercing(ctx.px, ctx.py, vx, vy, ctx.damage, BULLET_LIFETIME, ctx.weapon_id);
+        }
+    }
+
+    fn upgrade_desc(&self, weapon_id: u8, current_lv: u32) -> Vec<String> {
+        let mut lines = stat_upgrade_lines(weapon_id, current_lv);
+        lines.push("Piercing shot".to_string());
+        lines
     }
 }
 
-fn fire_lightning(
-    w: &mut GameWorldInner,
-    si: usize,
-    px: f32,
-    py: f32,
-    dmg: i32,
-    level: u32,
-    kind_id: u8,
-    weapon_kind: u8,
-    cd: f32,
-) {
-    // 最近接敵から始まり、最大 chain_count 体に連鎖
-    let chain_count = lightning_chain_count(kind_id, level);
-    // chain_count は最大 6 程度と小さいため Vec で十分（HashSet 不要）
-    let mut hit_vec: Vec<usize> = Vec::with_capacity(chain_count);
-    // 最初はプレイヤー位置から最近接敵を探す（空間ハッシュで候補を絞る）
-    let mut current = find_nearest_enemy_spatial(&w.collision, &w.enemies, px, py, WEAPON_SEARCH_RADIUS);
-    #[allow(unused_assignments)]
-    let mut next_search_x = px;
-    #[allow(unused_assignments)]
-    let mut next_search_y = py;
-    for _ in 0..chain_count {
-        if let Some(ei) = current {
-            let enemy_r = EnemyParams::get(w.enemies.kind_ids[ei]).radius;
+// ─── Lightning ─────────────────────────────────────────────────
+
+/// 最近接敵から始まり、最大 chain_count 体に連鎖
+pub(crate) struct Lightning;
+
+impl WeaponBehavior for Lightning {
+    fn name(&self) -> &'static str { "lightning" }
+
+    // 敵がいなくてもボスへの落雷があるため発射する
+    fn targeting(&self) -> Targeting { Targeting::Nearest { required: false } }
+
+    fn fire(&self, w: &mut GameWorldInner, ctx: &FireContext) {
+        let (px, py, dmg) = (ctx.px, ctx.py, ctx.damage);
+        let weapon_kind = ctx.weapon_id;
+        let chain_count = lightning_chain_count(ctx.weapon_id, ctx.level);
+        // chain_count は最大 6 程度と小さいため Vec で十分（HashSet 不要）
+        let mut hit_vec: Vec<usize> = Vec::with_capacity(chain_count);
+        // 最初はプレイヤー位置から最近接敵（ディスパッチャが空間ハッシュで解決済み）
+        let mut current = ctx.target;
+        for _ in 0..chain_count {
+            let Some(ei) = current else { break };
+            let enemy_r = w.enemies.radius(ei);
             let hit_x = w.enemies.positions_x[ei] + enemy_r;
             let hit_y = w.enemies.positions_y[ei] + enemy_r;
-            w.enemies.hp[ei] -= dmg as f32;
             // 電撃エフェクト弾（kind=9: 水色の電撃球）+ パーティクル
             w.bullets
                 .spawn_effect(hit_x, hit_y, 0.10, BULLET_KIND_LIGHTNING);
             w.particles.emit(hit_x, hit_y, 5, [0.3, 0.8, 1.0, 1.0]);
-            if w.enemies.hp[ei] <= 0.0 {
-                let kind_e = w.enemies.kind_ids[ei];
-                let ep_chain = EnemyParams::get(kind_e);
-                w.enemies.kill(ei);
-                w.kill_count += 1;
-                w.score_popups
-                    .push((hit_x, hit_y - 20.0, ep_chain.exp_reward * 2, 0.8));
-                w.frame_events.push(FrameEvent::EnemyKilled {
-                    enemy_kind: kind_e,
-                    weapon_kind,
-                });
-                w.score += ep_chain.exp_reward * 2;
-                w.exp += ep_chain.exp_reward;
-                if !w.level_up_pending {
-                    let required = exp_required_for_next(w.level);
-                    if w.exp >= required {
-                        let new_lv = w.level + 1;
-                        w.level_up_pending = true;
-                        w.weapon_choices = compute_weapon_choices(w);
-                        w.frame_events.push(FrameEvent::LevelUp { new_level: new_lv });
-                    }
-                }
-                let roll = w.rng.next_u32() % 100;
-                let (item_kind, item_value) = if roll < 2 {
-                    (ItemKind::Magnet, 0)
-                } else if roll < 7 {
-                    (ItemKind::Potion, 20)
-                } else {
-                    (ItemKind::Gem, ep_chain.exp_reward)
-                };
-                w.items.spawn(hit_x, hit_y, item_kind, item_value);
+            if !apply_damage(w, DamageTarget::Enemy(ei), dmg as f32, weapon_kind).killed {
+                apply_on_hit_status(w, ei, weapon_kind);
+                knock_back_from(w, ei, px, py, weapon_kind);
             }
             hit_vec.push(ei);
-            next_search_x = hit_x;
-            next_search_y = hit_y;
             current = find_nearest_enemy_spatial_excluding(
                 &w.collision,
                 &w.enemies,
-                next_search_x,
-                next_search_y,
+                hit_x,
+                hit_y,
                 WEAPON_SEARCH_RADIUS,
                 &hit_vec,
             );
-        } else {
-            break;
         }
-    }
-    // 1.2.9: Lightning vs ボス（600px 以内なら連鎖先としてダメージ）
-    {
-        let boss_hit_pos: Option<(f32, f32)> = if let Some(ref boss) = w.boss {
-            if !boss.invincible {
+        // 1.2.9: Lightning vs ボス（600px 以内なら連鎖先としてダメージ）
+        let boss_hits: Vec<(u32, f32, f32)> = w
+            .bosses
+            .iter()
+            .filter(|boss| {
                 let ddx = boss.x - px;
                 let ddy = boss.y - py;
-                if ddx * ddx + ddy * ddy < 600.0 * 600.0 {
-                    Some((boss.x, boss.y))
-                } else {
-                    None
-                }
-            } else {
-                None
-            }
-        } else {
-            None
-        };
-        if let Some((bx, by)) = boss_hit_pos {
-            if let Some(ref mut boss) = w.boss {
-                boss.hp -= dmg as f32;
-            }
+                !boss.invincible && ddx * ddx + ddy * ddy < 600.0 * 600.0
+            })
+            .map(|boss| (boss.id, boss.x, boss.y))
+            .collect();
+        for (id, bx, by) in boss_hits {
             w.bullets
                 .spawn_effect(bx, by, 0.10, BULLET_KIND_LIGHTNING);
             w.particles.emit(bx, by, 5, [0.3, 0.8, 1.0, 1.0]);
+            apply_damage(w, DamageTarget::Boss(id), dmg as f32, weapon_kind);
         }
     }
-    w.weapon_slots[si].cooldown_timer = cd;
+
+    fn upgrade_desc(&self, weapon_id: u8, current_lv: u32) -> Vec<String> {
+        let mut lines = stat_upgrade_lines(weapon_id, current_lv);
+        lines.push(format!(
+            "Chain: {} -> {} targets",
+            lightning_chain_count(weapon_id, current_lv.max(1)),
+            lightning_chain_count(weapon_id, current_lv + 1),
+        ));
+        lines
+    }
 }
 
-fn fire_garlic(
-    w: &mut GameWorldInner,
-    si: usize,
-    px: f32,
-    py: f32,
-    dmg: i32,
-    level: u32,
-    kind_id: u8,
-    weapon_kind: u8,
-    cd: f32,
-) {
-    // プレイヤー周囲オーラで一定間隔ダメージ（5 dmg/sec 想定: 0.2s 毎に 1）
-    let radius = garlic_radius(kind_id, level);
-    let radius_sq = radius * radius;
-    let candidates = w.collision.dynamic.query_nearby(px, py, radius);
-    for ei in candidates {
-        if !w.enemies.alive[ei] {
-            continue;
-        }
-        let ex = w.enemies.positions_x[ei];
-        let ey = w.enemies.positions_y[ei];
-        let ddx = ex - px;
-        let ddy = ey - py;
-        if ddx * ddx + ddy * ddy > radius_sq {
-            continue;
-        }
-        w.enemies.hp[ei] -= dmg as f32;
-        let kind_e = w.enemies.kind_ids[ei];
-        let ep = EnemyParams::get(kind_e);
-        let hit_x = ex + ep.radius;
-        let hit_y = ey + ep.radius;
-        if w.enemies.hp[ei] <= 0.0 {
-            w.enemies.kill(ei);
-            w.kill_count += 1;
-            w.score_popups
-                .push((hit_x, hit_y - 20.0, ep.exp_reward * 2, 0.8));
-            w.frame_events.push(FrameEvent::EnemyKilled {
-                enemy_kind: kind_e,
-                weapon_kind,
-            });
-            w.score += ep.exp_reward * 2;
-            w.exp += ep.exp_reward;
-            if !w.level_up_pending {
-                let required = exp_required_for_next(w.level);
-                if w.exp >= required {
-                    w.level_up_pending = true;
-                    w.weapon_choices = compute_weapon_choices(w);
-                    w.frame_events.push(FrameEvent::LevelUp {
-                        new_level: w.level + 1,
-                    });
-                }
+// ─── Garlic ────────────────────────────────────────────────────
+
+/// プレイヤー周囲オーラで一定間隔ダメージ（5 dmg/sec 想定: 0.2s 毎に 1）
+pub(crate) struct Garlic;
+
+impl WeaponBehavior for Garlic {
+    fn name(&self) -> &'static str { "garlic" }
+
+    fn targeting(&self) -> Targeting { Targeting::Untargeted }
+
+    fn fire(&self, w: &mut GameWorldInner, ctx: &FireContext) {
+        let (px, py, dmg) = (ctx.px, ctx.py, ctx.damage);
+        let weapon_kind = ctx.weapon_id;
+        let radius = garlic_radius(ctx.weapon_id, ctx.level);
+        let radius_sq = radius * radius;
+        let candidates = w.collision.dynamic.query_nearby(px, py, radius);
+        for ei in candidates {
+            if !w.enemies.alive[ei] {
+                continue;
             }
-            w.particles.emit(hit_x, hit_y, 8, ep.particle_color);
-            let roll = w.rng.next_u32() % 100;
-            let (item_kind, item_value) = if roll < 2 {
-                (ItemKind::Magnet, 0)
-            } else if roll < 7 {
-                (ItemKind::Potion, 20)
-            } else {
-                (ItemKind::Gem, ep.exp_reward)
-            };
-            w.items.spawn(hit_x, hit_y, item_kind, item_value);
-        } else {
-            w.particles.emit(hit_x, hit_y, 2, [0.9, 0.9, 0.3, 0.6]);
+            let ex = w.enemies.positions_x[ei];
+            let ey = w.enemies.positions_y[ei];
+            let ddx = ex - px;
+            let ddy = ey - py;
+            if ddx * ddx + ddy * ddy > radius_sq {
+                continue;
+            }
+            let enemy_r = w.enemies.radius(ei);
+            let hit_x = ex + enemy_r;
+            let hit_y = ey + enemy_r;
+            if !apply_damage(w, DamageTarget::Enemy(ei), dmg as f32, weapon_kind).killed {
+                w.particles.emit(hit_x, hit_y, 2, [0.9, 0.9, 0.3, 0.6]);
+                apply_on_hit_status(w, ei, weapon_kind);
+                knock_back_from(w, ei, px, py, weapon_kind);
+            }
+        }
+    }
+
+    fn upgrade_desc(&self, weapon_id: u8, current_lv: u32) -> Vec<String> {
+        let mut lines = stat_upgrade_lines(weapon_id, current_lv);
+        lines.push(format!(
+            "Aura: {}px -> {}px",
+            garlic_radius(weapon_id, current_lv.max(1)) as u32,
+            garlic_radius(weapon_id, current_lv + 1) as u32,
+        ));
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::world::{BossState, FrameEvent};
+
+    fn lightning_ctx(w: &GameWorldInner, damage: i32) -> FireContext {
+        let weapon_id = WeaponParams::find_id("lightning").unwrap();
+        FireContext {
+            slot_index:   0,
+            weapon_id,
+            level:        1,
+            damage,
+            bullet_count: 1,
+            px:           w.player.x,
+            py:           w.player.y,
+            facing_angle: 0.0,
+            target:       None,
         }
     }
-    w.weapon_slots[si].cooldown_timer = cd;
+
+    #[test]
+    fn lightning_damages_bosses_within_chain_range() {
+        let mut w = GameWorldInner::new(1);
+        let (px, py) = (w.player.x, w.player.y);
+        w.bosses.push(BossState::new(0, 0, px + 300.0, py));
+        w.bosses.push(BossState::new(1, 0, px + 700.0, py));
+        let max_hp = w.bosses[0].max_hp;
+
+        let ctx = lightning_ctx(&w, 12);
+        Lightning.fire(&mut w, &ctx);
+
+        assert_eq!(w.bosses[0].hp, max_hp - 12.0);
+        assert_eq!(w.bosses[1].hp, max_hp, "600px より遠いボスには届かない");
+    }
+
+    #[test]
+    fn lightning_can_finish_off_a_boss() {
+        let mut w = GameWorldInner::new(1);
+        let (px, py) = (w.player.x, w.player.y);
+        let mut boss = BossState::new(3, 1, px, py + 100.0);
+        boss.hp = 1.0;
+        w.bosses.push(boss);
+
+        let ctx = lightning_ctx(&w, 12);
+        Lightning.fire(&mut w, &ctx);
+
+        assert!(w.bosses.is_empty());
+        assert!(w
+            .frame_events
+            .iter()
+            .any(|e| matches!(e, FrameEvent::BossDefeated { boss_kind: 1, boss_id: 3 })));
+    }
 }
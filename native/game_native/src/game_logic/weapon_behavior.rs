@@ -0,0 +1,195 @@
+//! Path: native/game_native/src/game_logic/weapon_behavior.rs
+//! Summary: 武器の発射挙動トレイトと武器 ID → 挙動のレジストリ
+//!
+//! 武器を追加するときは WeaponBehavior を実装して register_weapon_behavior で登録し
+//! （組み込みの挙動は systems::weapons::builtin_behaviors）、entity_params の weapons テーブルに
+//! 行を足すだけでよい。既存の挙動を別 ID で使い回す場合は entity_params の `behavior` に挙動名を書く。
+
+use super::systems::weapons::builtin_behaviors;
+use crate::world::GameWorldInner;
+use game_core::entity_params::{EntityParamsError, EntityParamsTable, WeaponParams};
+use game_core::weapon::WeaponSlot;
+use std::sync::{Arc, OnceLock, RwLock};
+
+/// 発射前にディスパッチャが解決するターゲット
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Targeting {
+    /// 最近接敵を探して FireContext::target に渡す。
+    /// required = true の場合、敵がいなければ発射せずクールダウンも消費しない
+    Nearest { required: bool },
+    /// ターゲット不要（固定方向・向き・オーラ）
+    Untargeted,
+}
+
+/// 1 回の発射に必要なスロット情報
+#[derive(Clone, Copy, Debug)]
+pub struct FireContext {
+    pub slot_index:   usize,
+    pub weapon_id:    u8,
+    pub level:        u32,
+    /// レベル補正済みダメージ
+    pub damage:       i32,
+    /// レベル補正済み弾数（パッシブの追加弾数を含む。弾を撃つ挙動はこの数だけ発射する）
+    pub bullet_count: usize,
+    /// プレイヤー中心座標
+    pub px:           f32,
+    pub py:           f32,
+    /// プレイヤーの向き（ラジアン、停止中は右向き 0.0）
+    pub facing_angle: f32,
+    /// Targeting::Nearest で見つかった敵インデックス
+    pub target:       Option<usize>,
+}
+
+/// 武器の挙動。発射・アップグレード説明・ターゲット方式を提供する。
+pub trait WeaponBehavior: Send + Sync {
+    /// 挙動名（entity_params の name / behavior と照合する）
+    fn name(&self) -> &'static str;
+
+    fn targeting(&self) -> Targeting;
+
+    /// 攻撃を実行する。クールダウンの再設定はディスパッチャが行う
+    fn fire(&self, w: &mut GameWorldInner, ctx: &FireContext);
+
+    /// レベルアップカード用の説明行（current_lv = 0 は未所持）
+    fn upgrade_desc(&self, weapon_id: u8, current_lv: u32) -> Vec<String>;
+}
+
+/// DMG / CD の共通説明行
+pub(crate) fn stat_upgrade_lines(weapon_id: u8, current_lv: u32) -> Vec<String> {
+    let slot = |lv: u32| WeaponSlot { kind_id: weapon_id, level: lv.max(1), cooldown_timer: 0.0 };
+    let (now, next) = (slot(current_lv), slot(current_lv + 1));
+    vec![
+        format!("DMG: {} -> {}", now.effective_damage(), next.effective_damage()),
+        format!("CD:  {:.1}s -> {:.1}s", now.effective_cooldown(), next.effective_cooldown()),
+    ]
+}
+
+/// 登録済みの武器挙動（組み込み + register_weapon_behavior で追加したもの）
+fn behaviors() -> &'static RwLock<Vec<Arc<dyn WeaponBehavior>>> {
+    static BEHAVIORS: OnceLock<RwLock<Vec<Arc<dyn WeaponBehavior>>>> = OnceLock::new();
+    BEHAVIORS.get_or_init(|| RwLock::new(builtin_behaviors()))
+}
+
+/// 武器挙動を登録する（同名の挙動があれば置き換える）。
+/// 以降の load_entity_params の検証と、以降に生成するワールドの WeaponRegistry で使われる
+pub fn register_weapon_behavior(behavior: Box<dyn WeaponBehavior>) {
+    let behavior: Arc<dyn WeaponBehavior> = Arc::from(behavior);
+    let mut list = behaviors().write().unwrap_or_else(|e| e.into_inner());
+    match list.iter_mut().find(|b| b.name() == behavior.name()) {
+        Some(slot) => *slot = behavior,
+        None => list.push(behavior),
+    }
+}
+
+/// 挙動名から登録済みの挙動を引く
+fn find_behavior(name: &str) -> Option<Arc<dyn WeaponBehavior>> {
+    let list = behaviors().read().unwrap_or_else(|e| e.into_inner());
+    list.iter().find(|b| b.name() == name).cloned()
+}
+
+/// 各武器の挙動名が登録済みか検証する（load_entity_params でテーブルを差し替える前に呼ぶ）
+pub(crate) fn check_behaviors(table: &EntityParamsTable) -> Result<(), EntityParamsError> {
+    for (i, params) in table.weapons.iter().enumerate() {
+        let name = params.behavior_name();
+        if find_behavior(name).is_none() {
+            return Err(EntityParamsError::Invalid {
+                table:  "weapons",
+                index:  i,
+                reason: format!("unknown behavior '{name}'"),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// 武器 ID → 挙動のレジストリ（インデックス = 武器 ID）
+#[derive(Clone, Default)]
+pub struct WeaponRegistry {
+    by_id: Vec<Option<Arc<dyn WeaponBehavior>>>,
+}
+
+impl WeaponRegistry {
+    /// 現在の entity_params の weapons テーブルから登録済みの挙動を割り当てる。
+    /// 挙動名が見つからない武器は未登録のまま（発射されず選択肢にも出ない）
+    pub fn from_entity_params() -> Self {
+        let mut reg = Self::default();
+        for id in 0..WeaponParams::count() {
+            let params = WeaponParams::get(id as u8);
+            let name = params.behavior_name();
+            match find_behavior(name) {
+                Some(b) => reg.register(id as u8, b),
+                None => log::warn!("Unknown weapon behavior '{}' for weapon '{}'", name, params.name),
+            }
+        }
+        reg
+    }
+
+    /// このワールドの weapon_id に挙動を割り当てる（既存の割り当ては置き換える）
+    pub fn register(&mut self, weapon_id: u8, behavior: Arc<dyn WeaponBehavior>) {
+        let idx = weapon_id as usize;
+        if self.by_id.len() <= idx {
+            self.by_id.resize(idx + 1, None);
+        }
+        self.by_id[idx] = Some(behavior);
+    }
+
+    pub fn get(&self, weapon_id: u8) -> Option<Arc<dyn WeaponBehavior>> {
+        self.by_id.get(weapon_id as usize).cloned().flatten()
+    }
+
+    /// 挙動が登録されている武器 ID を昇順で返す
+    pub fn weapon_ids(&self) -> impl Iterator<Item = u8> + '_ {
+        self.by_id
+            .iter()
+            .enumerate()
+            .filter(|(_, b)| b.is_some())
+            .map(|(i, _)| i as u8)
+    }
+
+    /// 武器名からレベルアップカードの説明行を返す（on_hit の状態異常も末尾に追記）
+    pub fn upgrade_desc_by_name(&self, name: &str, current_lv: u32) -> Vec<String> {
+        WeaponParams::find_id(name)
+            .and_then(|id| {
+                let mut lines = self.get(id)?.upgrade_desc(id, current_lv);
+                if let Some(apply) = WeaponParams::get(id).on_hit {
+                    lines.push(apply.kind.describe(&apply));
+                }
+                Some(lines)
+            })
+            .unwrap_or_else(|| vec!["Upgrade weapon".to_string()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::borrow::Cow;
+
+    /// テスト用の何もしない挙動
+    struct Nova;
+
+    impl WeaponBehavior for Nova {
+        fn name(&self) -> &'static str { "test_nova" }
+
+        fn targeting(&self) -> Targeting { Targeting::Untargeted }
+
+        fn fire(&self, _w: &mut GameWorldInner, _ctx: &FireContext) {}
+
+        fn upgrade_desc(&self, _weapon_id: u8, _current_lv: u32) -> Vec<String> { Vec::new() }
+    }
+
+    #[test]
+    fn registered_behavior_can_be_named_in_entity_params() {
+        let mut table = EntityParamsTable::default();
+        table.weapons[0].behavior = Some(Cow::Borrowed("test_nova"));
+        assert!(check_behaviors(&table).is_err());
+
+        register_weapon_behavior(Box::new(Nova));
+        assert!(check_behaviors(&table).is_ok());
+
+        let mut reg = WeaponRegistry::from_entity_params();
+        reg.register(0, find_behavior("test_nova").unwrap());
+        assert_eq!(reg.get(0).map(|b| b.name()), Some("test_nova"));
+        assert_eq!(reg.get(1).map(|b| b.name()), Some("axe"));
+    }
+}
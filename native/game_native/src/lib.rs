@@ -9,13 +9,6 @@ rustler::atoms! {
     slime,
     bat,
     golem,
-    // 武器種別アトム
-    magic_wand,
-    axe,
-    cross,
-    whip,
-    fireball,
-    lightning,
     // level_up 通知アトム
     level_up,
     no_change,
@@ -29,13 +22,18 @@ rustler::atoms! {
     none,
     // イベントバス用アトム
     enemy_killed,
+    elite_enemy_killed,
     player_damaged,
     level_up_event,
     item_pickup,
     boss_defeated,
+    boss_phase_changed,
+    weapon_evolved,
     // Rust ゲームループ → Elixir 送信用
     frame_events,
     ui_action,
+    // パラメータテーブル形式
+    json,
 }
 
 mod asset;
@@ -50,15 +48,16 @@ mod world;
 pub use asset::{AssetId, AssetLoader};
 pub use audio::{start_audio_thread, AudioCommand, AudioCommandSender, AudioManager};
 pub use game_logic::{
-    find_nearest_enemy, find_nearest_enemy_excluding, find_nearest_enemy_spatial,
-    find_nearest_enemy_spatial_excluding, run_physics_step_for_bench, update_chase_ai,
-    update_chase_ai_simd,
+    apply_damage, find_nearest_enemy, find_nearest_enemy_excluding, find_nearest_enemy_spatial,
+    find_nearest_enemy_spatial_excluding, play_replay, register_weapon_behavior, run_physics_step_for_bench,
+    update_chase_ai, update_chase_ai_simd, update_chase_ai_with, DamageOutcome, DamageTarget, FireContext,
+    ReplayPlaybackError, ReplaySummary, Targeting, WeaponBehavior, WeaponRegistry,
 };
 pub use game_render::{BossHudInfo, GamePhase, HudData, RenderFrame};
-pub use nif::{SaveSnapshot, WeaponSlotSave};
+pub use nif::{PassiveSlotSave, SaveSnapshot, WeaponSlotSave};
 pub use world::{
     BossState, BulletWorld, EnemyWorld, FrameEvent, GameLoopControl, GameWorld, GameWorldInner,
-    ParticleWorld, PlayerState,
+    ParticleWorld, PlayerState, ReplayRecorder,
     BULLET_KIND_FIREBALL, BULLET_KIND_LIGHTNING, BULLET_KIND_NORMAL, BULLET_KIND_ROCK,
     BULLET_KIND_WHIP,
 };
@@ -1,68 +1,48 @@
 //! Path: native/game_native/src/nif/action_nif.rs
-//! Summary: アクション NIF（add_weapon, skip_level_up, spawn_boss, spawn_elite_enemy）
+//! Summary: アクション NIF（add_weapon, add_passive, skip_level_up, spawn_boss, spawn_elite_enemy）
 
 use super::util::lock_poisoned_err;
-use crate::game_logic::get_spawn_positions_around_player;
-use crate::world::{BossState, GameWorld};
-use game_core::constants::{PLAYER_RADIUS, SCREEN_HEIGHT, SCREEN_WIDTH};
-use game_core::entity_params::{BossParams, EnemyParams};
-use game_core::weapon::{WeaponSlot, MAX_WEAPON_LEVEL, MAX_WEAPON_SLOTS};
-use rustler::{Atom, NifResult, ResourceArc};
+use crate::game_logic::apply_command;
+use crate::world::GameWorld;
+use game_core::replay::ReplayCommand;
+use rustler::{Atom, Error, NifResult, ResourceArc};
 
 use crate::ok;
 
 #[rustler::nif]
 pub fn add_weapon(world: ResourceArc<GameWorld>, weapon_id: u8) -> NifResult<Atom> {
     let mut w = world.0.write().map_err(|_| lock_poisoned_err())?;
-    if let Some(slot) = w.weapon_slots.iter_mut().find(|s| s.kind_id == weapon_id) {
-        slot.level = (slot.level + 1).min(MAX_WEAPON_LEVEL);
-    } else if w.weapon_slots.len() < MAX_WEAPON_SLOTS {
-        w.weapon_slots.push(WeaponSlot::new(weapon_id));
-    }
-    w.complete_level_up();
+    apply_command(&mut w, ReplayCommand::AddWeapon { weapon_id })?;
+    Ok(ok())
+}
+
+/// パッシブアイテムを追加（所持中なら Lv+1）し、レベルアップを確定する
+#[rustler::nif]
+pub fn add_passive(world: ResourceArc<GameWorld>, passive_id: u8) -> NifResult<Atom> {
+    let mut w = world.0.write().map_err(|_| lock_poisoned_err())?;
+    apply_command(&mut w, ReplayCommand::AddPassive { passive_id })?;
     Ok(ok())
 }
 
 #[rustler::nif]
 pub fn skip_level_up(world: ResourceArc<GameWorld>) -> NifResult<Atom> {
     let mut w = world.0.write().map_err(|_| lock_poisoned_err())?;
-    w.complete_level_up();
+    apply_command(&mut w, ReplayCommand::SkipLevelUp)?;
     Ok(ok())
 }
 
 #[rustler::nif]
 pub fn spawn_boss(world: ResourceArc<GameWorld>, kind_id: u8) -> NifResult<Atom> {
     let mut w = world.0.write().map_err(|_| lock_poisoned_err())?;
-    if w.boss.is_some() { return Ok(ok()); }
-    if kind_id <= 2 {
-        let bp = BossParams::get(kind_id);
-        let px = w.player.x + PLAYER_RADIUS;
-        let py = w.player.y + PLAYER_RADIUS;
-        let bx = (px + 600.0).min(SCREEN_WIDTH  - bp.radius);
-        let by = py.clamp(bp.radius, SCREEN_HEIGHT - bp.radius);
-        w.boss = Some(BossState::new(kind_id, bx, by));
-    }
+    apply_command(&mut w, ReplayCommand::SpawnBoss { kind_id })?;
     Ok(ok())
 }
 
+/// hp_multiplier <= 0 ならコンテンツの EliteParams に従う
 #[rustler::nif]
 pub fn spawn_elite_enemy(world: ResourceArc<GameWorld>, kind_id: u8, count: usize, hp_multiplier: f64) -> NifResult<Atom> {
+    let count = u32::try_from(count).map_err(|_| Error::BadArg)?;
     let mut w = world.0.write().map_err(|_| lock_poisoned_err())?;
-    let ep = EnemyParams::get(kind_id);
-    let positions = get_spawn_positions_around_player(&mut w, count);
-    let before_len = w.enemies.positions_x.len();
-    w.enemies.spawn(&positions, kind_id);
-    let after_len = w.enemies.positions_x.len();
-    let base_hp = ep.max_hp * hp_multiplier as f32;
-    let mut applied = 0;
-    for i in (0..after_len).rev() {
-        if applied >= count { break; }
-        if w.enemies.alive[i] && w.enemies.kind_ids[i] == kind_id {
-            if i >= before_len || (w.enemies.hp[i] - ep.max_hp).abs() < 0.01 {
-                w.enemies.hp[i] = base_hp;
-                applied += 1;
-            }
-        }
-    }
+    apply_command(&mut w, ReplayCommand::SpawnElite { kind_id, count, hp_multiplier })?;
     Ok(ok())
 }
@@ -1,5 +1,5 @@
 //! Path: native/game_native/src/nif/game_loop_nif.rs
-//! Summary: ゲームループ NIF（physics_step, drain_frame_events, pause/resume, Rust ループ起動）
+//! Summary: ゲームループ NIF（physics_step, drain_frame_events, pause/resume, チェックサム間隔, Rust ループ起動）
 
 use super::util::lock_poisoned_err;
 use crate::game_logic::{drain_frame_events_inner, physics_step_inner};
@@ -66,7 +66,8 @@ fn run_rust_game_loop(
             thread::sleep(next_tick - now);
         }
 
-        let (events, ui_action_opt): (Vec<(Atom, u32, u32)>, Option<String>) = {
+        let checksum_interval = control.checksum_interval();
+        let (events, checksum_opt, ui_action_opt) = {
             let wait_start = Instant::now();
             let mut w = match world.0.write() {
                 Ok(guard) => guard,
@@ -74,18 +75,22 @@ fn run_rust_game_loop(
             };
             record_write_wait("loop.simulation_tick", wait_start.elapsed());
             let ui_action_opt = w.pending_ui_action.lock().ok().and_then(|mut g| g.take());
-            let events = if control.is_paused() {
-                Vec::new()
+            let (events, checksum_opt) = if control.is_paused() {
+                (Vec::new(), None)
             } else {
                 physics_step_inner(&mut w, TICK_MS);
-                drain_frame_events_inner(&mut w)
+                let checksum_opt = (checksum_interval > 0 && w.frame_id % checksum_interval == 0)
+                    .then(|| (w.frame_id, w.checksum()));
+                (drain_frame_events_inner(&mut w), checksum_opt)
             };
-            (events, ui_action_opt)
+            (events, checksum_opt, ui_action_opt)
         };
 
         let mut env = OwnedEnv::new();
-        let _ = env.send_and_clear(&pid, |env| {
-            (frame_events(), events).encode(env)
+        let _ = env.send_and_clear(&pid, |env| match checksum_opt {
+            // チェックサムを付けるフレームは {:frame_events, events, {frame_id, checksum}}
+            Some(checksum) => (frame_events(), events, checksum).encode(env),
+            None => (frame_events(), events).encode(env),
         });
         if let Some(action) = ui_action_opt {
             let _ = env.send_and_clear(&pid, |env| {
@@ -106,3 +111,10 @@ pub fn resume_physics(control: ResourceArc<GameLoopControl>) -> NifResult<Atom>
     control.resume();
     Ok(ok())
 }
+
+/// Rust ループが frames フレームごとに frame_events へワールドのチェックサムを付ける（0 で止める）
+#[rustler::nif]
+pub fn set_checksum_interval(control: ResourceArc<GameLoopControl>, frames: u32) -> NifResult<Atom> {
+    control.set_checksum_interval(frames);
+    Ok(ok())
+}
@@ -4,12 +4,14 @@
 mod action_nif;
 mod game_loop_nif;
 mod load;
+mod params_nif;
 mod push_tick_nif;
 mod render_nif;
 mod read_nif;
+mod replay_nif;
 mod save_nif;
 mod util;
 mod world_nif;
 
 pub use load::load;
-pub use save_nif::{SaveSnapshot, WeaponSlotSave};
+pub use save_nif::{PassiveSlotSave, SaveSnapshot, WeaponSlotSave};
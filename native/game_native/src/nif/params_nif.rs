@@ -0,0 +1,64 @@
+//! Path: native/game_native/src/nif/params_nif.rs
+//! Summary: エンティティパラメータテーブルの実行時読み込み NIF
+
+use game_core::entity_params::{
+    entity_params_counts, install_entity_params, EntityParamsError, EntityParamsTable,
+    PassiveParams, WeaponParams,
+};
+use rustler::{Atom, NifResult};
+
+use crate::game_logic::check_behaviors;
+use crate::world::GameWorld;
+use crate::{json, ok};
+
+/// コンテンツファイルの内容から敵・武器・ボス・パッシブのパラメータテーブルを差し替える。
+/// format は現在 :json のみ対応。検証エラー（未登録の挙動名を含む）は理由文字列付きで raise する。
+/// ワールドが残っている間は、既存の ID を無効にする（件数を減らす）テーブルを拒否する。
+#[rustler::nif]
+pub fn load_entity_params(format: Atom, contents: String) -> NifResult<Atom> {
+    if format != json() {
+        return Err(rustler::Error::BadArg);
+    }
+    let params_err = |e: EntityParamsError| rustler::Error::Term(Box::new(e.to_string()));
+    let table = EntityParamsTable::from_json(&contents).map_err(params_err)?;
+    check_behaviors(&table).map_err(params_err)?;
+    if GameWorld::live_count() > 0 {
+        check_no_shrink(&table).map_err(params_err)?;
+    }
+    install_entity_params(table).map_err(params_err)?;
+    let (enemies, weapons, bosses) = entity_params_counts();
+    let passives = PassiveParams::count();
+    log::info!("Entity params loaded: {enemies} enemies, {weapons} weapons, {bosses} bosses, {passives} passives");
+    Ok(ok())
+}
+
+/// 生存中のワールドは敵・武器・ボス・パッシブを ID で持つため、どの ID 範囲も縮めてはならない
+fn check_no_shrink(table: &EntityParamsTable) -> Result<(), EntityParamsError> {
+    let (enemies, weapons, bosses) = entity_params_counts();
+    let ranges = [
+        ("enemies", table.enemies.len(), enemies),
+        ("weapons", table.weapons.len(), weapons),
+        ("bosses", table.bosses.len(), bosses),
+        ("passives", table.passives.len(), PassiveParams::count()),
+    ];
+    match ranges.into_iter().find(|&(_, new, current)| new < current) {
+        Some((table, new, current)) => Err(EntityParamsError::Invalid {
+            table,
+            index: new,
+            reason: format!("table shrinks from {current} to {new} entries while a world exists"),
+        }),
+        None => Ok(()),
+    }
+}
+
+/// 武器カタログ [{name, label}]（インデックス = 武器 ID）。
+/// Elixir 側の武器名 → ID の解決と表示名はこれを使い、武器の定義を entity_params に一本化する
+#[rustler::nif]
+pub fn get_weapon_catalog() -> Vec<(String, String)> {
+    (0..WeaponParams::count())
+        .map(|id| {
+            let params = WeaponParams::get(id as u8);
+            (params.name.to_string(), params.label().to_string())
+        })
+        .collect()
+}
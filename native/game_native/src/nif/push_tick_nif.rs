@@ -7,9 +7,10 @@
 //! - 返す delta は変化分のみ（全量ではない）
 
 use super::util::lock_poisoned_err;
-use crate::game_logic::run_physics_step_for_bench as physics_step_inner;
+use crate::game_logic::{apply_command, run_physics_step_for_bench as physics_step_inner};
 use crate::lock_metrics::record_write_wait;
 use crate::world::GameWorld;
+use game_core::replay::ReplayCommand;
 use rustler::{Atom, NifResult, ResourceArc};
 use std::time::Instant;
 
@@ -31,8 +32,7 @@ pub fn push_tick(
     record_write_wait("nif.push_tick", wait_start.elapsed());
 
     // 入力を適用（inputs: プレイヤー移動方向）
-    w.player.input_dx = dx as f32;
-    w.player.input_dy = dy as f32;
+    apply_command(&mut w, ReplayCommand::SetInput { dx: dx as f32, dy: dy as f32 })?;
 
     // 1.10.7: 補間用 - 前フレームの位置・タイムスタンプを保存
     w.prev_player_x = w.player.x;
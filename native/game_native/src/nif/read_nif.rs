@@ -3,11 +3,9 @@
 
 use super::util::lock_poisoned_err;
 use crate::world::GameWorld;
-use game_core::entity_params::WeaponParams;
+use game_core::entity_params::{PassiveParams, WeaponParams};
 use game_core::util::exp_required_for_next;
-use rustler::{Atom, NifResult, ResourceArc};
-
-use crate::{alive, none};
+use rustler::{NifResult, ResourceArc};
 
 #[rustler::nif]
 pub fn get_player_pos(world: ResourceArc<GameWorld>) -> NifResult<(f64, f64)> {
@@ -28,6 +26,13 @@ pub fn get_bullet_count(world: ResourceArc<GameWorld>) -> NifResult<usize> {
     Ok(w.bullets.count)
 }
 
+/// (frame_id, チェックサム)。同じシード・同じ入力のワールド同士で比べて同期ずれを検出する
+#[rustler::nif]
+pub fn get_world_checksum(world: ResourceArc<GameWorld>) -> NifResult<(u32, u64)> {
+    let w = world.0.read().map_err(|_| lock_poisoned_err())?;
+    Ok((w.frame_id, w.checksum()))
+}
+
 #[rustler::nif]
 pub fn get_frame_time_ms(world: ResourceArc<GameWorld>) -> NifResult<f64> {
     let w = world.0.read().map_err(|_| lock_poisoned_err())?;
@@ -38,9 +43,14 @@ pub fn get_frame_time_ms(world: ResourceArc<GameWorld>) -> NifResult<f64> {
 #[rustler::nif]
 pub fn debug_dump_world(world: ResourceArc<GameWorld>) -> NifResult<String> {
     let w = world.0.read().map_err(|_| lock_poisoned_err())?;
-    let boss_str = match &w.boss {
-        Some(b) => format!("boss hp={:.0}/{:.0}", b.hp, b.max_hp),
-        None => "boss=none".to_string(),
+    let boss_str = if w.bosses.is_empty() {
+        "boss=none".to_string()
+    } else {
+        w.bosses
+            .iter()
+            .map(|b| format!("boss#{} hp={:.0}/{:.0}", b.id, b.hp, b.max_hp))
+            .collect::<Vec<_>>()
+            .join(" ")
     };
     Ok(format!(
         "enemies={} bullets={} player=({:.1},{:.1}) hp={:.0}/{:.0} {}",
@@ -81,10 +91,10 @@ pub fn get_frame_metadata(world: ResourceArc<GameWorld>) -> NifResult<(
 )> {
     let w = world.0.read().map_err(|_| lock_poisoned_err())?;
     let exp_to_next = exp_required_for_next(w.level).saturating_sub(w.exp);
-    let (boss_alive, boss_hp, boss_max_hp) = match &w.boss {
-        Some(boss) => (true, boss.hp as f64, boss.max_hp as f64),
-        None       => (false, 0.0, 0.0),
-    };
+    // 複数ボスは HP を合算する（個別の値は get_boss_info）
+    let boss_alive  = !w.bosses.is_empty();
+    let boss_hp     = w.bosses.iter().map(|b| b.hp as f64).sum::<f64>();
+    let boss_max_hp = w.bosses.iter().map(|b| b.max_hp as f64).sum::<f64>();
     Ok((
         (w.player.hp as f64, w.player_max_hp as f64, w.score, w.elapsed_seconds as f64),
         (w.enemies.count, w.bullets.count, w.last_frame_time_ms),
@@ -100,6 +110,23 @@ pub fn get_level_up_data(world: ResourceArc<GameWorld>) -> NifResult<(u32, u32,
     Ok((w.exp, w.level, w.level_up_pending, exp_to_next))
 }
 
+/// レベルアップ待ちの選択肢（武器名・パッシブ名）。描画スレッドのカードと同じ内容で、
+/// 選べる強化が残っていなければ空
+#[rustler::nif]
+pub fn get_level_up_choices(world: ResourceArc<GameWorld>) -> NifResult<Vec<String>> {
+    let w = world.0.read().map_err(|_| lock_poisoned_err())?;
+    Ok(w.weapon_choices.clone())
+}
+
+/// 現在のウェーブ名（スポーンスケジュールの label）。スケジュール未設定・ラベルなしなら nil
+#[rustler::nif]
+pub fn get_wave_label(world: ResourceArc<GameWorld>) -> NifResult<Option<String>> {
+    let w = world.0.read().map_err(|_| lock_poisoned_err())?;
+    Ok(w.spawn_director.as_ref()
+        .and_then(|director| director.wave_label(w.elapsed_seconds))
+        .map(str::to_string))
+}
+
 #[rustler::nif]
 pub fn get_weapon_levels(world: ResourceArc<GameWorld>) -> NifResult<Vec<(String, u32)>> {
     let w = world.0.read().map_err(|_| lock_poisoned_err())?;
@@ -108,19 +135,28 @@ pub fn get_weapon_levels(world: ResourceArc<GameWorld>) -> NifResult<Vec<(String
         .collect())
 }
 
+#[rustler::nif]
+pub fn get_passive_levels(world: ResourceArc<GameWorld>) -> NifResult<Vec<(String, u32)>> {
+    let w = world.0.read().map_err(|_| lock_poisoned_err())?;
+    Ok(w.passive_slots.iter()
+        .map(|s| (PassiveParams::get(s.kind_id).name.to_string(), s.level))
+        .collect())
+}
+
 #[rustler::nif]
 pub fn get_magnet_timer(world: ResourceArc<GameWorld>) -> NifResult<f64> {
     let w = world.0.read().map_err(|_| lock_poisoned_err())?;
     Ok(w.magnet_timer as f64)
 }
 
+/// 生存中のボスを出現順に [(id, kind_id, hp, max_hp)] で返す（いなければ空リスト）
 #[rustler::nif]
-pub fn get_boss_info(world: ResourceArc<GameWorld>) -> NifResult<(Atom, f64, f64)> {
+pub fn get_boss_info(world: ResourceArc<GameWorld>) -> NifResult<Vec<(u32, u8, f64, f64)>> {
     let w = world.0.read().map_err(|_| lock_poisoned_err())?;
-    Ok(match &w.boss {
-        Some(boss) => (alive(), boss.hp as f64, boss.max_hp as f64),
-        None       => (none(), 0.0, 0.0),
-    })
+    Ok(w.bosses
+        .iter()
+        .map(|b| (b.id, b.kind_id, b.hp as f64, b.max_hp as f64))
+        .collect())
 }
 
 #[rustler::nif]
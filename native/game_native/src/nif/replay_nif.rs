@@ -0,0 +1,50 @@
+//! Path: native/game_native/src/nif/replay_nif.rs
+//! Summary: リプレイ NIF（記録の開始・停止、ファイルの再生）
+
+use super::util::lock_poisoned_err;
+use crate::game_logic::play_replay;
+use crate::world::{GameWorld, ReplayRecorder};
+use rustler::{Atom, Error, NifResult, ResourceArc};
+use std::fs::File;
+use std::io::BufReader;
+
+use crate::ok;
+
+/// path へのリプレイ記録を開始する。再生は新しいワールドから行うので、
+/// create_world の直後（load_map などのコマンドや最初の物理ステップより前）に呼ぶ
+#[rustler::nif]
+pub fn start_replay_recording(world: ResourceArc<GameWorld>, path: String) -> NifResult<Atom> {
+    let mut w = world.0.write().map_err(|_| lock_poisoned_err())?;
+    if w.frame_id != 0 {
+        return Err(Error::Term(Box::new("replay recording must start before the first physics step")));
+    }
+    if w.replay.is_some() {
+        return Err(Error::Term(Box::new("replay recording is already running")));
+    }
+    let recorder = ReplayRecorder::create(&path, w.rng.seed())
+        .map_err(|e| Error::Term(Box::new(format!("failed to create {path}: {e}"))))?;
+    w.replay = Some(recorder);
+    log::info!("Replay recording started: {path}");
+    Ok(ok())
+}
+
+/// 記録を止めてファイルを閉じる（記録していなければ何もしない）
+#[rustler::nif]
+pub fn stop_replay_recording(world: ResourceArc<GameWorld>) -> NifResult<Atom> {
+    let recorder = world.0.write().map_err(|_| lock_poisoned_err())?.replay.take();
+    if let Some(recorder) = recorder {
+        let path = recorder.path().to_string();
+        recorder.finish().map_err(|e| Error::Term(Box::new(format!("failed to write {path}: {e}"))))?;
+        log::info!("Replay recording saved: {path}");
+    }
+    Ok(ok())
+}
+
+/// リプレイファイルを新しいワールドで再生し、(最終 frame_id, 一致したチェックポイント数) を返す。
+/// 状態が記録とずれたら、どのフレームで何がずれたかを含むエラーを返す
+#[rustler::nif(schedule = "DirtyCpu")]
+pub fn play_replay_file(path: String) -> NifResult<(u32, u32)> {
+    let file = File::open(&path).map_err(|e| Error::Term(Box::new(format!("failed to open {path}: {e}"))))?;
+    let summary = play_replay(BufReader::new(file)).map_err(|e| Error::Term(Box::new(e.to_string())))?;
+    Ok((summary.frames, summary.checkpoints))
+}
@@ -3,9 +3,12 @@
 
 use super::util::lock_poisoned_err;
 use crate::world::{BulletWorld, GameWorld};
-use game_core::constants::PARTICLE_RNG_SEED;
+use game_core::entity_params::{entity_params_counts, PassiveParams, WeaponParams};
 use game_core::item::ItemWorld;
-use game_core::weapon::WeaponSlot;
+use game_core::physics::rng::{RngState, RngStreams, RngStreamsState};
+use game_core::player_stats::{PassiveSlot, PlayerStats, MAX_PASSIVE_SLOTS};
+use game_core::status_effect::{StatusKind, StatusSlot};
+use game_core::weapon::{WeaponSlot, MAX_WEAPON_LEVEL, MAX_WEAPON_SLOTS};
 use rustler::{Atom, NifResult, ResourceArc};
 
 use crate::{ok, ParticleWorld};
@@ -17,6 +20,58 @@ pub struct WeaponSlotSave {
     pub level:   u32,
 }
 
+/// パッシブアイテムスロットの保存用データ
+#[derive(Debug, Clone, rustler::NifMap)]
+pub struct PassiveSlotSave {
+    pub kind_id: u8,
+    pub level:   u32,
+}
+
+/// 状態異常 1 件の保存用データ（kind は StatusKind::as_u8）
+#[derive(Debug, Clone, rustler::NifMap)]
+pub struct StatusEffectSave {
+    pub kind:          u8,
+    pub remaining:     f32,
+    pub stacks:        u8,
+    pub potency:       f32,
+    pub source_weapon: u8,
+}
+
+/// 生存中の敵 1 体の保存用データ
+#[derive(Debug, Clone, rustler::NifMap)]
+pub struct EnemySave {
+    pub kind_id:  u8,
+    pub x:        f32,
+    pub y:        f32,
+    pub hp:       f32,
+    pub is_elite: bool,
+    pub statuses: Vec<StatusEffectSave>,
+}
+
+/// 乱数ストリームの保存用データ（各ストリームは (state, inc)）
+#[derive(Debug, Clone, rustler::NifMap)]
+pub struct RngSave {
+    pub seed:   u64,
+    pub spawn:  (u64, u64),
+    pub loot:   (u64, u64),
+    pub combat: (u64, u64),
+    pub ai:     (u64, u64),
+}
+
+impl From<RngStreamsState> for RngSave {
+    fn from(s: RngStreamsState) -> Self {
+        let pair = |r: RngState| (r.state, r.inc);
+        Self { seed: s.seed, spawn: pair(s.spawn), loot: pair(s.loot), combat: pair(s.combat), ai: pair(s.ai) }
+    }
+}
+
+impl From<RngSave> for RngStreamsState {
+    fn from(s: RngSave) -> Self {
+        let state = |(state, inc): (u64, u64)| RngState { state, inc };
+        Self { seed: s.seed, spawn: state(s.spawn), loot: state(s.loot), combat: state(s.combat), ai: state(s.ai) }
+    }
+}
+
 /// ゲーム状態のスナップショット（セーブ/ロード用）
 #[derive(Debug, Clone, rustler::NifMap)]
 pub struct SaveSnapshot {
@@ -29,7 +84,11 @@ pub struct SaveSnapshot {
     pub score:            u32,
     pub elapsed_seconds:  f32,
     pub weapon_slots:     Vec<WeaponSlotSave>,
+    pub passive_slots:    Vec<PassiveSlotSave>,
     pub kill_count:       u32,
+    pub enemies:          Vec<EnemySave>,
+    /// 旧セーブは nil（ワールドの乱数をそのまま使う）
+    pub rng:              Option<RngSave>,
 }
 
 #[rustler::nif]
@@ -39,6 +98,32 @@ pub fn get_save_snapshot(world: ResourceArc<GameWorld>) -> NifResult<SaveSnapsho
         .iter()
         .map(|s| WeaponSlotSave { kind_id: s.kind_id, level: s.level })
         .collect();
+    let passive_slots = w.passive_slots
+        .iter()
+        .map(|s| PassiveSlotSave { kind_id: s.kind_id, level: s.level })
+        .collect();
+    let enemies = (0..w.enemies.len())
+        .filter(|&i| w.enemies.alive[i])
+        .map(|i| EnemySave {
+            kind_id:  w.enemies.kind_ids[i],
+            x:        w.enemies.positions_x[i],
+            y:        w.enemies.positions_y[i],
+            hp:       w.enemies.hp[i],
+            is_elite: w.enemies.is_elite[i],
+            statuses: StatusKind::ALL
+                .iter()
+                .map(|&k| (k, w.enemies.statuses[i].get(k)))
+                .filter(|(_, s)| s.is_active())
+                .map(|(k, s)| StatusEffectSave {
+                    kind:          k.as_u8(),
+                    remaining:     s.remaining,
+                    stacks:        s.stacks,
+                    potency:       s.potency,
+                    source_weapon: s.source_weapon,
+                })
+                .collect(),
+        })
+        .collect();
     Ok(SaveSnapshot {
         player_hp:       w.player.hp,
         player_x:        w.player.x,
@@ -49,7 +134,10 @@ pub fn get_save_snapshot(world: ResourceArc<GameWorld>) -> NifResult<SaveSnapsho
         score:           w.score,
         elapsed_seconds: w.elapsed_seconds,
         weapon_slots,
+        passive_slots,
         kill_count:      w.kill_count,
+        enemies,
+        rng:             Some(w.rng.state().into()),
     })
 }
 
@@ -57,6 +145,14 @@ pub fn get_save_snapshot(world: ResourceArc<GameWorld>) -> NifResult<SaveSnapsho
 pub fn load_save_snapshot(world: ResourceArc<GameWorld>, snapshot: SaveSnapshot) -> NifResult<Atom> {
     let mut w = world.0.write().map_err(|_| lock_poisoned_err())?;
 
+    // スナップショットの復元はリプレイに記録できないので、記録中なら止める
+    if let Some(recorder) = w.replay.take() {
+        log::warn!("Replay recording to {} stopped: save snapshots cannot be replayed", recorder.path());
+        if let Err(e) = recorder.finish() {
+            log::error!("Replay recording could not be written: {e}");
+        }
+    }
+
     w.player.hp               = snapshot.player_hp;
     w.player.x                = snapshot.player_x;
     w.player.y                = snapshot.player_y;
@@ -64,27 +160,69 @@ pub fn load_save_snapshot(world: ResourceArc<GameWorld>, snapshot: SaveSnapshot)
     w.player.input_dy         = 0.0;
     w.player.invincible_timer = 0.0;
 
-    w.player_max_hp   = snapshot.player_max_hp;
     w.score           = snapshot.score;
     w.elapsed_seconds = snapshot.elapsed_seconds;
     w.exp             = snapshot.exp;
     w.level           = snapshot.level;
     w.level_up_pending = false;
 
+    // 武器テーブルにない武器は捨て、レベルは 1..=MAX_WEAPON_LEVEL に収める
     let mut slots: Vec<WeaponSlot> = snapshot.weapon_slots
         .into_iter()
-        .map(|s| WeaponSlot { kind_id: s.kind_id, level: s.level, cooldown_timer: 0.0 })
+        .filter(|s| WeaponParams::try_get(s.kind_id).is_some())
+        .map(|s| WeaponSlot { kind_id: s.kind_id, level: s.level.clamp(1, MAX_WEAPON_LEVEL), cooldown_timer: 0.0 })
+        .take(MAX_WEAPON_SLOTS)
         .collect();
     if slots.is_empty() {
         slots.push(WeaponSlot::new(0));
     }
     w.weapon_slots = slots;
 
+    // 最大 HP はパッシブから再計算する（HP はセーブ時の値のまま）
+    w.passive_slots = snapshot.passive_slots
+        .into_iter()
+        .filter_map(|s| {
+            let pp = PassiveParams::try_get(s.kind_id)?;
+            Some(PassiveSlot { kind_id: s.kind_id, level: s.level.clamp(1, pp.max_level) })
+        })
+        .take(MAX_PASSIVE_SLOTS)
+        .collect();
+    w.player_stats  = PlayerStats::from_passives(&w.passive_slots);
+    w.player_max_hp = w.player_stats.max_hp;
+    w.player.hp     = w.player.hp.min(w.player_max_hp);
+
+    // 敵は状態異常ごと復元する（旧セーブは enemies が空なので敵なしで再開）
     w.enemies  = crate::EnemyWorld::new();
+    let (enemy_kinds, _, _) = entity_params_counts();
+    for e in snapshot.enemies {
+        if e.kind_id as usize >= enemy_kinds {
+            continue;
+        }
+        if e.is_elite {
+            w.enemies.spawn_elite(&[(e.x, e.y)], e.kind_id, 1.0);
+        } else {
+            w.enemies.spawn(&[(e.x, e.y)], e.kind_id);
+        }
+        let i = w.enemies.len() - 1;
+        w.enemies.hp[i] = e.hp;
+        for s in e.statuses {
+            if let Some(kind) = StatusKind::from_u8(s.kind) {
+                w.enemies.statuses[i].slots[kind as usize] = StatusSlot {
+                    remaining:     s.remaining,
+                    stacks:        s.stacks.clamp(1, kind.max_stacks()),
+                    potency:       s.potency,
+                    source_weapon: s.source_weapon,
+                };
+            }
+        }
+    }
     w.bullets  = BulletWorld::new();
-    w.particles = ParticleWorld::new(PARTICLE_RNG_SEED);
+    if let Some(rng) = snapshot.rng {
+        w.rng = RngStreams::from_state(rng.into());
+    }
+    w.particles = ParticleWorld::new(w.rng.seed());
     w.items    = ItemWorld::new();
-    w.boss     = None;
+    w.bosses.clear();
     w.frame_events.clear();
     w.magnet_timer = 0.0;
     w.kill_count   = snapshot.kill_count;
@@ -93,5 +231,11 @@ pub fn load_save_snapshot(world: ResourceArc<GameWorld>, snapshot: SaveSnapshot)
 
     w.collision.dynamic.clear();
 
+    // スポーンディレクターは現在のスケジュールのまま、ロードした経過時間から再開する
+    let elapsed = w.elapsed_seconds;
+    if let Some(director) = w.spawn_director.as_mut() {
+        director.resume_at(elapsed);
+    }
+
     Ok(ok())
 }
@@ -1,8 +1,19 @@
 //! Path: native/game_native/src/nif/util.rs
-//! Summary: NIF 共通ユーティリティ（lock_poisoned_err）
+//! Summary: NIF 共通ユーティリティ（lock_poisoned_err, CommandError の変換）
+
+use crate::game_logic::CommandError;
 
 /// RwLock の PoisonError を NifResult に変換するヘルパー
 #[inline]
 pub(crate) fn lock_poisoned_err() -> rustler::Error {
     rustler::Error::RaiseAtom("lock_poisoned")
 }
+
+impl From<CommandError> for rustler::Error {
+    fn from(e: CommandError) -> Self {
+        match e {
+            CommandError::BadArg => rustler::Error::BadArg,
+            CommandError::Invalid(reason) => rustler::Error::Term(Box::new(reason)),
+        }
+    }
+}
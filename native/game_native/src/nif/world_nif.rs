@@ -2,78 +2,77 @@
 //! Summary: ワールド作成・入力・スポーン・障害物設定 NIF
 
 use super::util::lock_poisoned_err;
-use crate::game_logic::get_spawn_positions_around_player;
-use crate::world::{GameWorld, GameWorldInner, PlayerState};
-use game_core::constants::{CELL_SIZE, PARTICLE_RNG_SEED, PLAYER_SIZE, SCREEN_HEIGHT, SCREEN_WIDTH};
-use game_core::item::ItemWorld;
-use game_core::physics::rng::SimpleRng;
-use game_core::physics::spatial_hash::CollisionWorld;
-use game_core::weapon::WeaponSlot;
+use crate::game_logic::apply_command;
+use crate::world::{GameWorld, GameWorldInner};
+use game_core::replay::ReplayCommand;
 use rustler::types::list::ListIterator;
-use rustler::{Atom, NifResult, ResourceArc, Term};
-use std::sync::{Mutex, RwLock};
+use rustler::{Atom, Error, NifResult, ResourceArc, Term};
 
-use crate::{ok, BulletWorld, EnemyWorld, ParticleWorld};
+use crate::{json, ok};
 
 #[rustler::nif]
 pub fn add(a: i64, b: i64) -> NifResult<i64> {
     Ok(a + b)
 }
 
+/// seed からゲームプレイ用・パーティクル用の乱数ストリームを作る（同じシードなら同じ展開になる）
 #[rustler::nif]
-pub fn create_world() -> ResourceArc<GameWorld> {
-    ResourceArc::new(GameWorld(RwLock::new(GameWorldInner {
-        frame_id:           0,
-        player:             PlayerState {
-            x:                SCREEN_WIDTH  / 2.0 - PLAYER_SIZE / 2.0,
-            y:                SCREEN_HEIGHT / 2.0 - PLAYER_SIZE / 2.0,
-            input_dx:         0.0,
-            input_dy:         0.0,
-            hp:               100.0,
-            invincible_timer: 0.0,
-        },
-        enemies:            EnemyWorld::new(),
-        bullets:            BulletWorld::new(),
-        particles:          ParticleWorld::new(PARTICLE_RNG_SEED),
-        items:              ItemWorld::new(),
-        magnet_timer:       0.0,
-        rng:                SimpleRng::new(12345),
-        collision:          CollisionWorld::new(CELL_SIZE),
-        obstacle_query_buf: Vec::new(),
-        last_frame_time_ms: 0.0,
-        score:              0,
-        elapsed_seconds:    0.0,
-        player_max_hp:      100.0,
-        exp:                0,
-        level:              1,
-        level_up_pending:   false,
-        weapon_slots:       vec![WeaponSlot::new(0)], // MagicWand
-        boss:               None,
-        frame_events:       Vec::new(),
-        pending_ui_action:  Mutex::new(None),
-        weapon_choices:     Vec::new(),
-        score_popups:       Vec::new(),
-        kill_count:         0,
-        prev_player_x:      SCREEN_WIDTH  / 2.0 - PLAYER_SIZE / 2.0,
-        prev_player_y:      SCREEN_HEIGHT / 2.0 - PLAYER_SIZE / 2.0,
-        prev_tick_ms:       0,
-        curr_tick_ms:       0,
-    })))
+pub fn create_world(seed: u64) -> ResourceArc<GameWorld> {
+    ResourceArc::new(GameWorld::new(GameWorldInner::new(seed)))
 }
 
 #[rustler::nif]
 pub fn set_player_input(world: ResourceArc<GameWorld>, dx: f64, dy: f64) -> NifResult<Atom> {
     let mut w = world.0.write().map_err(|_| lock_poisoned_err())?;
-    w.player.input_dx = dx as f32;
-    w.player.input_dy = dy as f32;
+    apply_command(&mut w, ReplayCommand::SetInput { dx: dx as f32, dy: dy as f32 })?;
     Ok(ok())
 }
 
 #[rustler::nif]
 pub fn spawn_enemies(world: ResourceArc<GameWorld>, kind_id: u8, count: usize) -> NifResult<Atom> {
+    let count = u32::try_from(count).map_err(|_| Error::BadArg)?;
     let mut w = world.0.write().map_err(|_| lock_poisoned_err())?;
-    let positions = get_spawn_positions_around_player(&mut w, count);
-    w.enemies.spawn(&positions, kind_id);
+    apply_command(&mut w, ReplayCommand::SpawnEnemies { kind_id, count })?;
+    Ok(ok())
+}
+
+/// ワールドの広さを設定する（マップ読み込み時）。プレイヤーは新しい境界の内側に収める
+#[rustler::nif]
+pub fn set_world_size(world: ResourceArc<GameWorld>, width: f64, height: f64) -> NifResult<Atom> {
+    let mut w = world.0.write().map_err(|_| lock_poisoned_err())?;
+    apply_command(&mut w, ReplayCommand::SetWorldSize { width, height })?;
+    Ok(ok())
+}
+
+/// ウェーブスケジュールを設定し、physics_step 内のスポーンディレクターを有効にする。
+/// format は現在 :json のみ対応。敵 ID は読み込み済みの entity_params で検証する
+#[rustler::nif]
+pub fn set_spawn_schedule(world: ResourceArc<GameWorld>, format: Atom, contents: String) -> NifResult<Atom> {
+    if format != json() {
+        return Err(Error::BadArg);
+    }
+    let mut w = world.0.write().map_err(|_| lock_poisoned_err())?;
+    apply_command(&mut w, ReplayCommand::SetSpawnSchedule { json: contents })?;
+    if let Some(director) = &w.spawn_director {
+        log::info!("Spawn schedule loaded: {} waves", director.schedule().waves.len());
+    }
+    Ok(ok())
+}
+
+/// マップファイル（JSON）を読み込み、ワールドの広さ・障害物・スポーン領域・地面タイルを設定する。
+/// プレイヤーはマップの開始位置に移す。リプレイにはファイルの中身を記録する
+#[rustler::nif]
+pub fn load_map(world: ResourceArc<GameWorld>, path: String) -> NifResult<Atom> {
+    let contents = std::fs::read_to_string(&path)
+        .map_err(|e| Error::Term(Box::new(format!("failed to read {path}: {e}"))))?;
+    let mut w = world.0.write().map_err(|_| lock_poisoned_err())?;
+    apply_command(&mut w, ReplayCommand::LoadMap { json: contents })?;
+    if let Some(map) = &w.tile_map {
+        log::info!(
+            "Map loaded: {path} ({}x{} tiles, {} obstacles, {} spawn zones)",
+            map.width, map.height, map.obstacles.len(), map.spawn_zones.len()
+        );
+    }
     Ok(ok())
 }
 
@@ -91,6 +90,6 @@ pub fn set_map_obstacles(world: ResourceArc<GameWorld>, obstacles_term: Term) ->
         ));
     }
     let mut w = world.0.write().map_err(|_| lock_poisoned_err())?;
-    w.collision.rebuild_static(&obstacles);
+    apply_command(&mut w, ReplayCommand::SetMapObstacles { obstacles })?;
     Ok(ok())
 }
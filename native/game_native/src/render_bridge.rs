@@ -2,12 +2,15 @@
 //! Summary: game_window の RenderBridge 実装（1.8.4）
 
 use crate::asset::AssetLoader;
+use crate::game_logic::apply_command;
 use crate::lock_metrics::{record_read_wait, record_write_wait};
 use crate::render_snapshot::{
-    build_render_frame, calc_interpolation_alpha, copy_interpolation_data, interpolate_player_pos,
+    build_render_frame, calc_interpolation_alpha, camera_offset_for, copy_interpolation_data,
+    interpolate_player_pos,
 };
 use crate::world::GameWorld;
 use game_core::constants::{SCREEN_HEIGHT, SCREEN_WIDTH};
+use game_core::replay::ReplayCommand;
 use game_render::RenderFrame;
 use game_window::{run_render_loop, RenderBridge, RendererInit, WindowConfig};
 use rustler::ResourceArc;
@@ -76,10 +79,7 @@ impl RenderBridge for NativeRenderBridge {
                 entry.1 = interp_y;
             }
             // カメラオフセットも補間位置に合わせて更新
-            use game_core::constants::{PLAYER_SIZE, SCREEN_WIDTH, SCREEN_HEIGHT};
-            let cam_x = interp_x + PLAYER_SIZE / 2.0 - SCREEN_WIDTH / 2.0;
-            let cam_y = interp_y + PLAYER_SIZE / 2.0 - SCREEN_HEIGHT / 2.0;
-            frame.camera_offset = (cam_x, cam_y);
+            frame.camera_offset = camera_offset_for(&interp_data.bounds, interp_x, interp_y);
         }
 
         frame
@@ -90,8 +90,8 @@ impl RenderBridge for NativeRenderBridge {
         match self.world.0.write() {
             Ok(mut guard) => {
                 record_write_wait("render.on_move_input", wait_start.elapsed());
-                guard.player.input_dx = dx;
-                guard.player.input_dy = dy;
+                // 入力の設定は失敗しない
+                let _ = apply_command(&mut guard, ReplayCommand::SetInput { dx, dy });
             }
             Err(e) => {
                 log::error!("Render bridge: failed to acquire write lock for input: {e:?}");
@@ -5,23 +5,28 @@
 //! 必要なデータを RenderSnapshot にコピーしてからロックを解放する。
 
 use crate::world::GameWorldInner;
-use game_render::{BossHudInfo, GamePhase, HudData, RenderFrame};
+use game_render::{BossHudInfo, GamePhase, HudData, RenderFrame, ELITE_RENDER_KIND_OFFSET, TINT_NONE};
 use game_core::constants::{INVINCIBLE_DURATION, PLAYER_SIZE, SCREEN_HEIGHT, SCREEN_WIDTH};
-use game_core::entity_params::{BossParams, EnemyParams, WeaponParams};
+use game_core::entity_params::{BossParams, EnemyParams, PassiveParams, WeaponParams};
+use game_core::physics::bounds::WorldBounds;
+use game_core::physics::collider::ObstacleShape;
 use game_core::util::exp_required_for_next;
 
+/// 凸多角形の障害物を塗るマスの一辺（px）
+const OBSTACLE_FILL_CELL: f32 = 16.0;
+
 /// GameWorldInner から RenderSnapshot を構築する。
 /// get_render_data / get_particle_data / get_item_data / get_frame_metadata 相当のロジックを集約。
 pub fn build_render_frame(w: &GameWorldInner) -> RenderFrame {
     // 1. スプライト（player, boss, enemies, bullets）
     let anim_frame = ((w.frame_id / 4) % 4) as u8;
     let mut render_data = Vec::with_capacity(
-        1 + w.boss.is_some() as usize + w.enemies.count + w.bullets.count,
+        1 + w.bosses.len() + w.enemies.count + w.bullets.count,
     );
 
-    render_data.push((w.player.x, w.player.y, 0, anim_frame));
+    render_data.push((w.player.x, w.player.y, 0, anim_frame, TINT_NONE));
 
-    if let Some(ref boss) = w.boss {
+    for boss in &w.bosses {
         let bp = BossParams::get(boss.kind_id);
         let boss_sprite_size = bp.radius * 2.0;
         render_data.push((
@@ -29,18 +34,24 @@ pub fn build_render_frame(w: &GameWorldInner) -> RenderFrame {
             boss.y - boss_sprite_size / 2.0,
             bp.render_kind,
             0,
+            boss.current_phase().tint.unwrap_or(TINT_NONE),
         ));
     }
 
     for i in 0..w.enemies.len() {
         if w.enemies.alive[i] {
             let base_kind = EnemyParams::get(w.enemies.kind_ids[i]).render_kind;
-            // game_native の EnemyWorld には is_elite がないため、通常描画のみ
+            // 1.2.10: エリートは base_kind + ELITE_RENDER_KIND_OFFSET（赤み・拡大はレンダラー側）
+            let kind = if w.enemies.is_elite[i] { base_kind + ELITE_RENDER_KIND_OFFSET } else { base_kind };
+            // 状態異常中は種別ごとの色を乗算する（凍結中はアニメーションも止める）
+            let status = &w.enemies.statuses[i];
+            let frame = if status.speed_multiplier() == 0.0 { 0 } else { anim_frame };
             render_data.push((
                 w.enemies.positions_x[i],
                 w.enemies.positions_y[i],
-                base_kind,
-                anim_frame,
+                kind,
+                frame,
+                status.tint().unwrap_or(TINT_NONE),
             ));
         }
     }
@@ -52,6 +63,7 @@ pub fn build_render_frame(w: &GameWorldInner) -> RenderFrame {
                 w.bullets.positions_y[i],
                 w.bullets.render_kind[i],
                 0,
+                TINT_NONE,
             ));
         }
     }
@@ -85,30 +97,85 @@ pub fn build_render_frame(w: &GameWorldInner) -> RenderFrame {
         }
     }
 
-    // 4. 障害物（collision.obstacles から）
-    let obstacle_data: Vec<(f32, f32, f32, u8)> = w.collision.obstacles
-        .iter()
-        .map(|o| (o.x, o.y, o.radius, o.kind))
-        .collect();
+    // 4. 障害物（collision.obstacles から）。円・矩形は 1 枚、凸多角形は内側を OBSTACLE_FILL_CELL 四方のマスで塗る
+    let mut obstacle_data: Vec<(f32, f32, f32, f32, u8, bool)> = Vec::with_capacity(w.collision.obstacles.len());
+    let mut fill = Vec::new();
+    for o in &w.collision.obstacles {
+        match o.shape {
+            ObstacleShape::Circle { .. } | ObstacleShape::Rect { .. } => {
+                let (min_x, min_y, max_x, max_y) = o.aabb();
+                let round = matches!(o.shape, ObstacleShape::Circle { .. });
+                obstacle_data.push((min_x, min_y, max_x - min_x, max_y - min_y, o.kind, round));
+            }
+            ObstacleShape::Polygon { .. } => {
+                fill.clear();
+                o.shape.fill_cells(OBSTACLE_FILL_CELL, &mut fill);
+                obstacle_data.extend(
+                    fill.iter().map(|&(x, y)| (o.x + x, o.y + y, OBSTACLE_FILL_CELL, OBSTACLE_FILL_CELL, o.kind, false)),
+                );
+            }
+        }
+    }
 
-    // 5. カメラオフセット（プレイヤー中心を画面中心に）
-    let cam_x = w.player.x + PLAYER_SIZE / 2.0 - SCREEN_WIDTH / 2.0;
-    let cam_y = w.player.y + PLAYER_SIZE / 2.0 - SCREEN_HEIGHT / 2.0;
+    // 5. カメラオフセット（プレイヤー中心を画面中心に。ワールドの端では止める）
+    let (cam_x, cam_y) = camera_offset_for(&w.bounds, w.player.x, w.player.y);
     let camera_offset = (cam_x, cam_y);
 
+    // 5.5. 地面タイル（画面内のみ。補間でカメラが少しずれても欠けないよう 1 タイル分広く取る）
+    let mut tile_data = Vec::new();
+    if let Some(map) = &w.tile_map {
+        let margin = map.tile_size;
+        map.visible_tiles(
+            cam_x - margin,
+            cam_y - margin,
+            SCREEN_WIDTH + margin * 2.0,
+            SCREEN_HEIGHT + margin * 2.0,
+            &mut tile_data,
+        );
+    }
+
     // 6. HUD メタデータ（get_frame_metadata 相当）
     let exp_to_next = exp_required_for_next(w.level).saturating_sub(w.exp);
-    let boss_info = w.boss.as_ref().map(|b| BossHudInfo {
-        name:   BossParams::get(b.kind_id).name.to_string(),
-        hp:     b.hp,
-        max_hp: b.max_hp,
-    });
+    let boss_info: Vec<BossHudInfo> = w.bosses
+        .iter()
+        .map(|b| BossHudInfo {
+            id:     b.id,
+            name:   BossParams::get(b.kind_id).name.to_string(),
+            hp:     b.hp,
+            max_hp: b.max_hp,
+        })
+        .collect();
 
     let weapon_levels: Vec<(String, u32)> = w.weapon_slots
         .iter()
         .map(|s| (WeaponParams::get(s.kind_id).name.to_string(), s.level))
         .collect();
 
+    let passive_levels: Vec<(String, u32)> = w.passive_slots
+        .iter()
+        .map(|s| (PassiveParams::get(s.kind_id).name.to_string(), s.level))
+        .collect();
+
+    let weapon_upgrade_descs: Vec<Vec<String>> = w.weapon_choices
+        .iter()
+        .map(|name| {
+            if let Some(pid) = PassiveParams::find_id(name) {
+                let current_lv = w.passive_slots
+                    .iter()
+                    .find(|s| s.kind_id == pid)
+                    .map(|s| s.level)
+                    .unwrap_or(0);
+                return PassiveParams::get(pid).upgrade_desc(current_lv);
+            }
+            let current_lv = w.weapon_slots
+                .iter()
+                .find(|s| WeaponParams::get(s.kind_id).name == name.as_str())
+                .map(|s| s.level)
+                .unwrap_or(0);
+            w.weapon_registry.upgrade_desc_by_name(name, current_lv)
+        })
+        .collect();
+
     let screen_flash_alpha = if w.player.invincible_timer > 0.0 && INVINCIBLE_DURATION > 0.0 {
         // 被弾直後に強く、無敵時間の減衰にあわせてフラッシュも弱くする（最大 0.5）
         ((w.player.invincible_timer / INVINCIBLE_DURATION).clamp(0.0, 1.0)) * 0.5
@@ -129,7 +196,9 @@ pub fn build_render_frame(w: &GameWorldInner) -> RenderFrame {
         fps:              0.0,
         level_up_pending: w.level_up_pending,
         weapon_choices:   w.weapon_choices.clone(),
+        weapon_upgrade_descs,
         weapon_levels,
+        passive_levels,
         magnet_timer:     w.magnet_timer,
         item_count:       w.items.count,
         camera_x:         cam_x,
@@ -146,6 +215,7 @@ pub fn build_render_frame(w: &GameWorldInner) -> RenderFrame {
         particle_data,
         item_data,
         obstacle_data,
+        tile_data,
         camera_offset,
         player_pos: (w.player.x, w.player.y),
         hud,
@@ -160,6 +230,8 @@ pub struct InterpolationData {
     pub curr_player_y: f32,
     pub prev_tick_ms: u64,
     pub curr_tick_ms: u64,
+    /// 補間後のカメラオフセット計算用
+    pub bounds: WorldBounds,
 }
 
 /// 1.10.7: ロック内で補間に必要なデータのみをコピーして即解放するためのヘルパー
@@ -172,6 +244,7 @@ pub fn copy_interpolation_data(w: &GameWorldInner) -> InterpolationData {
         curr_player_y: w.player.y,
         prev_tick_ms: w.prev_tick_ms,
         curr_tick_ms: w.curr_tick_ms,
+        bounds: w.bounds,
     }
 }
 
@@ -192,3 +265,13 @@ pub fn interpolate_player_pos(data: &InterpolationData, alpha: f32) -> (f32, f32
     let y = data.prev_player_y + (data.curr_player_y - data.prev_player_y) * alpha;
     (x, y)
 }
+
+/// プレイヤー位置（左上基準）を画面中心に映すカメラオフセット（ワールドの外は映さない）
+pub fn camera_offset_for(bounds: &WorldBounds, player_x: f32, player_y: f32) -> (f32, f32) {
+    bounds.camera_offset(
+        player_x + PLAYER_SIZE / 2.0,
+        player_y + PLAYER_SIZE / 2.0,
+        SCREEN_WIDTH,
+        SCREEN_HEIGHT,
+    )
+}
@@ -1,16 +1,24 @@
 //! Path: native/game_native/src/world/boss.rs
 //! Summary: ボス状態（BossState）
 
+use game_core::boss::BossPhase;
 use game_core::entity_params::BossParams;
 
 /// ボス状態（1.4.7: kind_id で参照。0=SlimeKing, 1=BatLord, 2=StoneGolem）
 pub struct BossState {
+    /// ワールド内で一意な ID（出現順に採番し、撃破まで変わらない）
+    pub id:               u32,
     pub kind_id:          u8,
     pub x:                f32,
     pub y:                f32,
     pub hp:               f32,
     pub max_hp:           f32,
-    pub phase_timer:      f32,
+    /// BossParams::phases の現在のインデックス
+    pub phase:            usize,
+    /// 現在のフェーズに入ってからの経過時間（秒）
+    pub phase_elapsed:    f32,
+    /// 現在のフェーズの attacks ごとの次の発動までの残り時間（秒）
+    pub attack_timers:    Vec<f32>,
     pub invincible:       bool,
     pub invincible_timer: f32,
     pub is_dashing:       bool,
@@ -20,20 +28,40 @@ pub struct BossState {
 }
 
 impl BossState {
-    pub fn new(kind_id: u8, x: f32, y: f32) -> Self {
+    pub fn new(id: u32, kind_id: u8, x: f32, y: f32) -> Self {
         let params = BossParams::get(kind_id);
-        Self {
+        let mut boss = Self {
+            id,
             kind_id,
             x, y,
             hp: params.max_hp,
             max_hp: params.max_hp,
-            phase_timer: params.special_interval,
+            phase: 0,
+            phase_elapsed: 0.0,
+            attack_timers: Vec::new(),
             invincible: false,
             invincible_timer: 0.0,
             is_dashing: false,
             dash_timer: 0.0,
             dash_vx: 0.0,
             dash_vy: 0.0,
-        }
+        };
+        boss.enter_phase(0);
+        boss
+    }
+
+    /// 現在のフェーズ定義（テーブル差し替えでフェーズ数が減っていても最後のフェーズを返す）
+    pub fn current_phase(&self) -> &'static BossPhase {
+        let phases = &BossParams::get(self.kind_id).phases;
+        &phases[self.phase.min(phases.len() - 1)]
+    }
+
+    /// phase に移行し、経過時間と攻撃タイマー（各攻撃の cooldown）をリセットする
+    pub fn enter_phase(&mut self, phase: usize) {
+        self.phase = phase;
+        self.phase_elapsed = 0.0;
+        let attacks = &self.current_phase().attacks;
+        self.attack_timers.clear();
+        self.attack_timers.extend(attacks.iter().map(|a| a.cooldown));
     }
 }
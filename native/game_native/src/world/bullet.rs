@@ -1,13 +1,17 @@
 //! Path: native/game_native/src/world/bullet.rs
 //! Summary: 弾丸 SoA（BulletWorld）と描画種別定数
 
+use game_core::bullet_pattern::{BulletPattern, PatternShot};
+use game_core::checksum::StateHasher;
+use game_core::physics::rng::SimpleRng;
+
 /// 弾丸の描画種別（renderer に渡す kind 値）
 pub const BULLET_KIND_NORMAL:    u8 = 4;  // MagicWand / Axe / Cross（黄色い円）
 pub const BULLET_KIND_FIREBALL:  u8 = 8;  // Fireball（赤橙の炎球）
 pub const BULLET_KIND_LIGHTNING: u8 = 9;  // Lightning（水色の電撃球）
 pub const BULLET_KIND_WHIP:      u8 = 10; // Whip（黄緑の弧状）
 // 11=SlimeKing, 12=BatLord, 13=StoneGolem（ボス render_kind と共有）
-pub const BULLET_KIND_ROCK:      u8 = 14; // StoneGolem の岩弾
+pub const BULLET_KIND_ROCK:      u8 = 14; // ボス・エリートの敵弾（岩弾）
 
 /// 弾丸 SoA（Structure of Arrays）
 pub struct BulletWorld {
@@ -15,6 +19,9 @@ pub struct BulletWorld {
     pub positions_y:  Vec<f32>,
     pub velocities_x: Vec<f32>,
     pub velocities_y: Vec<f32>,
+    /// このフレームの移動前の位置（当たり判定は移動前後を結ぶ線分で行う）
+    pub prev_x:       Vec<f32>,
+    pub prev_y:       Vec<f32>,
     pub damage:       Vec<i32>,
     pub lifetime:     Vec<f32>,
     pub alive:        Vec<bool>,
@@ -24,9 +31,27 @@ pub struct BulletWorld {
     pub render_kind:  Vec<u8>,
     /// 1.3.1: 発射元武器（EnemyKilled イベント用、WeaponKind::as_u8()）
     pub weapon_kind:  Vec<u8>,
+    /// true の弾丸は敵陣営（ボス・遠距離敵）が発射し、プレイヤーにのみ当たる
+    pub hostile:      Vec<bool>,
     pub count:        usize,
     /// 空きスロットのインデックススタック — O(1) でスロットを取得・返却
     free_list:        Vec<usize>,
+    /// 弾幕パターンの delay で発射待ちの敵弾
+    pending:          Vec<PendingShot>,
+    /// パターン生成の作業バッファ（毎回のアロケーションを回避）
+    pattern_buf:      Vec<PatternShot>,
+}
+
+/// 発射待ちの敵弾（delay が 0 以下になったら spawn_hostile する）
+struct PendingShot {
+    x:           f32,
+    y:           f32,
+    vx:          f32,
+    vy:          f32,
+    damage:      i32,
+    lifetime:    f32,
+    render_kind: u8,
+    delay:       f32,
 }
 
 impl BulletWorld {
@@ -36,14 +61,19 @@ impl BulletWorld {
             positions_y:  Vec::new(),
             velocities_x: Vec::new(),
             velocities_y: Vec::new(),
+            prev_x:       Vec::new(),
+            prev_y:       Vec::new(),
             damage:       Vec::new(),
             lifetime:     Vec::new(),
             alive:        Vec::new(),
             piercing:     Vec::new(),
             render_kind:  Vec::new(),
             weapon_kind:  Vec::new(),
+            hostile:      Vec::new(),
             count:        0,
             free_list:    Vec::new(),
+            pending:      Vec::new(),
+            pattern_buf:  Vec::new(),
         }
     }
 
@@ -60,33 +90,100 @@ impl BulletWorld {
         self.spawn_ex(x, y, 0.0, 0.0, 0, lifetime, false, render_kind, 0);
     }
 
-    pub(crate) fn spawn_ex(&mut self, x: f32, y: f32, vx: f32, vy: f32, damage: i32, lifetime: f32, piercing: bool, render_kind: u8, weapon_kind: u8) {
-        if let Some(i) = self.free_list.pop() {
+    /// 敵陣営の弾を生成する。プレイヤーにのみ当たり、damage は 1 ヒットあたりの値
+    #[allow(clippy::too_many_arguments)]
+    pub fn spawn_hostile(&mut self, x: f32, y: f32, vx: f32, vy: f32, damage: i32, lifetime: f32, render_kind: u8) {
+        let i = self.spawn_ex(x, y, vx, vy, damage, lifetime, false, render_kind, 0);
+        self.hostile[i] = true;
+    }
+
+    /// (x, y) から弾幕パターンで敵弾を撃つ。aim はプレイヤーへの角度（ラジアン）。
+    /// delay 付きの弾は update_pending で時間が来たら生成する
+    pub fn fire_pattern(&mut self, pattern: &BulletPattern, x: f32, y: f32, aim: f32, rng: &mut SimpleRng, render_kind: u8) {
+        let mut shots = std::mem::take(&mut self.pattern_buf);
+        shots.clear();
+        pattern.generate(aim, rng, &mut shots);
+        for shot in &shots {
+            let vx = shot.angle.cos() * shot.speed;
+            let vy = shot.angle.sin() * shot.speed;
+            if shot.delay <= 0.0 {
+                self.spawn_hostile(x, y, vx, vy, pattern.damage, pattern.lifetime, render_kind);
+            } else {
+                self.pending.push(PendingShot {
+                    x, y, vx, vy,
+                    damage: pattern.damage,
+                    lifetime: pattern.lifetime,
+                    render_kind,
+                    delay: shot.delay,
+                });
+            }
+        }
+        self.pattern_buf = shots;
+    }
+
+    /// 発射待ちの敵弾の delay を進め、時間が来たものを生成する
+    pub fn update_pending(&mut self, dt: f32) {
+        let mut i = 0;
+        while i < self.pending.len() {
+            self.pending[i].delay -= dt;
+            if self.pending[i].delay > 0.0 {
+                i += 1;
+                continue;
+            }
+            let s = self.pending.swap_remove(i);
+            self.spawn_hostile(s.x, s.y, s.vx, s.vy, s.damage, s.lifetime, s.render_kind);
+        }
+    }
+
+    /// 発射待ちの敵弾をチェックサムに含める
+    pub(crate) fn hash_pending(&self, h: &mut StateHasher) {
+        h.write_u32(self.pending.len() as u32);
+        for s in &self.pending {
+            h.write_f32_pair(s.x, s.y);
+            h.write_f32_pair(s.vx, s.vy);
+            h.write_f32_pair(s.lifetime, s.delay);
+            h.write_u32(s.damage as u32);
+            h.write_u8(s.render_kind);
+        }
+    }
+
+    /// 弾を生成してスロットのインデックスを返す（プレイヤー陣営）
+    pub(crate) fn spawn_ex(&mut self, x: f32, y: f32, vx: f32, vy: f32, damage: i32, lifetime: f32, piercing: bool, render_kind: u8, weapon_kind: u8) -> usize {
+        let i = if let Some(i) = self.free_list.pop() {
             // O(1): フリーリストから空きスロットを取得
             self.positions_x[i]  = x;
             self.positions_y[i]  = y;
             self.velocities_x[i] = vx;
             self.velocities_y[i] = vy;
+            self.prev_x[i]       = x;
+            self.prev_y[i]       = y;
             self.damage[i]       = damage;
             self.lifetime[i]     = lifetime;
             self.alive[i]        = true;
             self.piercing[i]     = piercing;
             self.render_kind[i]  = render_kind;
             self.weapon_kind[i]  = weapon_kind;
+            self.hostile[i]      = false;
+            i
         } else {
             // フリーリストが空なら末尾に追加
             self.positions_x.push(x);
             self.positions_y.push(y);
             self.velocities_x.push(vx);
             self.velocities_y.push(vy);
+            self.prev_x.push(x);
+            self.prev_y.push(y);
             self.damage.push(damage);
             self.lifetime.push(lifetime);
             self.alive.push(true);
             self.piercing.push(piercing);
             self.render_kind.push(render_kind);
             self.weapon_kind.push(weapon_kind);
-        }
+            self.hostile.push(false);
+            self.positions_x.len() - 1
+        };
         self.count += 1;
+        i
     }
 
     pub fn kill(&mut self, i: usize) {
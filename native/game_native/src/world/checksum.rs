@@ -0,0 +1,233 @@
+//! Path: native/game_native/src/world/checksum.rs
+//! Summary: ゲームプレイに影響するワールド状態のチェックサム（リプレイ・同期ずれの検出用）
+//!
+//! プレイヤー・敵・弾・アイテム・ボス・武器/パッシブスロット・乱数ストリームに加え、
+//! スポーンディレクターのタイマー・ワールドの広さ・静的障害物・スポーン領域を含める。
+//! パーティクル・スコアポップアップ・補間用の値・地面タイル層など見た目だけの状態は含めない。
+//! 生存中のエンティティだけをスロット番号付きで混ぜる。
+
+use super::GameWorldInner;
+use game_core::checksum::StateHasher;
+use game_core::physics::collider::ObstacleShape;
+
+impl GameWorldInner {
+    /// 現在の状態のチェックサム。同じシード・同じコマンド列で進めたワールドは同じ値になる
+    pub fn checksum(&self) -> u64 {
+        let mut h = StateHasher::new();
+
+        h.write_u32(self.frame_id);
+        h.write_f32(self.elapsed_seconds);
+        let p = &self.player;
+        h.write_f32_pair(p.x, p.y);
+        h.write_f32_pair(p.input_dx, p.input_dy);
+        h.write_f32_pair(p.hp, p.invincible_timer);
+        h.write_f32(self.player_max_hp);
+        for v in [self.score, self.kill_count, self.exp, self.level] {
+            h.write_u32(v);
+        }
+        h.write_bool(self.level_up_pending);
+        h.write_f32(self.magnet_timer);
+
+        h.write_u32(self.weapon_slots.len() as u32);
+        for s in &self.weapon_slots {
+            h.write_u8(s.kind_id);
+            h.write_u32(s.level);
+            h.write_f32(s.cooldown_timer);
+        }
+        h.write_u32(self.passive_slots.len() as u32);
+        for s in &self.passive_slots {
+            h.write_u8(s.kind_id);
+            h.write_u32(s.level);
+        }
+
+        let rng = self.rng.state();
+        h.write_u64(rng.seed);
+        for s in [rng.spawn, rng.loot, rng.combat, rng.ai] {
+            h.write_u64(s.state);
+            h.write_u64(s.inc);
+        }
+
+        let e = &self.enemies;
+        for i in (0..e.len()).filter(|&i| e.alive[i]) {
+            h.write_u32(i as u32);
+            h.write_u8(e.kind_ids[i]);
+            h.write_bool(e.is_elite[i]);
+            h.write_f32_pair(e.positions_x[i], e.positions_y[i]);
+            h.write_f32_pair(e.velocities_x[i], e.velocities_y[i]);
+            h.write_f32_pair(e.hp[i], e.stun_timers[i]);
+            h.write_f32(e.shot_timers[i]);
+            let st = &e.statuses[i];
+            h.write_f32(st.tick_timer);
+            for (k, slot) in st.slots.iter().enumerate().filter(|(_, s)| s.is_active()) {
+                h.write_u8(k as u8);
+                h.write_f32_pair(slot.remaining, slot.potency);
+                h.write_u8(slot.stacks);
+                h.write_u8(slot.source_weapon);
+            }
+        }
+
+        let b = &self.bullets;
+        for i in (0..b.len()).filter(|&i| b.alive[i]) {
+            h.write_u32(i as u32);
+            h.write_f32_pair(b.positions_x[i], b.positions_y[i]);
+            h.write_f32_pair(b.velocities_x[i], b.velocities_y[i]);
+            h.write_f32(b.lifetime[i]);
+            h.write_u32(b.damage[i] as u32);
+            h.write_u8(b.weapon_kind[i]);
+            h.write_bool(b.piercing[i]);
+            h.write_bool(b.hostile[i]);
+        }
+        b.hash_pending(&mut h);
+
+        let it = &self.items;
+        for i in (0..it.len()).filter(|&i| it.alive[i]) {
+            h.write_u32(i as u32);
+            h.write_u8(it.kinds[i] as u8);
+            h.write_f32_pair(it.positions_x[i], it.positions_y[i]);
+            h.write_u32(it.value[i]);
+        }
+
+        h.write_u32(self.next_boss_id);
+        for boss in &self.bosses {
+            h.write_u32(boss.id);
+            h.write_u8(boss.kind_id);
+            h.write_f32_pair(boss.x, boss.y);
+            h.write_f32_pair(boss.hp, boss.max_hp);
+            h.write_u32(boss.phase as u32);
+            h.write_f32(boss.phase_elapsed);
+            for &t in &boss.attack_timers {
+                h.write_f32(t);
+            }
+            h.write_bool(boss.invincible);
+            h.write_f32(boss.invincible_timer);
+            h.write_bool(boss.is_dashing);
+            h.write_f32(boss.dash_timer);
+            h.write_f32_pair(boss.dash_vx, boss.dash_vy);
+        }
+
+        // スケジュール本体は set_spawn_schedule 以降変わらないため、ウェーブ数とタイマーだけを混ぜる
+        match &self.spawn_director {
+            Some(director) => {
+                h.write_u32(director.timers().len() as u32);
+                for &t in director.timers() {
+                    h.write_f32(t);
+                }
+            }
+            None => h.write_u32(u32::MAX),
+        }
+
+        // マップ由来の状態（広さ・障害物・スポーン領域）は移動・衝突・スポーン位置に効く
+        h.write_f32_pair(self.bounds.width, self.bounds.height);
+        h.write_u32(self.collision.obstacles.len() as u32);
+        for o in &self.collision.obstacles {
+            h.write_f32_pair(o.x, o.y);
+            h.write_u8(o.kind);
+            hash_shape(&mut h, &o.shape);
+        }
+        let zones = self.tile_map.as_ref().map_or(&[][..], |m| &m.spawn_zones);
+        h.write_u32(zones.len() as u32);
+        for z in zones {
+            h.write_f32_pair(z.x, z.y);
+            h.write_f32_pair(z.width, z.height);
+        }
+
+        h.finish()
+    }
+}
+
+fn hash_shape(h: &mut StateHasher, shape: &ObstacleShape) {
+    match shape {
+        ObstacleShape::Circle { radius } => {
+            h.write_u8(0);
+            h.write_f32(*radius);
+        }
+        ObstacleShape::Rect { width, height } => {
+            h.write_u8(1);
+            h.write_f32_pair(*width, *height);
+        }
+        ObstacleShape::Polygon { points } => {
+            h.write_u8(2);
+            h.write_u32(points.len() as u32);
+            for &[x, y] in points {
+                h.write_f32_pair(x, y);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_logic::apply_command;
+    use game_core::replay::ReplayCommand;
+
+    /// 同じコマンド列を適用したワールドを返す。input は移動方向を切り替える周期ごとの入力
+    fn run(seed: u64, inputs: &[(f32, f32)]) -> GameWorldInner {
+        let mut w = GameWorldInner::new(seed);
+        let commands = [
+            ReplayCommand::SpawnEnemies { kind_id: 0, count: 30 },
+            ReplayCommand::SpawnElite { kind_id: 1, count: 2, hp_multiplier: 0.0 },
+            ReplayCommand::AddWeapon { weapon_id: 1 },
+        ];
+        for cmd in commands {
+            apply_command(&mut w, cmd).unwrap();
+        }
+        for frame in 0..300 {
+            let (dx, dy) = inputs[frame / 60 % inputs.len()];
+            apply_command(&mut w, ReplayCommand::SetInput { dx, dy }).unwrap();
+            apply_command(&mut w, ReplayCommand::Step { delta_ms: 1000.0 / 60.0 }).unwrap();
+        }
+        w
+    }
+
+    #[test]
+    fn identical_inputs_produce_identical_checksums() {
+        let inputs = [(1.0, 0.0), (0.0, -1.0), (-0.7, 0.7)];
+        let a = run(7, &inputs);
+        let b = run(7, &inputs);
+        assert_eq!(a.frame_id, 300);
+        assert_eq!(a.checksum(), b.checksum());
+    }
+
+    #[test]
+    fn different_input_or_seed_changes_the_checksum() {
+        let inputs = [(1.0, 0.0), (0.0, -1.0)];
+        let base = run(7, &inputs).checksum();
+        assert_ne!(base, run(7, &[(1.0, 0.0), (0.0, 1.0)]).checksum());
+        assert_ne!(base, run(8, &inputs).checksum());
+    }
+
+    #[test]
+    fn map_and_spawn_director_state_changes_the_checksum() {
+        use game_core::physics::bounds::WorldBounds;
+        use game_core::physics::rng::SimpleRng;
+        use game_core::physics::spatial_hash::StaticObstacle;
+        use game_core::spawn_director::{SpawnDirector, SpawnSchedule};
+
+        let mut w = GameWorldInner::new(7);
+        let base = w.checksum();
+
+        w.bounds = WorldBounds::new(w.bounds.width + 64.0, w.bounds.height);
+        let resized = w.checksum();
+        assert_ne!(base, resized);
+
+        w.collision.set_static(vec![StaticObstacle::circle(100.0, 100.0, 30.0, 1)]);
+        let with_obstacle = w.checksum();
+        assert_ne!(resized, with_obstacle);
+
+        let schedule = SpawnSchedule::from_json(
+            r#"{ "waves": [{ "start": 0.0, "interval": 2.0, "count": 1, "mix": [{ "enemy": 0, "weight": 1 }] }] }"#,
+            1,
+        )
+        .unwrap();
+        w.spawn_director = Some(SpawnDirector::new(schedule));
+        let with_director = w.checksum();
+        assert_ne!(with_obstacle, with_director);
+
+        // タイマーが進めば（同じフレームでも）値が変わる
+        let mut batches = Vec::new();
+        let director = w.spawn_director.as_mut().unwrap();
+        director.tick(0.5, 0.5, 0, &mut SimpleRng::new(1), &mut batches);
+        assert_ne!(with_director, w.checksum());
+    }
+}
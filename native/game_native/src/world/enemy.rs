@@ -1,8 +1,9 @@
 //! Path: native/game_native/src/world/enemy.rs
 //! Summary: 敵 SoA（EnemyWorld）と EnemySeparation の実装
 
-use game_core::entity_params::EnemyParams;
-use game_core::physics::separation::EnemySeparation;
+use game_core::entity_params::{EliteParams, EnemyParams};
+use game_core::physics::separation::{EnemySeparation, SeparationSlices};
+use game_core::status_effect::StatusEffects;
 
 /// 敵 SoA（Structure of Arrays）
 #[derive(Clone)]
@@ -15,6 +16,14 @@ pub struct EnemyWorld {
     pub hp:           Vec<f32>,
     pub alive:        Vec<bool>,
     pub kind_ids:     Vec<u8>,
+    /// エリート（EliteParams の補正を受ける）
+    pub is_elite:     Vec<bool>,
+    /// 状態異常（燃焼・毒・凍結・鈍足・弱体）。speeds は status システムが毎フレーム再計算する
+    pub statuses:     Vec<StatusEffects>,
+    /// のけぞり残り時間（秒）。> 0 の間は Chase AI が追尾せず、velocities（ノックバック）で流される
+    pub stun_timers:  Vec<f32>,
+    /// エリートの弾幕攻撃（EliteParams::attack）の次の発動までの残り時間（秒）
+    pub shot_timers:  Vec<f32>,
     pub count:        usize,
     /// 分離パス用の作業バッファ（毎フレーム再利用してアロケーションを回避）
     pub sep_x:        Vec<f32>,
@@ -36,6 +45,10 @@ impl EnemyWorld {
             hp:           Vec::new(),
             alive:        Vec::new(),
             kind_ids:     Vec::new(),
+            is_elite:     Vec::new(),
+            statuses:     Vec::new(),
+            stun_timers:  Vec::new(),
+            shot_timers:  Vec::new(),
             count:        0,
             sep_x:        Vec::new(),
             sep_y:        Vec::new(),
@@ -48,6 +61,13 @@ impl EnemyWorld {
         self.positions_x.len()
     }
 
+    /// 当たり判定の半径（エリートは EliteParams::size_multiplier 倍）。
+    /// 座標は左上基準のため、中心は (x + radius, y + radius)
+    pub fn radius(&self, i: usize) -> f32 {
+        let r = EnemyParams::get(self.kind_ids[i]).radius;
+        if self.is_elite[i] { r * EliteParams::get().size_multiplier } else { r }
+    }
+
     pub fn kill(&mut self, i: usize) {
         if self.alive[i] {
             self.alive[i] = false;
@@ -56,11 +76,40 @@ impl EnemyWorld {
         }
     }
 
+    /// (dir_x, dir_y) 方向へ吹き飛ばし、stun 秒のけぞらせる。
+    /// 速度は impulse / mass（重い敵ほど飛ばない）。方向ベクトルは正規化不要。
+    /// のけぞり中以外は Chase AI が速度を上書きするため、stun = 0 ならノックバックもしない
+    pub fn knock_back(&mut self, i: usize, dir_x: f32, dir_y: f32, impulse: f32, stun: f32) {
+        if !self.alive[i] || stun <= 0.0 {
+            return;
+        }
+        let len = (dir_x * dir_x + dir_y * dir_y).sqrt();
+        let speed = impulse / EnemyParams::get(self.kind_ids[i]).mass;
+        if len > 0.001 {
+            self.velocities_x[i] = dir_x / len * speed;
+            self.velocities_y[i] = dir_y / len * speed;
+        }
+        self.stun_timers[i] = self.stun_timers[i].max(stun);
+    }
+
     /// 指定 ID の敵を `positions` の座標にスポーン（O(1) でスロット取得）
     pub fn spawn(&mut self, positions: &[(f32, f32)], kind_id: u8) {
-        let params = EnemyParams::get(kind_id);
-        let speed  = params.speed;
-        let max_hp = params.max_hp;
+        let max_hp = EnemyParams::get(kind_id).max_hp;
+        self.spawn_inner(positions, kind_id, max_hp, false);
+    }
+
+    /// 指定 ID のエリート敵をスポーンする（HP は max_hp × hp_multiplier）
+    pub fn spawn_elite(&mut self, positions: &[(f32, f32)], kind_id: u8, hp_multiplier: f32) {
+        let max_hp = EnemyParams::get(kind_id).max_hp * hp_multiplier;
+        self.spawn_inner(positions, kind_id, max_hp, true);
+    }
+
+    fn spawn_inner(&mut self, positions: &[(f32, f32)], kind_id: u8, max_hp: f32, is_elite: bool) {
+        let speed = EnemyParams::get(kind_id).speed;
+        let shot_timer = match EliteParams::get().attack {
+            Some(attack) if is_elite => attack.cooldown,
+            _ => 0.0,
+        };
 
         for &(x, y) in positions {
             if let Some(i) = self.free_list.pop() {
@@ -73,6 +122,10 @@ impl EnemyWorld {
                 self.hp[i]           = max_hp;
                 self.alive[i]        = true;
                 self.kind_ids[i]     = kind_id;
+                self.is_elite[i]     = is_elite;
+                self.statuses[i].clear();
+                self.stun_timers[i]  = 0.0;
+                self.shot_timers[i]  = shot_timer;
                 self.sep_x[i]        = 0.0;
                 self.sep_y[i]        = 0.0;
             } else {
@@ -84,6 +137,10 @@ impl EnemyWorld {
                 self.hp.push(max_hp);
                 self.alive.push(true);
                 self.kind_ids.push(kind_id);
+                self.is_elite.push(is_elite);
+                self.statuses.push(StatusEffects::default());
+                self.stun_timers.push(0.0);
+                self.shot_timers.push(shot_timer);
                 self.sep_x.push(0.0);
                 self.sep_y.push(0.0);
             }
@@ -102,4 +159,13 @@ impl EnemySeparation for EnemyWorld {
     fn sep_buf_x(&mut self) -> &mut Vec<f32>  { &mut self.sep_x }
     fn sep_buf_y(&mut self) -> &mut Vec<f32>  { &mut self.sep_y }
     fn neighbor_buf(&mut self) -> &mut Vec<usize> { &mut self.neighbor_buf }
+    fn separation_slices(&mut self) -> SeparationSlices<'_> {
+        SeparationSlices {
+            pos_x: &self.positions_x,
+            pos_y: &self.positions_y,
+            alive: &self.alive,
+            sep_x: &mut self.sep_x,
+            sep_y: &mut self.sep_y,
+        }
+    }
 }
@@ -4,9 +4,14 @@
 /// 1.3.1: フレーム内で発生したゲームイベント（EventBus 用）
 #[derive(Debug, Clone)]
 pub enum FrameEvent {
-    EnemyKilled  { enemy_kind: u8, weapon_kind: u8 },
+    /// is_elite の撃破は elite_enemy_killed として Elixir に渡す
+    EnemyKilled  { enemy_kind: u8, weapon_kind: u8, is_elite: bool },
     PlayerDamaged { damage: f32 },
     LevelUp      { new_level: u32 },
     ItemPickup   { item_kind: u8 },
-    BossDefeated { boss_kind: u8 },
+    BossDefeated { boss_kind: u8, boss_id: u32 },
+    /// ボスが次のフェーズに移行した（phase は BossParams::phases のインデックス）
+    BossPhaseChanged { boss_id: u32, phase: u32 },
+    /// 最大 Lv の武器が対になるパッシブと揃って進化した
+    WeaponEvolved { from_weapon: u8, to_weapon: u8 },
 }
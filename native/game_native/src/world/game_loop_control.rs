@@ -1,15 +1,18 @@
 //! Path: native/game_native/src/world/game_loop_control.rs
-//! Summary: GameLoop 制御用（pause/resume）リソース
+//! Summary: GameLoop 制御用（pause/resume、チェックサム送信間隔）リソース
 
 /// 1.5.1: GameLoop 制御用（pause/resume）
 pub struct GameLoopControl {
     paused: std::sync::atomic::AtomicBool,
+    /// frame_events にチェックサムを付ける間隔（フレーム数、0 なら付けない）
+    checksum_interval: std::sync::atomic::AtomicU32,
 }
 
 impl GameLoopControl {
     pub fn new() -> Self {
         Self {
             paused: std::sync::atomic::AtomicBool::new(false),
+            checksum_interval: std::sync::atomic::AtomicU32::new(0),
         }
     }
     pub fn pause(&self) {
@@ -21,4 +24,10 @@ impl GameLoopControl {
     pub fn is_paused(&self) -> bool {
         self.paused.load(std::sync::atomic::Ordering::SeqCst)
     }
+    pub fn set_checksum_interval(&self, frames: u32) {
+        self.checksum_interval.store(frames, std::sync::atomic::Ordering::SeqCst);
+    }
+    pub fn checksum_interval(&self) -> u32 {
+        self.checksum_interval.load(std::sync::atomic::Ordering::SeqCst)
+    }
 }
@@ -1,11 +1,19 @@
 //! Path: native/game_native/src/world/game_world.rs
 //! Summary: ゲームワールド（GameWorldInner, GameWorld）
 
-use super::{BossState, BulletWorld, EnemyWorld, ParticleWorld, PlayerState};
+use super::{BossState, BulletWorld, EnemyWorld, ParticleWorld, PlayerState, ReplayRecorder};
+use crate::game_logic::WeaponRegistry;
+use game_core::constants::{CELL_SIZE, PLAYER_BASE_MAX_HP, PLAYER_SIZE};
 use game_core::item::ItemWorld;
-use game_core::physics::rng::SimpleRng;
+use game_core::physics::bounds::WorldBounds;
+use game_core::physics::rng::RngStreams;
 use game_core::physics::spatial_hash::CollisionWorld;
+use game_core::player_stats::{PassiveSlot, PlayerStats};
+use game_core::replay::{ReplayCheckpoint, ReplayCommand};
+use game_core::spawn_director::SpawnDirector;
+use game_core::tile_map::TileMap;
 use game_core::weapon::WeaponSlot;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Mutex, RwLock};
 
 use super::FrameEvent;
@@ -21,8 +29,13 @@ pub struct GameWorldInner {
     pub items:              ItemWorld,
     /// 磁石エフェクト残り時間（秒）
     pub magnet_timer:       f32,
-    pub rng:                SimpleRng,
+    /// 用途別の乱数ストリーム（create_world のシードから作る）
+    pub rng:                RngStreams,
     pub collision:          CollisionWorld,
+    /// ワールドの広さ（プレイヤー・敵・ボス・弾・カメラ共通）
+    pub bounds:             WorldBounds,
+    /// load_map で読み込んだマップ（地面タイル・スポーン領域）。None なら set_map_obstacles の障害物のみ
+    pub tile_map:           Option<TileMap>,
     /// 1.5.2: 障害物クエリ用バッファ（毎フレーム再利用）
     pub obstacle_query_buf: Vec<usize>,
     /// 直近フレームの物理ステップ処理時間（ミリ秒）
@@ -31,7 +44,7 @@ pub struct GameWorldInner {
     pub score:              u32,
     /// ゲーム開始からの経過時間（秒）
     pub elapsed_seconds:    f32,
-    /// プレイヤーの最大 HP（HP バー計算用）
+    /// プレイヤーの最大 HP（HP バー計算用。player_stats.max_hp と同期する）
     pub player_max_hp:      f32,
     /// 1.1.14: 現在の経験値
     pub exp:                u32,
@@ -41,8 +54,18 @@ pub struct GameWorldInner {
     pub level_up_pending:   bool,
     /// 装備中の武器スロット（最大 6 つ）
     pub weapon_slots:       Vec<WeaponSlot>,
-    /// 1.2.9: ボスエネミー
-    pub boss:               Option<BossState>,
+    /// 所持中のパッシブアイテム（最大 MAX_PASSIVE_SLOTS）
+    pub passive_slots:      Vec<PassiveSlot>,
+    /// passive_slots から算出した能力値（passive_slots を変えたら refresh_player_stats を呼ぶ）
+    pub player_stats:       PlayerStats,
+    /// 武器 ID → 発射挙動（ワールド生成時に entity_params から構築）
+    pub weapon_registry:    WeaponRegistry,
+    /// 1.2.9: ボスエネミー（同時に最大 MAX_BOSSES 体。撃破したボスは取り除く）
+    pub bosses:             Vec<BossState>,
+    /// 次に出現するボスの ID
+    pub next_boss_id:       u32,
+    /// set_spawn_schedule で設定したスポーンディレクター（None なら Elixir 側が spawn_enemies で出す）
+    pub spawn_director:     Option<SpawnDirector>,
     /// 1.3.1: このフレームで発生したイベント（毎フレーム drain される）
     pub frame_events:       Vec<FrameEvent>,
     /// 1.7.5: 描画スレッドからの UI アクション（Start/Retry/武器選択/Save/Load 等）
@@ -61,9 +84,67 @@ pub struct GameWorldInner {
     pub prev_tick_ms:       u64,
     /// 1.10.7: 補間用 - 現在フレームの更新タイムスタンプ（ms）
     pub curr_tick_ms:       u64,
+    /// start_replay_recording で開始したリプレイの記録先（None なら記録しない）
+    pub replay:             Option<ReplayRecorder>,
 }
 
 impl GameWorldInner {
+    /// 新しいワールド。プレイヤーはワールドの中央から始め、乱数は seed から作る
+    pub fn new(seed: u64) -> Self {
+        let bounds = WorldBounds::default();
+        let (cx, cy) = bounds.center();
+        Self {
+            frame_id:           0,
+            player:             PlayerState {
+                x:                cx - PLAYER_SIZE / 2.0,
+                y:                cy - PLAYER_SIZE / 2.0,
+                input_dx:         0.0,
+                input_dy:         0.0,
+                hp:               PLAYER_BASE_MAX_HP,
+                invincible_timer: 0.0,
+            },
+            enemies:            EnemyWorld::new(),
+            bullets:            BulletWorld::new(),
+            particles:          ParticleWorld::new(seed),
+            items:              ItemWorld::new(),
+            magnet_timer:       0.0,
+            rng:                RngStreams::new(seed),
+            collision:          CollisionWorld::new(CELL_SIZE),
+            bounds,
+            tile_map:           None,
+            obstacle_query_buf: Vec::new(),
+            last_frame_time_ms: 0.0,
+            score:              0,
+            elapsed_seconds:    0.0,
+            player_max_hp:      PLAYER_BASE_MAX_HP,
+            exp:                0,
+            level:              1,
+            level_up_pending:   false,
+            weapon_slots:       vec![WeaponSlot::new(0)], // MagicWand
+            passive_slots:      Vec::new(),
+            player_stats:       PlayerStats::default(),
+            weapon_registry:    WeaponRegistry::from_entity_params(),
+            bosses:             Vec::new(),
+            next_boss_id:       0,
+            spawn_director:     None,
+            frame_events:       Vec::new(),
+            pending_ui_action:  Mutex::new(None),
+            weapon_choices:     Vec::new(),
+            score_popups:       Vec::new(),
+            kill_count:         0,
+            prev_player_x:      cx - PLAYER_SIZE / 2.0,
+            prev_player_y:      cy - PLAYER_SIZE / 2.0,
+            prev_tick_ms:       0,
+            curr_tick_ms:       0,
+            replay:             None,
+        }
+    }
+
+    /// ボス ID から bosses のインデックスを引く（撃破済みなら None）
+    pub(crate) fn boss_index(&self, id: u32) -> Option<usize> {
+        self.bosses.iter().position(|b| b.id == id)
+    }
+
     /// レベルアップ処理を完了する（武器選択・スキップ共通）
     pub(crate) fn complete_level_up(&mut self) {
         self.level += 1;
@@ -71,22 +152,89 @@ impl GameWorldInner {
         self.weapon_choices.clear();
     }
 
-    /// 衝突判定用の Spatial Hash を再構築する（clone 不要）
+    /// passive_slots から player_stats を再計算する。最大 HP が増えた分だけ HP も回復する
+    pub(crate) fn refresh_player_stats(&mut self) {
+        self.player_stats = PlayerStats::from_passives(&self.passive_slots);
+        let gained = self.player_stats.max_hp - self.player_max_hp;
+        self.player_max_hp = self.player_stats.max_hp;
+        if gained > 0.0 {
+            self.player.hp += gained;
+        }
+        self.player.hp = self.player.hp.min(self.player_max_hp);
+    }
+
+    /// リプレイ記録中ならコマンドを現在のフレーム ID で書き出す
+    pub(crate) fn record_command(&mut self, cmd: &ReplayCommand) {
+        let frame_id = self.frame_id;
+        if let Some(result) = self.replay.as_mut().map(|r| r.write_command(frame_id, cmd)) {
+            self.check_replay_write(result);
+        }
+    }
+
+    /// リプレイ記録中ならチェックポイントを書き出す
+    pub(crate) fn record_checkpoint(&mut self) {
+        let (frame_id, cp) = (self.frame_id, self.checkpoint());
+        if let Some(result) = self.replay.as_mut().map(|r| r.write_checkpoint(frame_id, &cp)) {
+            self.check_replay_write(result);
+        }
+    }
+
+    /// 書き込みに失敗したら記録を止める（ゲームは続ける）
+    fn check_replay_write(&mut self, result: std::io::Result<()>) {
+        if let Err(e) = result {
+            if let Some(r) = self.replay.take() {
+                log::error!("Replay recording to {} stopped: {e}", r.path());
+            }
+        }
+    }
+
+    /// リプレイ再生でずれを検出するための状態の要約（ずれた値を読めるよう主要な値とチェックサムを持つ）
+    pub(crate) fn checkpoint(&self) -> ReplayCheckpoint {
+        ReplayCheckpoint {
+            player_x:      self.player.x,
+            player_y:      self.player.y,
+            player_hp:     self.player.hp,
+            score:         self.score,
+            kill_count:    self.kill_count,
+            level:         self.level,
+            exp:           self.exp,
+            alive_enemies: self.enemies.alive.iter().filter(|&&a| a).count() as u32,
+            bullets:       self.bullets.count as u32,
+            checksum:      self.checksum(),
+        }
+    }
+
+    /// 衝突判定用のグリッドを再構築する（clone・アロケーション不要）
     pub(crate) fn rebuild_collision(&mut self) {
-        self.collision.dynamic.clear();
-        self.enemies.alive
-            .iter()
-            .enumerate()
-            .filter(|&(_, &is_alive)| is_alive)
-            .for_each(|(i, _)| {
-                self.collision.dynamic.insert(
-                    i,
-                    self.enemies.positions_x[i],
-                    self.enemies.positions_y[i],
-                );
-            });
+        let enemies = &self.enemies;
+        self.collision.dynamic.rebuild(
+            (0..enemies.len())
+                .filter(|&i| enemies.alive[i])
+                .map(|i| (i, enemies.positions_x[i], enemies.positions_y[i])),
+        );
     }
 }
 
-/// ゲームワールド（RwLock で保護された内部状態）
+/// ゲームワールド（RwLock で保護された内部状態）。生成は GameWorld::new を通す
 pub struct GameWorld(pub RwLock<GameWorldInner>);
+
+/// 生存中の GameWorld の数（entity_params の差し替え可否の判定に使う）
+static LIVE_WORLDS: AtomicUsize = AtomicUsize::new(0);
+
+impl GameWorld {
+    pub fn new(inner: GameWorldInner) -> Self {
+        LIVE_WORLDS.fetch_add(1, Ordering::AcqRel);
+        Self(RwLock::new(inner))
+    }
+
+    /// 生存中のワールドの数
+    pub fn live_count() -> usize {
+        LIVE_WORLDS.load(Ordering::Acquire)
+    }
+}
+
+impl Drop for GameWorld {
+    fn drop(&mut self) {
+        LIVE_WORLDS.fetch_sub(1, Ordering::AcqRel);
+    }
+}
@@ -1,14 +1,16 @@
 //! Path: native/game_native/src/world/mod.rs
-//! Summary: ワールド型（PlayerState, EnemyWorld, BulletWorld, ParticleWorld, BossState, GameWorld）
+//! Summary: ワールド型（PlayerState, EnemyWorld, BulletWorld, ParticleWorld, BossState, GameWorld, ReplayRecorder）
 
 mod boss;
 mod bullet;
+mod checksum;
 mod enemy;
 mod frame_event;
 mod game_loop_control;
 mod game_world;
 mod particle;
 mod player;
+mod replay_recorder;
 
 pub use boss::BossState;
 pub use bullet::{BulletWorld, BULLET_KIND_FIREBALL, BULLET_KIND_LIGHTNING, BULLET_KIND_NORMAL, BULLET_KIND_ROCK, BULLET_KIND_WHIP};
@@ -18,3 +20,4 @@ pub use game_loop_control::GameLoopControl;
 pub use game_world::{GameWorld, GameWorldInner};
 pub use particle::ParticleWorld;
 pub use player::PlayerState;
+pub use replay_recorder::ReplayRecorder;
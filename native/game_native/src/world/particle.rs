@@ -1,7 +1,7 @@
 //! Path: native/game_native/src/world/particle.rs
 //! Summary: パーティクル SoA（ParticleWorld）
 
-use game_core::physics::rng::SimpleRng;
+use game_core::physics::rng::{RngStream, SimpleRng};
 
 /// パーティクル SoA（Structure of Arrays）
 pub struct ParticleWorld {
@@ -21,6 +21,7 @@ pub struct ParticleWorld {
 }
 
 impl ParticleWorld {
+    /// seed はワールドのシード（パーティクル用ストリームを使う）
     pub fn new(seed: u64) -> Self {
         Self {
             positions_x:  Vec::new(),
@@ -33,7 +34,7 @@ impl ParticleWorld {
             size:         Vec::new(),
             alive:        Vec::new(),
             count:        0,
-            rng:          SimpleRng::new(seed),
+            rng:          SimpleRng::for_stream(seed, RngStream::Particles),
             free_list:    Vec::new(),
         }
     }
@@ -0,0 +1,38 @@
+//! Path: native/game_native/src/world/replay_recorder.rs
+//! Summary: リプレイの記録先ファイル（start_replay_recording で作り、GameWorldInner が持つ）
+
+use game_core::replay::{ReplayCheckpoint, ReplayCommand, ReplayHeader, ReplayWriter};
+use std::fs::File;
+use std::io::{self, BufWriter};
+
+pub struct ReplayRecorder {
+    writer: ReplayWriter<BufWriter<File>>,
+    path:   String,
+}
+
+impl ReplayRecorder {
+    /// path にヘッダを書いたリプレイファイルを作る（既存のファイルは上書き）
+    pub fn create(path: &str, seed: u64) -> io::Result<Self> {
+        let file = BufWriter::new(File::create(path)?);
+        let writer = ReplayWriter::new(file, ReplayHeader { seed })?;
+        Ok(Self { writer, path: path.to_string() })
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn write_command(&mut self, frame_id: u32, cmd: &ReplayCommand) -> io::Result<()> {
+        self.writer.write_command(frame_id, cmd)
+    }
+
+    pub fn write_checkpoint(&mut self, frame_id: u32, cp: &ReplayCheckpoint) -> io::Result<()> {
+        self.writer.write_checkpoint(frame_id, cp)
+    }
+
+    /// バッファをファイルに書き出して閉じる
+    pub fn finish(self) -> io::Result<()> {
+        self.writer.finish()?;
+        Ok(())
+    }
+}
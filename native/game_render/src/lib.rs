@@ -4,6 +4,12 @@ pub const BULLET_KIND_LIGHTNING: u8 = 9;
 pub const BULLET_KIND_WHIP: u8 = 10;
 pub const BULLET_KIND_ROCK: u8 = 14;
 
+/// 1.2.10: エリート敵の kind（= 通常の render_kind + このオフセット）
+pub const ELITE_RENDER_KIND_OFFSET: u8 = 20;
+
+/// render_data の乗算カラー（状態異常などの色付けなし）
+pub const TINT_NONE: [f32; 4] = [1.0, 1.0, 1.0, 1.0];
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum UiAction {
     Start,
@@ -33,10 +39,14 @@ impl UiAction {
 
 #[derive(Clone, Default)]
 pub struct RenderFrame {
-    pub render_data: Vec<(f32, f32, u8, u8)>,
+    /// (x, y, kind, anim_frame, tint)。tint はスプライト既定色に乗算する（状態異常の色付け等）
+    pub render_data: Vec<(f32, f32, u8, u8, [f32; 4])>,
     pub particle_data: Vec<(f32, f32, f32, f32, f32, f32, f32)>,
     pub item_data: Vec<(f32, f32, u8)>,
-    pub obstacle_data: Vec<(f32, f32, f32, u8)>,
+    /// 障害物 (左上 x, 左上 y, 幅, 高さ, kind, 円か)。矩形・多角形は四角いスプライトの集まりで描く
+    pub obstacle_data: Vec<(f32, f32, f32, f32, u8, bool)>,
+    /// 地面タイル (左上 x, 左上 y, 一辺, 色)。load_map したマップの画面内の分のみ（下の層から順）
+    pub tile_data: Vec<(f32, f32, f32, [f32; 4])>,
     pub camera_offset: (f32, f32),
     /// プレイヤーのスプライト位置（補間後に上書きされる専用フィールド）。
     /// render_data[0] への暗黙的な依存を排除するために独立させている。
@@ -3,7 +3,8 @@
 //! 1.8: game_native から game_render へ分離移設。
 
 use game_core::constants::{BG_B, BG_G, BG_R, SPRITE_SIZE};
-use game_core::item::{RENDER_KIND_GEM, RENDER_KIND_MAGNET, RENDER_KIND_POTION};
+use game_core::entity_params::EliteParams;
+use game_core::item::{RENDER_KIND_CHEST, RENDER_KIND_GEM, RENDER_KIND_MAGNET, RENDER_KIND_POTION};
 use std::sync::Arc;
 use wgpu::util::DeviceExt;
 use winit::window::Window;
@@ -17,9 +18,6 @@ pub enum GamePhase {
     GameOver,
 }
 
-const ELITE_RENDER_KIND_OFFSET: u8 = 20;
-const ELITE_SIZE_MULTIPLIER: f32 = 1.2;
-
 // ─── 頂点・インデックス ────────────────────────────────────────
 
 #[repr(C)]
@@ -69,6 +67,8 @@ pub struct SpriteInstance {
 //   [1344..1407] Bat Lord
 //   [1408..1471] Stone Golem
 //   [1472..1535] 岩弾
+// 地面タイル（64x64、白地。タイルごとの色を乗算して使う）:
+//   [1536..1599] 地面タイル
 const ATLAS_W: f32 = 1600.0;
 const FRAME_W: f32 = 64.0; // 1 フレームの幅（px）
 
@@ -89,6 +89,7 @@ const SLIME_KING_ATLAS_OFFSET_X: f32 = 1280.0;
 const BAT_LORD_ATLAS_OFFSET_X: f32 = 1344.0;
 const STONE_GOLEM_ATLAS_OFFSET_X: f32 = 1408.0;
 const ROCK_BULLET_ATLAS_OFFSET_X: f32 = 1472.0;
+const GROUND_TILE_ATLAS_OFFSET_X: f32 = 1536.0;
 
 /// プレイヤーのアニメーション UV（フレーム番号 0〜3）
 pub fn player_anim_uv(frame: u8) -> ([f32; 2], [f32; 2]) {
@@ -149,6 +150,9 @@ pub fn stone_golem_uv() -> ([f32; 2], [f32; 2]) {
 pub fn rock_bullet_uv() -> ([f32; 2], [f32; 2]) {
     ([ROCK_BULLET_ATLAS_OFFSET_X / ATLAS_W, 0.0], [FRAME_W / ATLAS_W, 1.0])
 }
+pub fn ground_tile_uv() -> ([f32; 2], [f32; 2]) {
+    ([GROUND_TILE_ATLAS_OFFSET_X / ATLAS_W, 0.0], [FRAME_W / ATLAS_W, 1.0])
+}
 
 // ─── 画面サイズ Uniform ────────────────────────────────────────
 
@@ -184,8 +188,9 @@ impl CameraUniform {
 }
 
 // ─── インスタンスバッファの最大容量 ────────────────────────────
-// Player 1 + Boss 1 + Enemies 10000 + Bullets 2000 + Particles 2000 + Items 500 = 14502
-const MAX_INSTANCES: usize = 14502;
+// Player 1 + Boss 1 + Enemies 10000 + Bullets 2000 + Particles 2000 + Items 500
+// + 地面タイル 2048（64px タイル 2 層分の画面 + 余白）= 16550
+const MAX_INSTANCES: usize = 16550;
 
 // 敵タイプ別のスプライトサイズ（px）
 // kind: 1=slime(40px), 2=bat(24px), 3=golem(64px), 4=ghost(32px), 5=skeleton(40px)
@@ -243,13 +248,17 @@ pub struct HudData {
     pub fps:              f32,
     pub level_up_pending: bool,
     pub weapon_choices:   Vec<String>,
+    /// weapon_choices と同順のアップグレード説明行（WeaponBehavior::upgrade_desc / PassiveParams::upgrade_desc）
+    pub weapon_upgrade_descs: Vec<Vec<String>>,
     pub weapon_levels:    Vec<(String, u32)>,
+    /// 所持中のパッシブアイテム [(name, level)]
+    pub passive_levels:   Vec<(String, u32)>,
     pub magnet_timer:     f32,
     pub item_count:       usize,
     pub camera_x:         f32,
     pub camera_y:         f32,
-    /// 1.2.9: ボス情報（ボスが存在しない場合は None）
-    pub boss_info:        Option<BossHudInfo>,
+    /// 1.2.9: ボス情報（出現順。ボスが存在しない場合は空）
+    pub boss_info:        Vec<BossHudInfo>,
     // 1.2.10
     pub phase:            GamePhase,
     /// 画面フラッシュのアルファ値（0.0=なし, 0.5=最大）
@@ -262,6 +271,8 @@ pub struct HudData {
 /// 1.2.9: HUD に表示するボス情報
 #[derive(Clone)]
 pub struct BossHudInfo {
+    /// BossState::id（HP バーの egui ID に使う）
+    pub id:      u32,
     pub name:    String,
     pub hp:      f32,
     pub max_hp:  f32,
@@ -273,9 +284,10 @@ impl Default for HudData {
             hp: 0.0, max_hp: 100.0, score: 0, elapsed_seconds: 0.0,
             level: 1, exp: 0, exp_to_next: 10, enemy_count: 0, bullet_count: 0,
             fps: 0.0, level_up_pending: false, weapon_choices: Vec::new(),
-            weapon_levels: Vec::new(), magnet_timer: 0.0, item_count: 0,
+            weapon_upgrade_descs: Vec::new(),
+            weapon_levels: Vec::new(), passive_levels: Vec::new(), magnet_timer: 0.0, item_count: 0,
             camera_x: 0.0, camera_y: 0.0,
-            boss_info: None,
+            boss_info: Vec::new(),
             phase: GamePhase::Title, screen_flash_alpha: 0.0,
             score_popups: Vec::new(), kill_count: 0,
         }
@@ -626,17 +638,20 @@ impl Renderer {
     }
 
     /// ゲーム状態からインスタンスリストを構築して GPU バッファを更新する
-    /// render_data: [(x, y, kind, anim_frame)] kind: 0=player, 1=slime, 2=bat, 3=golem, 4=bullet
+    /// render_data: [(x, y, kind, anim_frame, tint)] kind: 0=player, 1=slime, 2=bat, 3=golem, 4=bullet
+    ///   tint: スプライト既定の color_tint に成分ごとに乗算する RGBA
     /// particle_data: [(x, y, r, g, b, alpha, size)]
     /// item_data: [(x, y, kind)] kind: 5=gem, 6=potion, 7=magnet
-    /// obstacle_data: [(x, y, radius, kind)] kind: 0=木, 1=岩（1.5.2）
+    /// obstacle_data: [(x, y, w, h, kind, round)] kind: 0=木, 1=岩（1.5.2）。round なら円、それ以外は矩形で描く
+    /// tile_data: [(x, y, size, color)] 地面タイル（最初に描くため他のスプライトの下になる）
     /// camera_offset: (cam_x, cam_y) カメラのワールド座標オフセット（1.2.5）
     pub fn update_instances(
         &mut self,
-        render_data: &[(f32, f32, u8, u8)],
+        render_data: &[(f32, f32, u8, u8, [f32; 4])],
         particle_data: &[(f32, f32, f32, f32, f32, f32, f32)],
         item_data: &[(f32, f32, u8)],
-        obstacle_data: &[(f32, f32, f32, u8)],
+        obstacle_data: &[(f32, f32, f32, f32, u8, bool)],
+        tile_data: &[(f32, f32, f32, [f32; 4])],
         camera_offset: (f32, f32),
     ) {
         // 1.2.5: カメラ Uniform を更新
@@ -651,12 +666,26 @@ impl Renderer {
         let (potion_uv_off, potion_uv_sz)           = potion_uv();
         let (magnet_uv_off, magnet_uv_sz)           = magnet_uv();
         let (rock_uv_off, rock_uv_sz)               = rock_bullet_uv();
+        let (ground_uv_off, ground_uv_sz)           = ground_tile_uv();
 
-        let mut instances: Vec<SpriteInstance> =
-            Vec::with_capacity(render_data.len() + particle_data.len() + item_data.len() + obstacle_data.len());
+        let mut instances: Vec<SpriteInstance> = Vec::with_capacity(
+            tile_data.len() + render_data.len() + particle_data.len() + item_data.len() + obstacle_data.len(),
+        );
+
+        // 地面タイルを最初に描画（インスタンス順に描かれるため最背面になる）
+        for &(x, y, size, color) in tile_data {
+            if instances.len() >= MAX_INSTANCES { break; }
+            instances.push(SpriteInstance {
+                position:   [x, y],
+                size:       [size, size],
+                uv_offset:  ground_uv_off,
+                uv_size:    ground_uv_sz,
+                color_tint: color,
+            });
+        }
 
-        for &(x, y, kind, anim_frame) in render_data {
-            let inst = match kind {
+        for &(x, y, kind, anim_frame, tint) in render_data {
+            let mut inst = match kind {
                 // 1.2.8: プレイヤーはアニメーションフレームに応じた UV を使用
                 0 => {
                     let (uv_off, uv_sz) = player_anim_uv(anim_frame);
@@ -680,13 +709,14 @@ impl Renderer {
                         color_tint: [1.0, 1.0, 1.0, 1.0],
                     }
                 }
-                // 1.2.10: エリート敵（kind = base_kind + ELITE_RENDER_KIND_OFFSET）: 赤みがかった色で描画
-                21 | 22 | 23 => {
-                    let base = kind - ELITE_RENDER_KIND_OFFSET;
-                    let sz = enemy_sprite_size(base) * ELITE_SIZE_MULTIPLIER;
+                // 1.2.10: エリート敵（kind = base_kind + ELITE_RENDER_KIND_OFFSET）: 赤みがかった色で描画。
+                // 当たり判定と同じく左上基準で EliteParams::size_multiplier 倍に拡大する
+                21..=25 => {
+                    let base = kind - crate::ELITE_RENDER_KIND_OFFSET;
+                    let sz = enemy_sprite_size(base) * EliteParams::get().size_multiplier;
                     let (uv_off, uv_sz) = enemy_anim_uv(base, anim_frame);
                     SpriteInstance {
-                        position:   [x - sz * 0.1, y - sz * 0.1],
+                        position:   [x, y],
                         size:       [sz, sz],
                         uv_offset:  uv_off,
                         uv_size:    uv_sz,
@@ -747,6 +777,9 @@ impl Renderer {
                 },
                 _ => continue,
             };
+            for (c, t) in inst.color_tint.iter_mut().zip(tint) {
+                *c *= t;
+            }
             instances.push(inst);
             if instances.len() >= MAX_INSTANCES {
                 break;
@@ -765,20 +798,24 @@ impl Renderer {
             });
         }
 
-        // 1.5.2: 障害物を描画（木=緑褐色、岩=灰色の円）
-        for &(x, y, radius, kind) in obstacle_data {
+        // 1.5.2: 障害物を描画（木=緑褐色、岩=灰色。円はパーティクル、矩形は地面タイルのスプライトを使う）
+        for &(x, y, w, h, kind, round) in obstacle_data {
             if instances.len() >= MAX_INSTANCES { break; }
             let (r, g, b) = if kind == 0 {
                 (0.35, 0.55, 0.2)  // 木
             } else {
                 (0.45, 0.45, 0.5)  // 岩
             };
-            let sz = radius * 2.0;
+            let (uv_off, uv_sz) = if round {
+                (particle_uv_off, particle_uv_sz)
+            } else {
+                (ground_uv_off, ground_uv_sz)
+            };
             instances.push(SpriteInstance {
-                position:   [x - radius, y - radius],
-                size:       [sz, sz],
-                uv_offset:  particle_uv_off,
-                uv_size:    particle_uv_sz,
+                position:   [x, y],
+                size:       [w, h],
+                uv_offset:  uv_off,
+                uv_size:    uv_sz,
                 color_tint: [r, g, b, 1.0],
             });
         }
@@ -786,10 +823,12 @@ impl Renderer {
         // 1.2.4: アイテムを描画
         for &(x, y, kind) in item_data {
             if instances.len() >= MAX_INSTANCES { break; }
-            let (uv_off, uv_sz, sz) = match kind {
-                RENDER_KIND_GEM    => (gem_uv_off,    gem_uv_sz,    20.0_f32),
-                RENDER_KIND_POTION => (potion_uv_off, potion_uv_sz, 24.0_f32),
-                RENDER_KIND_MAGNET => (magnet_uv_off, magnet_uv_sz, 28.0_f32),
+            let (uv_off, uv_sz, sz, tint) = match kind {
+                RENDER_KIND_GEM    => (gem_uv_off,    gem_uv_sz,    20.0_f32, [1.0, 1.0, 1.0, 1.0]),
+                RENDER_KIND_POTION => (potion_uv_off, potion_uv_sz, 24.0_f32, [1.0, 1.0, 1.0, 1.0]),
+                RENDER_KIND_MAGNET => (magnet_uv_off, magnet_uv_sz, 28.0_f32, [1.0, 1.0, 1.0, 1.0]),
+                // 宝箱は専用スプライトが無いため、宝石を金色で大きく描く
+                RENDER_KIND_CHEST  => (gem_uv_off,    gem_uv_sz,    36.0_f32, [1.0, 0.8, 0.25, 1.0]),
                 _ => continue,
             };
             instances.push(SpriteInstance {
@@ -797,7 +836,7 @@ impl Renderer {
                 size:       [sz, sz],
                 uv_offset:  uv_off,
                 uv_size:    uv_sz,
-                color_tint: [1.0, 1.0, 1.0, 1.0],
+                color_tint: tint,
             });
         }
 
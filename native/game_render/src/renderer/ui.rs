@@ -1,5 +1,4 @@
-use super::{GamePhase, GameUiState, HudData};
-use game_core::weapon::weapon_upgrade_desc;
+use super::{BossHudInfo, GamePhase, GameUiState, HudData};
 
 /// HUD を描画し、ボタン操作があった場合はアクション文字列を返す。
 /// - レベルアップ選択: 武器名
@@ -388,6 +387,17 @@ fn build_playing_hud_ui(ctx: &egui::Context, hud: &HudData, fps: f32, ui_state:
                             }
                         }
 
+                        // パッシブアイテム
+                        if !hud.passive_levels.is_empty() {
+                            ui.separator();
+                            for (name, lv) in &hud.passive_levels {
+                                ui.label(
+                                    egui::RichText::new(format!("[{}] Lv.{lv}", weapon_short_name(name)))
+                                        .color(egui::Color32::from_rgb(200, 255, 200)),
+                                );
+                            }
+                        }
+
                         // 1.5.3: セーブ・ロードボタン
                         ui.separator();
                         if ui.add(
@@ -449,58 +459,66 @@ fn build_playing_hud_ui(ctx: &egui::Context, hud: &HudData, fps: f32, ui_state:
     None
 }
 
-/// ボス HP バー（画面上部中央）
+/// ボス HP バー（画面上部中央。複数ボスは出現順に縦に並べる）
 fn build_boss_hp_bar_ui(ctx: &egui::Context, hud: &HudData) {
-    let Some(ref boss) = hud.boss_info else { return };
+    if hud.boss_info.is_empty() { return; }
+    egui::Area::new(egui::Id::new("boss_hp_bar"))
+        .anchor(egui::Align2::CENTER_TOP, egui::vec2(0.0, 8.0))
+        .order(egui::Order::Foreground)
+        .show(ctx, |ui| {
+            for boss in &hud.boss_info {
+                ui.push_id(boss.id, |ui| build_boss_hp_bar(ui, boss));
+                ui.add_space(4.0);
+            }
+        });
+}
+
+/// ボス 1 体分の HP バー
+fn build_boss_hp_bar(ui: &mut egui::Ui, boss: &BossHudInfo) {
     let boss_ratio = if boss.max_hp > 0.0 {
         (boss.hp / boss.max_hp).clamp(0.0, 1.0)
     } else {
         0.0
     };
-    egui::Area::new(egui::Id::new("boss_hp_bar"))
-        .anchor(egui::Align2::CENTER_TOP, egui::vec2(0.0, 8.0))
-        .order(egui::Order::Foreground)
-        .show(ctx, |ui| {
-            egui::Frame::new()
-                .fill(egui::Color32::from_rgba_unmultiplied(20, 0, 30, 220))
-                .inner_margin(egui::Margin::symmetric(16, 10))
-                .corner_radius(8.0)
-                .stroke(egui::Stroke::new(2.0, egui::Color32::from_rgb(200, 0, 255)))
-                .show(ui, |ui| {
-                    ui.vertical_centered(|ui| {
-                        ui.label(
-                            egui::RichText::new(format!("👹 {}", boss.name))
-                                .color(egui::Color32::from_rgb(255, 80, 80))
-                                .size(18.0)
-                                .strong(),
-                        );
-                        ui.add_space(4.0);
-                        let (bar_rect, _) = ui.allocate_exact_size(
-                            egui::vec2(360.0, 22.0),
-                            egui::Sense::hover(),
-                        );
-                        let painter = ui.painter();
-                        painter.rect_filled(bar_rect, 6.0, egui::Color32::from_rgb(40, 10, 10));
-                        let fill_w = bar_rect.width() * boss_ratio;
-                        let fill_rect = egui::Rect::from_min_size(
-                            bar_rect.min,
-                            egui::vec2(fill_w, bar_rect.height()),
-                        );
-                        let bar_color = if boss_ratio > 0.5 {
-                            egui::Color32::from_rgb(180, 0, 220)
-                        } else if boss_ratio > 0.25 {
-                            egui::Color32::from_rgb(220, 60, 60)
-                        } else {
-                            egui::Color32::from_rgb(255, 30, 30)
-                        };
-                        painter.rect_filled(fill_rect, 6.0, bar_color);
-                        ui.label(
-                            egui::RichText::new(format!("{:.0} / {:.0}", boss.hp, boss.max_hp))
-                                .color(egui::Color32::from_rgb(255, 200, 255))
-                                .size(12.0),
-                        );
-                    });
-                });
+    egui::Frame::new()
+        .fill(egui::Color32::from_rgba_unmultiplied(20, 0, 30, 220))
+        .inner_margin(egui::Margin::symmetric(16, 10))
+        .corner_radius(8.0)
+        .stroke(egui::Stroke::new(2.0, egui::Color32::from_rgb(200, 0, 255)))
+        .show(ui, |ui| {
+            ui.vertical_centered(|ui| {
+                ui.label(
+                    egui::RichText::new(format!("👹 {}", boss.name))
+                        .color(egui::Color32::from_rgb(255, 80, 80))
+                        .size(18.0)
+                        .strong(),
+                );
+                ui.add_space(4.0);
+                let (bar_rect, _) = ui.allocate_exact_size(
+                    egui::vec2(360.0, 22.0),
+                    egui::Sense::hover(),
+                );
+                let painter = ui.painter();
+                painter.rect_filled(bar_rect, 6.0, egui::Color32::from_rgb(40, 10, 10));
+                let fill_w = bar_rect.width() * boss_ratio;
+                let fill_rect = egui::Rect::from_min_size(
+                    bar_rect.min,
+                    egui::vec2(fill_w, bar_rect.height()),
+                );
+                let bar_color = if boss_ratio > 0.5 {
+                    egui::Color32::from_rgb(180, 0, 220)
+                } else if boss_ratio > 0.25 {
+                    egui::Color32::from_rgb(220, 60, 60)
+                } else {
+                    egui::Color32::from_rgb(255, 30, 30)
+                };
+                painter.rect_filled(fill_rect, 6.0, bar_color);
+                ui.label(
+                    egui::RichText::new(format!("{:.0} / {:.0}", boss.hp, boss.max_hp))
+                        .color(egui::Color32::from_rgb(255, 200, 255))
+                        .size(12.0),
+                );
+            });
         });
 }
 
@@ -567,6 +585,12 @@ fn build_max_level_ui(ui: &mut egui::Ui) -> Option<String> {
             .size(16.0)
             .strong(),
     );
+    ui.add_space(6.0);
+    ui.label(
+        egui::RichText::new("Pair a MAX weapon with its item, then defeat a boss or open a chest to evolve it")
+            .color(egui::Color32::from_rgb(200, 200, 200))
+            .size(12.0),
+    );
     ui.add_space(16.0);
     let btn = egui::Button::new(
         egui::RichText::new("Continue  [Esc]")
@@ -587,20 +611,22 @@ fn build_weapon_choice_ui(ui: &mut egui::Ui, hud: &HudData) -> Option<String> {
     let mut chosen: Option<String> = None;
 
     ui.label(
-        egui::RichText::new("Choose a weapon")
+        egui::RichText::new("Choose a weapon or item")
             .color(egui::Color32::WHITE)
             .size(16.0),
     );
     ui.add_space(16.0);
 
     ui.horizontal(|ui| {
-        for choice in &hud.weapon_choices {
+        for (i, choice) in hud.weapon_choices.iter().enumerate() {
             let current_lv = hud.weapon_levels
                 .iter()
+                .chain(&hud.passive_levels)
                 .find(|(n, _)| n == choice)
                 .map(|(_, lv)| *lv)
                 .unwrap_or(0);
-            if build_weapon_card(ui, choice, current_lv).is_some() {
+            let desc = hud.weapon_upgrade_descs.get(i).map(Vec::as_slice).unwrap_or(&[]);
+            if build_weapon_card(ui, choice, current_lv, desc).is_some() {
                 chosen = Some(choice.clone());
             }
             ui.add_space(12.0);
@@ -621,7 +647,7 @@ fn build_weapon_choice_ui(ui: &mut egui::Ui, hud: &HudData) -> Option<String> {
 }
 
 /// 武器1枚分のカードUIを描画し、選択されたら `Some(())` を返す
-fn build_weapon_card(ui: &mut egui::Ui, choice: &str, current_lv: u32) -> Option<()> {
+fn build_weapon_card(ui: &mut egui::Ui, choice: &str, current_lv: u32, desc: &[String]) -> Option<()> {
     let is_upgrade  = current_lv > 0;
     let next_lv     = current_lv + 1;
 
@@ -671,7 +697,7 @@ fn build_weapon_card(ui: &mut egui::Ui, choice: &str, current_lv: u32) -> Option
             );
             ui.add_space(6.0);
 
-            for line in weapon_upgrade_desc(choice, current_lv) {
+            for line in desc {
                 ui.label(
                     egui::RichText::new(line)
                         .color(egui::Color32::from_rgb(180, 200, 180))
@@ -696,12 +722,29 @@ fn build_weapon_card(ui: &mut egui::Ui, choice: &str, current_lv: u32) -> Option
 
 fn weapon_short_name(name: &str) -> &str {
     match name {
-        "magic_wand" => "Magic Wand",
-        "axe"        => "Axe",
-        "cross"      => "Cross",
-        "whip"       => "Whip",
-        "fireball"   => "Fireball",
-        "lightning"  => "Lightning",
-        _            => name,
+        "magic_wand"  => "Magic Wand",
+        "axe"         => "Axe",
+        "cross"       => "Cross",
+        "whip"        => "Whip",
+        "fireball"    => "Fireball",
+        "lightning"   => "Lightning",
+        "garlic"      => "Garlic",
+        "frost_shard" => "Frost Shard",
+        // 進化後の武器
+        "holy_wand"    => "Holy Wand",
+        "death_spiral" => "Death Spiral",
+        "bloody_tear"  => "Bloody Tear",
+        "hellfire"     => "Hellfire",
+        "soul_eater"   => "Soul Eater",
+        // パッシブアイテム
+        "spinach"      => "Spinach",
+        "armor"        => "Armor",
+        "hollow_heart" => "Hollow Heart",
+        "empty_tome"   => "Empty Tome",
+        "wings"        => "Wings",
+        "attractorb"   => "Attractorb",
+        "clover"       => "Clover",
+        "duplicator"   => "Duplicator",
+        _             => name,
     }
 }
@@ -166,6 +166,7 @@ impl<B: RenderBridge> ApplicationHandler for RenderApp<B> {
                         &frame.particle_data,
                         &frame.item_data,
                         &frame.obstacle_data,
+                        &frame.tile_data,
                         frame.camera_offset,
                     );
                     if let Some(action) = renderer.render(window, &frame.hud, &mut self.ui_state) {
@@ -17,10 +17,13 @@ defmodule GameContent.VampireSurvivor do
   def entity_registry do
     %{
       enemies: %{slime: 0, bat: 1, golem: 2, skeleton: 3, ghost: 4},
-      weapons: %{
-        magic_wand: 0, axe: 1, cross: 2, whip: 3, fireball: 4, lightning: 5, garlic: 6
-      },
-      bosses: %{slime_king: 0, bat_lord: 1, stone_golem: 2}
+      # 武器は entity_params の武器テーブルから引く
+      weapons: GameEngine.weapon_ids(),
+      bosses: %{slime_king: 0, bat_lord: 1, stone_golem: 2},
+      passives: %{
+        spinach: 0, armor: 1, hollow_heart: 2, empty_tome: 3, wings: 4, attractorb: 5,
+        clover: 6, duplicator: 7
+      }
     }
   end
 
@@ -41,6 +44,9 @@ defmodule GameContent.VampireSurvivor do
   @impl GameEngine.GameBehaviour
   def assets_path, do: "vampire_survivor"
 
+  def entity_params_path, do: "assets/vampire_survivor/entity_params.json"
+  def spawn_schedule_path, do: "assets/vampire_survivor/spawn_schedule.json"
+
   def level_up_scene, do: GameContent.VampireSurvivor.Scenes.LevelUp
   def boss_alert_scene, do: GameContent.VampireSurvivor.Scenes.BossAlert
   def game_over_scene, do: GameContent.VampireSurvivor.Scenes.GameOver
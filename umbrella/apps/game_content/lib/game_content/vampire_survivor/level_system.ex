@@ -1,36 +1,13 @@
 # Path: umbrella/apps/game_content/lib/game_content/vampire_survivor/level_system.ex
-# Summary: レベルアップ・武器選択生成の純粋関数モジュール（ヴァンサバ固有）
+# Summary: レベルアップ選択肢の表示ラベル（ヴァンサバ固有）
 defmodule GameContent.VampireSurvivor.LevelSystem do
-  @all_weapons [:magic_wand, :garlic, :axe, :cross, :whip, :fireball, :lightning]
-  @max_weapon_level 8
-  @max_weapon_slots 6
+  # 選択肢（武器・パッシブ）は Rust 側が計算する（GameEngine.get_level_up_choices/1）
+  # 表示名は entity_params の武器テーブル（GameEngine.weapon_labels/0）から引く
 
-  def generate_weapon_choices(weapon_levels) when is_map(weapon_levels) do
-    slots_full? = map_size(weapon_levels) >= @max_weapon_slots
+  def weapon_label(weapon, level), do: weapon_label(weapon, level, GameEngine.weapon_labels())
 
-    @all_weapons
-    |> Enum.reject(fn w ->
-      lv = Map.get(weapon_levels, w, 0)
-      lv >= @max_weapon_level or (slots_full? and lv == 0)
-    end)
-    |> Enum.sort_by(fn w ->
-      lv = Map.get(weapon_levels, w, 0)
-      if lv == 0, do: -1, else: lv
-    end)
-    |> Enum.take(3)
+  def weapon_label(weapon, level, labels) when is_integer(level) and level > 1 do
+    "#{weapon_label(weapon, 1, labels)} Lv.#{level}"
   end
-
-  def weapon_label(weapon, level) when is_integer(level) and level > 1 do
-    "#{weapon_label(weapon)} Lv.#{level}"
-  end
-  def weapon_label(weapon, _level), do: weapon_label(weapon)
-
-  def weapon_label(:magic_wand), do: "Magic Wand (auto-aim)"
-  def weapon_label(:garlic), do: "Garlic (aura damage)"
-  def weapon_label(:axe), do: "Axe (upward throw)"
-  def weapon_label(:cross), do: "Cross (4-way fire)"
-  def weapon_label(:whip), do: "Whip (fan sweep)"
-  def weapon_label(:fireball), do: "Fireball (piercing)"
-  def weapon_label(:lightning), do: "Lightning (chain)"
-  def weapon_label(other), do: to_string(other)
+  def weapon_label(weapon, _level, labels), do: Map.get_lazy(labels, weapon, fn -> to_string(weapon) end)
 end
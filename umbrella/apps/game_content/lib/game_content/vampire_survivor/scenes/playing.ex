@@ -46,10 +46,11 @@ defmodule GameContent.VampireSurvivor.Scenes.Playing do
 
           if level_up_pending do
             :telemetry.execute([:game, :level_up], %{level: level, count: 1}, %{})
-            choices = GameContent.VampireSurvivor.LevelSystem.generate_weapon_choices(weapon_levels)
+            # 武器とパッシブの選択肢は Rust 側（描画スレッドのカードと同じもの）を使う
+            choices = GameEngine.get_level_up_choices(world_ref)
 
             if choices == [] do
-              Logger.info("[LEVEL UP] All weapons at max level — skipping weapon selection")
+              Logger.info("[LEVEL UP] No weapon or passive upgrades left — skipping selection")
               GameEngine.skip_level_up(world_ref)
               {:continue, state}
             else
@@ -72,9 +73,14 @@ defmodule GameContent.VampireSurvivor.Scenes.Playing do
               }}, state}
             end
           else
-            new_last_spawn =
-              GameContent.VampireSurvivor.SpawnSystem.maybe_spawn(world_ref, elapsed, last_spawn_ms)
-            {:continue, state, %{context_updates: %{last_spawn_ms: new_last_spawn}}}
+            # スケジュールを設定できていれば Rust 側のスポーンディレクター（spawn_schedule.json）が出す
+            if Map.get(context, :spawn_schedule_loaded, false) do
+              {:continue, state}
+            else
+              new_last_spawn =
+                GameContent.VampireSurvivor.SpawnSystem.maybe_spawn(world_ref, elapsed, last_spawn_ms)
+              {:continue, state, %{context_updates: %{last_spawn_ms: new_last_spawn}}}
+            end
           end
       end
     end
@@ -1,6 +1,8 @@
 # Path: umbrella/apps/game_content/lib/game_content/vampire_survivor/spawn_system.ex
 # Summary: ウェーブベース敵スポーンシステム（ヴァンサバ固有）
 defmodule GameContent.VampireSurvivor.SpawnSystem do
+  # 通常のスポーンとウェーブ名は spawn_schedule.json（Rust 側のスポーンディレクター）が持つ。
+  # ここはスケジュールを読み込めなかったときのフォールバック
   @max_enemies 10_000
 
   @waves [
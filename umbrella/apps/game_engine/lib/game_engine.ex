@@ -33,14 +33,106 @@ defmodule GameEngine do
 
   def get_level_up_data(world_ref), do: Queries.get_level_up_data(world_ref)
 
+  # 読み込み済みの entity_params の武器テーブル（[{weapon, label}]、並び順 = 武器 ID）
+  def weapon_catalog do
+    Queries.get_weapon_catalog()
+    |> Enum.map(fn {name, label} -> {String.to_atom(name), label} end)
+  end
+
+  # 武器名 atom → 武器 ID（entity_registry/0 の weapons 用）
+  def weapon_ids do
+    weapon_catalog()
+    |> Enum.with_index()
+    |> Map.new(fn {{weapon, _label}, id} -> {weapon, id} end)
+  end
+
+  def weapon_labels, do: Map.new(weapon_catalog())
+
+  # Rust 側で計算済みのレベルアップ選択肢（武器名・パッシブ名の atom、空なら強化が残っていない）
+  def get_level_up_choices(world_ref) do
+    world_ref
+    |> Queries.get_level_up_choices()
+    |> Enum.map(&String.to_atom/1)
+  end
+
+  # 現在のウェーブ名（スポーンスケジュールの label）。スケジュール未設定・ラベルなしなら nil
+  def get_wave_label(world_ref), do: Queries.get_wave_label(world_ref)
+
   def skip_level_up(world_ref), do: Commands.skip_level_up(world_ref)
 
   # ── エンジン内部用 ──────────────────────────────────────────────────
 
-  def create_world, do: Commands.create_world()
+  # seed を省略するとランダムなシードでワールドを作る
+  def create_world(seed \\ random_seed()), do: Commands.create_world(seed)
+
+  def random_seed, do: :rand.uniform(0x7FFF_FFFF_FFFF_FFFF) - 1
+
+  # create_world の直後に呼ぶ（ワールドを変更するコマンドを path に記録する）
+  def start_replay_recording(world_ref, path) do
+    File.mkdir_p(Path.dirname(path))
+    Commands.start_replay_recording(world_ref, path)
+    :ok
+  rescue
+    e in ErlangError -> {:error, e.original}
+  end
+
+  def stop_replay_recording(world_ref) do
+    Commands.stop_replay_recording(world_ref)
+    :ok
+  rescue
+    e in ErlangError -> {:error, e.original}
+  end
+
+  # 新しいワールドで再生し、記録時の状態とずれたら {:error, reason}
+  def play_replay(path) do
+    {frames, checkpoints} = Commands.play_replay_file(path)
+    {:ok, %{frames: frames, checkpoints: checkpoints}}
+  rescue
+    e in ErlangError -> {:error, e.original}
+  end
+
+  def set_world_size(world_ref, width, height), do: Commands.set_world_size(world_ref, width, height)
 
   def set_map_obstacles(world_ref, obstacles), do: Commands.set_map_obstacles(world_ref, obstacles)
 
+  def load_map(world_ref, path) do
+    Commands.load_map(world_ref, path)
+    :ok
+  rescue
+    e in ErlangError -> {:error, e.original}
+  end
+
+  # 敵・武器・ボスのパラメータテーブルを読み込み、Rust 側の組み込みテーブルを置き換える
+  def load_entity_params(path) do
+    case Path.extname(path) do
+      ".json" ->
+        with {:ok, contents} <- File.read(path) do
+          Commands.load_entity_params(:json, contents)
+          :ok
+        end
+
+      ext ->
+        {:error, {:unsupported_format, ext}}
+    end
+  rescue
+    e in ErlangError -> {:error, e.original}
+  end
+
+  def load_spawn_schedule(world_ref, path) do
+    case Path.extname(path) do
+      ".json" ->
+        with {:ok, contents} <- File.read(path) do
+          Commands.set_spawn_schedule(world_ref, :json, contents)
+          :ok
+        end
+
+      ext ->
+        {:error, {:unsupported_format, ext}}
+    end
+  rescue
+    e in ErlangError -> {:error, e.original}
+  end
+
   def create_game_loop_control, do: Commands.create_game_loop_control()
 
   def start_rust_game_loop(world_ref, control_ref, pid) do
@@ -53,6 +145,14 @@ defmodule GameEngine do
 
   def resume_physics(control_ref), do: Commands.resume_physics(control_ref)
 
+  # Rust ループが frames フレームごとに {:frame_events, events, {frame_id, checksum}} を送る（0 で止める）
+  def set_checksum_interval(control_ref, frames) when is_integer(frames) and frames >= 0 do
+    Commands.set_checksum_interval(control_ref, frames)
+  end
+
+  # {frame_id, checksum}。同じシード・同じ入力のワールド同士なら同じ値（同期ずれの検出用）
+  def get_world_checksum(world_ref), do: Queries.get_world_checksum(world_ref)
+
   def physics_step(world_ref, delta_ms), do: Commands.physics_step(world_ref, delta_ms)
 
   def set_player_input(world_ref, dx, dy), do: Commands.set_player_input(world_ref, dx, dy)
@@ -76,6 +176,12 @@ defmodule GameEngine do
 
   def get_weapon_levels(world_ref), do: Queries.get_weapon_levels(world_ref)
 
+  def add_passive(world_ref, passive) when is_atom(passive) do
+    Commands.add_passive(world_ref, resolve_passive_id(passive))
+  end
+
+  def get_passive_levels(world_ref), do: Queries.get_passive_levels(world_ref)
+
   # ── セーブ・ロード ──────────────────────────────────────────────────
 
   def save_session(world_ref), do: GameEngine.SaveManager.save_session(world_ref)
@@ -125,4 +231,9 @@ defmodule GameEngine do
     game = Application.get_env(:game_engine, :current)
     Map.fetch!(game.entity_registry().weapons, weapon)
   end
+
+  defp resolve_passive_id(passive) do
+    game = Application.get_env(:game_engine, :current)
+    Map.fetch!(game.entity_registry().passives, passive)
+  end
 end
@@ -10,14 +10,19 @@ defmodule GameEngine.Commands do
 
   alias GameEngine.NifBridge
 
-  def create_world, do: NifBridge.create_world()
+  def create_world(seed), do: NifBridge.create_world(seed)
+  def set_world_size(world_ref, width, height), do: NifBridge.set_world_size(world_ref, width, height)
   def set_map_obstacles(world_ref, obstacles), do: NifBridge.set_map_obstacles(world_ref, obstacles)
+  def load_map(world_ref, path), do: NifBridge.load_map(world_ref, path)
+  def load_entity_params(format, contents), do: NifBridge.load_entity_params(format, contents)
+  def set_spawn_schedule(world_ref, format, contents), do: NifBridge.set_spawn_schedule(world_ref, format, contents)
 
   def create_game_loop_control, do: NifBridge.create_game_loop_control()
   def start_rust_game_loop(world_ref, control_ref, pid), do: NifBridge.start_rust_game_loop(world_ref, control_ref, pid)
   def start_render_thread(world_ref), do: NifBridge.start_render_thread(world_ref)
   def pause_physics(control_ref), do: NifBridge.pause_physics(control_ref)
   def resume_physics(control_ref), do: NifBridge.resume_physics(control_ref)
+  def set_checksum_interval(control_ref, frames), do: NifBridge.set_checksum_interval(control_ref, frames)
 
   def physics_step(world_ref, delta_ms), do: NifBridge.physics_step(world_ref, delta_ms)
   def set_player_input(world_ref, dx, dy), do: NifBridge.set_player_input(world_ref, dx, dy)
@@ -32,7 +37,12 @@ defmodule GameEngine.Commands do
   def spawn_boss(world_ref, kind_id), do: NifBridge.spawn_boss(world_ref, kind_id)
 
   def add_weapon(world_ref, weapon_id), do: NifBridge.add_weapon(world_ref, weapon_id)
+  def add_passive(world_ref, passive_id), do: NifBridge.add_passive(world_ref, passive_id)
   def skip_level_up(world_ref), do: NifBridge.skip_level_up(world_ref)
 
   def load_save_snapshot(world_ref, snapshot), do: NifBridge.load_save_snapshot(world_ref, snapshot)
+
+  def start_replay_recording(world_ref, path), do: NifBridge.start_replay_recording(world_ref, path)
+  def stop_replay_recording(world_ref), do: NifBridge.stop_replay_recording(world_ref)
+  def play_replay_file(path), do: NifBridge.play_replay_file(path)
 end
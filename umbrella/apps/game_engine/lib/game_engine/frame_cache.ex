@@ -7,12 +7,13 @@ defmodule GameEngine.FrameCache do
     :ets.new(@table, [:named_table, :public, :set, read_concurrency: true])
   end
 
-  def put(enemy_count, bullet_count, physics_ms, hud_data, render_type \\ :playing, high_scores \\ nil) do
+  def put(enemy_count, bullet_count, physics_ms, hud_data, wave, render_type \\ :playing, high_scores \\ nil) do
     base = %{
       enemy_count: enemy_count,
       bullet_count: bullet_count,
       physics_ms: physics_ms,
       hud_data: hud_data,
+      wave: wave,
       render_type: render_type,
       updated_at: System.monotonic_time(:millisecond)
     }
@@ -40,11 +40,14 @@ defmodule GameEngine.GameEvents do
       GameEngine.RoomRegistry.register(:main)
     end
 
-    world_ref = GameEngine.create_world()
+    maybe_load_entity_params(current_game())
+    world_seed = Keyword.get_lazy(opts, :seed, fn -> Application.get_env(:game_engine, :world_seed) || GameEngine.random_seed() end)
+    world_ref = GameEngine.create_world(world_seed)
+    maybe_start_replay_recording(world_ref)
 
     map_id = Application.get_env(:game_engine, :map, :plain)
-    obstacles = GameEngine.MapLoader.obstacles_for_map(map_id)
-    GameEngine.set_map_obstacles(world_ref, obstacles)
+    load_map(world_ref, map_id)
+    spawn_schedule_loaded = maybe_load_spawn_schedule(current_game(), world_ref)
 
     if room_id == :main, do: GameEngine.FrameCache.init()
     start_ms = now_ms()
@@ -64,10 +67,12 @@ defmodule GameEngine.GameEvents do
      %{
        room_id: room_id,
        world_ref: world_ref,
+       world_seed: world_seed,
        last_tick: start_ms,
        frame_count: 0,
        start_ms: start_ms,
        last_spawn_ms: start_ms,
+       spawn_schedule_loaded: spawn_schedule_loaded,
        weapon_levels: initial_weapon_levels
      }}
   end
@@ -142,7 +147,7 @@ defmodule GameEngine.GameEvents do
 
     case GameEngine.SceneManager.current() do
       {:ok, %{module: ^level_up_scene}} ->
-        GameEngine.add_weapon(state.world_ref, weapon)
+        add_upgrade(state.world_ref, weapon)
         new_weapon_levels = fetch_weapon_levels(state.world_ref)
         lv = Map.get(new_weapon_levels, weapon, 1)
         Logger.info("[LEVEL UP] Weapon selected: #{game.weapon_label(weapon, lv)} -> resuming")
@@ -266,6 +271,17 @@ defmodule GameEngine.GameEvents do
     end
   end
 
+  # 選択肢は武器名かパッシブ名（Rust 側が両方を混ぜて返す）
+  defp add_upgrade(world_ref, choice) do
+    passives = current_game().entity_registry() |> Map.get(:passives, %{})
+
+    if Map.has_key?(passives, choice) do
+      GameEngine.add_passive(world_ref, choice)
+    else
+      GameEngine.add_weapon(world_ref, choice)
+    end
+  end
+
   defp apply_weapon_selection(state, weapon_name) when is_binary(weapon_name) do
     requested_weapon =
       try do
@@ -276,9 +292,15 @@ defmodule GameEngine.GameEvents do
 
     game = current_game()
     allowed_weapons = game.entity_registry().weapons |> Map.keys() |> MapSet.new()
+    # 選択肢にはパッシブアイテム名も含まれる（passives を持たないゲームもある）
+    allowed_passives = game.entity_registry() |> Map.get(:passives, %{}) |> Map.keys() |> MapSet.new()
     fallback_weapon = Map.keys(state.weapon_levels) |> List.first() || :magic_wand
 
     cond do
+      is_atom(requested_weapon) and MapSet.member?(allowed_passives, requested_weapon) ->
+        GameEngine.add_passive(state.world_ref, requested_weapon)
+        {requested_weapon, fetch_weapon_levels(state.world_ref)}
+
       is_atom(requested_weapon) and MapSet.member?(allowed_weapons, requested_weapon) ->
         GameEngine.add_weapon(state.world_ref, requested_weapon)
         {requested_weapon, fetch_weapon_levels(state.world_ref)}
@@ -352,6 +374,7 @@ defmodule GameEngine.GameEvents do
       now: now,
       elapsed: elapsed,
       last_spawn_ms: state.last_spawn_ms,
+      spawn_schedule_loaded: state.spawn_schedule_loaded,
       weapon_levels: state.weapon_levels,
       frame_count: state.frame_count,
       start_ms: state.start_ms
@@ -379,7 +402,7 @@ defmodule GameEngine.GameEvents do
       state =
         case scene_state do
           %{choices: [first | _]} ->
-            GameEngine.add_weapon(state.world_ref, first)
+            add_upgrade(state.world_ref, first)
             new_levels = fetch_weapon_levels(state.world_ref)
             Logger.info("[LEVEL UP] Auto-selected: #{game.weapon_label(first, Map.get(new_levels, first, 1))} -> resuming")
             %{state | weapon_levels: new_levels}
@@ -442,9 +465,9 @@ defmodule GameEngine.GameEvents do
       hud_data = {hp, max_hp, score, elapsed_s}
       render_type = GameEngine.SceneManager.render_type()
       high_scores = if render_type == :game_over, do: GameEngine.load_high_scores(), else: nil
-      GameEngine.FrameCache.put(enemy_count, bullet_count, physics_ms, hud_data, render_type, high_scores)
+      wave = GameEngine.get_wave_label(state.world_ref) || fallback_wave_label(game, elapsed_s)
+      GameEngine.FrameCache.put(enemy_count, bullet_count, physics_ms, hud_data, wave, render_type, high_scores)
 
-      wave = if function_exported?(game, :wave_label, 1), do: game.wave_label(elapsed_s), else: "Wave"
       budget_warn = if physics_ms > @tick_ms, do: " [OVER BUDGET]", else: ""
 
       weapon_info =
@@ -481,6 +504,68 @@ defmodule GameEngine.GameEvents do
 
   defp now_ms, do: System.monotonic_time(:millisecond)
 
+  defp maybe_load_entity_params(game) do
+    if function_exported?(game, :entity_params_path, 0) do
+      path = game.entity_params_path()
+
+      case GameEngine.load_entity_params(path) do
+        :ok -> :ok
+        {:error, reason} -> Logger.error("[ENTITY_PARAMS] Failed to load #{path}: #{inspect(reason)}")
+      end
+    end
+  end
+
+  defp load_map(world_ref, map_id) do
+    case GameEngine.MapLoader.map_path(map_id) do
+      nil ->
+        {map_width, map_height} = GameEngine.MapLoader.world_size_for_map(map_id)
+        GameEngine.set_world_size(world_ref, map_width, map_height)
+        GameEngine.set_map_obstacles(world_ref, GameEngine.MapLoader.obstacles_for_map(map_id))
+
+      path ->
+        case GameEngine.load_map(world_ref, path) do
+          :ok -> :ok
+          {:error, reason} -> Logger.error("[MAP] Failed to load #{path}: #{inspect(reason)}")
+        end
+    end
+  end
+
+  defp maybe_start_replay_recording(world_ref) do
+    case Application.get_env(:game_engine, :replay_path) do
+      nil ->
+        :ok
+
+      path ->
+        case GameEngine.start_replay_recording(world_ref, path) do
+          :ok -> :ok
+          {:error, reason} -> Logger.error("[REPLAY] Failed to record to #{path}: #{inspect(reason)}")
+        end
+    end
+  end
+
+  # スケジュールを設定できたら true。false ならシーンが Elixir 側でスポーンする（context の spawn_schedule_loaded）
+  defp maybe_load_spawn_schedule(game, world_ref) do
+    if function_exported?(game, :spawn_schedule_path, 0) do
+      path = game.spawn_schedule_path()
+
+      case GameEngine.load_spawn_schedule(world_ref, path) do
+        :ok ->
+          true
+
+        {:error, reason} ->
+          Logger.error("[SPAWN] Failed to load #{path}: #{inspect(reason)} -> falling back to Elixir-side spawning")
+          false
+      end
+    else
+      false
+    end
+  end
+
+  # スケジュールにウェーブ名がないときはゲームの wave_label/1 を使う
+  defp fallback_wave_label(game, elapsed_s) do
+    if function_exported?(game, :wave_label, 1), do: game.wave_label(elapsed_s), else: "Wave"
+  end
+
   defp current_game do
     Application.get_env(:game_engine, :current)
   end
@@ -1,25 +1,17 @@
 # Path: umbrella/apps/game_engine/lib/game_engine/map_loader.ex
-# Summary: マップ ID に応じた障害物リストの提供
+# Summary: マップ ID に応じたタイルマップファイル・ワールドの広さ・障害物リストの提供
 defmodule GameEngine.MapLoader do
-  def obstacles_for_map(:plain), do: []
+  def map_path(:forest), do: "assets/maps/forest.json"
+  def map_path(_map_id), do: nil
 
-  def obstacles_for_map(:forest) do
-    [
-      {512.0, 512.0, 40.0, 0},
-      {1024.0, 768.0, 30.0, 1},
-      {1536.0, 256.0, 40.0, 0},
-      {768.0, 1024.0, 35.0, 1},
-      {2048.0, 2048.0, 50.0, 0},
-      {2560.0, 1280.0, 30.0, 1},
-      {1280.0, 2560.0, 40.0, 0},
-      {640.0, 640.0, 25.0, 1}
-    ]
-  end
+  def world_size_for_map(_map_id), do: {4096.0, 4096.0}
+
+  def obstacles_for_map(:plain), do: []
 
   def obstacles_for_map(:minimal) do
     [
-      {512.0, 360.0, 40.0, 0},
-      {768.0, 400.0, 30.0, 1}
+      {1920.0, 2048.0, 40.0, 0},
+      {2176.0, 2088.0, 30.0, 1}
     ]
   end
 
@@ -26,9 +26,14 @@ defmodule GameEngine.NifBridge do
 
   # ── control ───────────────────────────────────────────────────────
   def add(_a, _b), do: :erlang.nif_error(:nif_not_loaded)
-  def create_world(), do: :erlang.nif_error(:nif_not_loaded)
+  def create_world(_seed), do: :erlang.nif_error(:nif_not_loaded)
 
+  def set_world_size(_world, _width, _height), do: :erlang.nif_error(:nif_not_loaded)
   def set_map_obstacles(_world, _obstacles), do: :erlang.nif_error(:nif_not_loaded)
+  def load_map(_world, _path), do: :erlang.nif_error(:nif_not_loaded)
+  def load_entity_params(_format, _contents), do: :erlang.nif_error(:nif_not_loaded)
+  def get_weapon_catalog, do: :erlang.nif_error(:nif_not_loaded)
+  def set_spawn_schedule(_world, _format, _contents), do: :erlang.nif_error(:nif_not_loaded)
   def physics_step(_world, _delta_ms), do: :erlang.nif_error(:nif_not_loaded)
 
   def drain_frame_events(_world), do: :erlang.nif_error(:nif_not_loaded)
@@ -38,6 +43,7 @@ defmodule GameEngine.NifBridge do
   def spawn_enemies(_world, _kind, _count), do: :erlang.nif_error(:nif_not_loaded)
 
   def add_weapon(_world, _weapon_name), do: :erlang.nif_error(:nif_not_loaded)
+  def add_passive(_world, _passive_id), do: :erlang.nif_error(:nif_not_loaded)
 
   def skip_level_up(_world), do: :erlang.nif_error(:nif_not_loaded)
 
@@ -51,6 +57,7 @@ defmodule GameEngine.NifBridge do
   def start_render_thread(_world), do: :erlang.nif_error(:nif_not_loaded)
   def pause_physics(_control), do: :erlang.nif_error(:nif_not_loaded)
   def resume_physics(_control), do: :erlang.nif_error(:nif_not_loaded)
+  def set_checksum_interval(_control, _frames), do: :erlang.nif_error(:nif_not_loaded)
 
   # 1.10.5: Push 型同期（Elixir → Rust 入力 → delta 返却）
   def push_tick(_world, _dx, _dy, _delta_ms), do: :erlang.nif_error(:nif_not_loaded)
@@ -65,13 +72,22 @@ defmodule GameEngine.NifBridge do
   def get_hud_data(_world), do: :erlang.nif_error(:nif_not_loaded)
   def get_frame_metadata(_world), do: :erlang.nif_error(:nif_not_loaded)
   def get_level_up_data(_world), do: :erlang.nif_error(:nif_not_loaded)
+  def get_level_up_choices(_world), do: :erlang.nif_error(:nif_not_loaded)
+  def get_wave_label(_world), do: :erlang.nif_error(:nif_not_loaded)
   def get_weapon_levels(_world), do: :erlang.nif_error(:nif_not_loaded)
+  def get_passive_levels(_world), do: :erlang.nif_error(:nif_not_loaded)
   def get_magnet_timer(_world), do: :erlang.nif_error(:nif_not_loaded)
   def get_boss_info(_world), do: :erlang.nif_error(:nif_not_loaded)
   def is_player_dead(_world), do: :erlang.nif_error(:nif_not_loaded)
+  def get_world_checksum(_world), do: :erlang.nif_error(:nif_not_loaded)
 
   # ── snapshot_heavy（明示操作時のみ）──────────────────────────────
   def get_save_snapshot(_world), do: :erlang.nif_error(:nif_not_loaded)
   def load_save_snapshot(_world, _snapshot), do: :erlang.nif_error(:nif_not_loaded)
   def debug_dump_world(_world), do: :erlang.nif_error(:nif_not_loaded)
+
+  # ── replay ────────────────────────────────────────────────────────
+  def start_replay_recording(_world, _path), do: :erlang.nif_error(:nif_not_loaded)
+  def stop_replay_recording(_world), do: :erlang.nif_error(:nif_not_loaded)
+  def play_replay_file(_path), do: :erlang.nif_error(:nif_not_loaded)
 end
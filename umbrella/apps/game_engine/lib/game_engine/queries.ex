@@ -13,7 +13,12 @@ defmodule GameEngine.Queries do
   def get_enemy_count(world_ref), do: NifBridge.get_enemy_count(world_ref)
   def is_player_dead(world_ref), do: NifBridge.is_player_dead(world_ref)
   def get_level_up_data(world_ref), do: NifBridge.get_level_up_data(world_ref)
+  def get_weapon_catalog, do: NifBridge.get_weapon_catalog()
+  def get_level_up_choices(world_ref), do: NifBridge.get_level_up_choices(world_ref)
+  def get_wave_label(world_ref), do: NifBridge.get_wave_label(world_ref)
   def get_frame_metadata(world_ref), do: NifBridge.get_frame_metadata(world_ref)
   def get_weapon_levels(world_ref), do: NifBridge.get_weapon_levels(world_ref)
+  def get_passive_levels(world_ref), do: NifBridge.get_passive_levels(world_ref)
+  def get_world_checksum(world_ref), do: NifBridge.get_world_checksum(world_ref)
   def get_save_snapshot_heavy(world_ref), do: NifBridge.get_save_snapshot(world_ref)
 end
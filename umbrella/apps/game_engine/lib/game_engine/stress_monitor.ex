@@ -45,12 +45,9 @@ defmodule GameEngine.StressMonitor do
          enemy_count: enemy_count,
          bullet_count: bullet_count,
          physics_ms: physics_ms,
-         hud_data: {hp, max_hp, score, elapsed_s}
+         hud_data: {hp, max_hp, score, _elapsed_s},
+         wave: wave
        }} ->
-        game_module = Application.get_env(:game_engine, :current)
-        wave = if game_module && function_exported?(game_module, :wave_label, 1),
-          do: game_module.wave_label(elapsed_s),
-          else: "Wave"
 
         overrun = physics_ms > @frame_budget_ms
 
@@ -13,6 +13,12 @@ config :game_engine, current: GameContent.VampireSurvivor
 # マップ障害物設定: :plain | :forest | :minimal
 config :game_engine, map: :minimal
 
+# ワールドの乱数シード（未指定時はランダム）
+# config :game_engine, world_seed: 12345
+
+# 入力・コマンドのリプレイ記録先（GameEngine.play_replay/1 で再生）
+# config :game_engine, replay_path: "saves/last_run.replay"
+
 # tick_hz: 10 | 20 | 30（デフォルト: 20Hz）
 # GAME_ENGINE_HEADLESS=true 環境変数でヘッドレスモードに切り替え可能
 headless_env = System.get_env("GAME_ENGINE_HEADLESS", "false") == "true"